@@ -9,7 +9,7 @@ mod wallet;
 
 use crate::fs::PersistentData;
 
-pub use crate::config::wallet::Wallet;
+pub use crate::config::wallet::{Wallet, WalletBackend};
 
 /// Config struct.
 #[derive(Deserialize, Serialize, Debug, PartialEq, Default, Clone)]
@@ -74,6 +74,22 @@ pub struct Config {
 
     #[serde(default)]
     pub tx_method: TxMethod,
+
+    /// Defaults for the advanced options when sending
+    #[serde(default)]
+    pub tx_defaults: TxDefaults,
+
+    /// Sending straight to the recipient's wallet over Tor
+    #[serde(default)]
+    pub tor_dispatch: TorDispatch,
+
+    /// Listener for sends from other wallets
+    #[serde(default)]
+    pub foreign_listener: ForeignListenerConfig,
+
+    /// Coinswaps through the MWMixnet servers in `mixnet_keys`
+    #[serde(default)]
+    pub mixnet: MixnetConfig,
 }
 
 impl Config {
@@ -95,6 +111,29 @@ impl Config {
             None
         }
     }
+
+    /// How long the current wallet may sit idle before it's closed
+    pub fn get_wallet_idle_lock(&self) -> Option<std::time::Duration> {
+        let i = self.current_wallet_index?;
+        self.wallets[i]
+            .idle_lock_minutes
+            .map(|m| std::time::Duration::from_secs(u64::from(m) * 60))
+    }
+
+    /// Default destination of exported payment proofs
+    pub fn get_wallet_proof_dir(&self) -> Option<String> {
+        if let Some(i) = self.current_wallet_index.as_ref() {
+            if let Some(ref tld) = self.wallets[*i].tld {
+                let proof_dir = format!("{}/{}", tld.as_os_str().to_str().unwrap(), "proofs");
+                let _ = std::fs::create_dir_all(proof_dir.clone());
+                Some(proof_dir)
+            } else {
+                None
+            }
+        } else {
+            None
+        }
+    }
 }
 
 impl PersistentData for Config {
@@ -235,6 +274,114 @@ impl Default for TxMethod {
     }
 }
 
+/// Transaction building options a user can tweak when sending
+#[derive(Deserialize, Serialize, Debug, PartialEq, Eq, Clone)]
+#[serde(default)]
+pub struct TxDefaults {
+    /// Confirmations an output needs before it's selected as an input
+    pub minimum_confirmations: u64,
+    /// Number of outputs change is split into
+    pub num_change_outputs: usize,
+    /// Spend all eligible outputs rather than the smallest set covering the amount
+    pub selection_strategy_is_use_all: bool,
+    /// Select inputs and fee at finalization time rather than when creating the slate
+    pub late_lock: bool,
+    /// Blocks after which an unfinished transaction is cancelled
+    pub ttl_blocks: Option<u64>,
+}
+
+impl TxDefaults {
+    pub const MAX_MINIMUM_CONFIRMATIONS: u64 = 10_000;
+    pub const MAX_CHANGE_OUTPUTS: usize = 32;
+    /// A week of blocks
+    pub const MAX_TTL_BLOCKS: u64 = 10_080;
+
+    pub fn minimum_confirmations_valid(value: u64) -> bool {
+        (1..=Self::MAX_MINIMUM_CONFIRMATIONS).contains(&value)
+    }
+
+    pub fn num_change_outputs_valid(value: usize) -> bool {
+        (1..=Self::MAX_CHANGE_OUTPUTS).contains(&value)
+    }
+
+    pub fn ttl_blocks_valid(value: u64) -> bool {
+        (1..=Self::MAX_TTL_BLOCKS).contains(&value)
+    }
+}
+
+impl Default for TxDefaults {
+    fn default() -> Self {
+        TxDefaults {
+            minimum_confirmations: 2,
+            num_change_outputs: 1,
+            selection_strategy_is_use_all: false,
+            late_lock: false,
+            ttl_blocks: None,
+        }
+    }
+}
+
+/// Synchronous delivery of sends to the recipient's onion address
+#[derive(Deserialize, Serialize, Debug, PartialEq, Eq, Clone)]
+#[serde(default)]
+pub struct TorDispatch {
+    /// Try delivering over Tor before falling back to showing the slatepack
+    pub enabled: bool,
+    /// SOCKS proxy of a locally running Tor
+    pub socks_proxy_addr: String,
+}
+
+impl Default for TorDispatch {
+    fn default() -> Self {
+        TorDispatch {
+            enabled: false,
+            socks_proxy_addr: "127.0.0.1:9050".to_owned(),
+        }
+    }
+}
+
+/// Where coinswaps are submitted and what each server is paid
+#[derive(Deserialize, Serialize, Debug, PartialEq, Eq, Clone)]
+#[serde(default)]
+pub struct MixnetConfig {
+    /// JSON-RPC endpoint of the first server, reached through the Tor proxy of
+    /// `tor_dispatch` when it's an onion address
+    pub server_url: String,
+    /// Fee in nanogrin paid to each server
+    pub fee_per_hop: u64,
+}
+
+impl Default for MixnetConfig {
+    fn default() -> Self {
+        MixnetConfig {
+            server_url: String::new(),
+            fee_per_hop: 50_000_000,
+        }
+    }
+}
+
+/// Foreign API listener run while a local wallet is open
+#[derive(Deserialize, Serialize, Debug, PartialEq, Eq, Clone)]
+#[serde(default)]
+pub struct ForeignListenerConfig {
+    /// Start the listener whenever a wallet is opened
+    pub enabled: bool,
+    pub listen_addr: String,
+    /// Also publish the listener as a Tor hidden service at the wallet's slatepack address
+    pub use_tor: bool,
+}
+
+impl Default for ForeignListenerConfig {
+    fn default() -> Self {
+        ForeignListenerConfig {
+            enabled: false,
+            // grin-wallet's default foreign listener port
+            listen_addr: "127.0.0.1:3415".to_owned(),
+            use_tor: false,
+        }
+    }
+}
+
 
 #[derive(
     Default, Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize, Hash, PartialOrd, Ord,
@@ -304,6 +451,53 @@ const fn default_true() -> bool {
 
 #[cfg(test)]
 mod test {
+    use super::{Config, TorDispatch, TxDefaults, Wallet};
+
+    #[test]
+    fn test_tx_defaults_missing_fields() {
+        let defaults: TxDefaults = serde_yaml::from_str("num_change_outputs: 3").unwrap();
+        assert_eq!(defaults.num_change_outputs, 3);
+        assert_eq!(defaults.minimum_confirmations, 2);
+        assert_eq!(defaults.ttl_blocks, None);
+    }
+
+    #[test]
+    fn test_tx_defaults_validation() {
+        assert!(!TxDefaults::minimum_confirmations_valid(0));
+        assert!(TxDefaults::minimum_confirmations_valid(10));
+        assert!(!TxDefaults::num_change_outputs_valid(0));
+        assert!(!TxDefaults::num_change_outputs_valid(
+            TxDefaults::MAX_CHANGE_OUTPUTS + 1
+        ));
+        assert!(TxDefaults::ttl_blocks_valid(60));
+        assert!(!TxDefaults::ttl_blocks_valid(
+            TxDefaults::MAX_TTL_BLOCKS + 1
+        ));
+    }
+
+    #[test]
+    fn test_tor_dispatch_off_by_default() {
+        let tor: TorDispatch = serde_yaml::from_str("socks_proxy_addr: 127.0.0.1:9150").unwrap();
+        assert!(!tor.enabled);
+        assert_eq!(tor.socks_proxy_addr, "127.0.0.1:9150");
+    }
+
+    #[test]
+    fn test_wallet_idle_lock() {
+        // Wallets saved before the setting existed never lock
+        let wallet: Wallet = serde_yaml::from_str("display_name: Office").unwrap();
+        assert_eq!(wallet.idle_lock_minutes, None);
+
+        let mut config = Config::default();
+        assert_eq!(config.get_wallet_idle_lock(), None);
+        let mut wallet = Wallet::default();
+        wallet.idle_lock_minutes = Some(5);
+        config.current_wallet_index = Some(config.add_wallet(wallet));
+        assert_eq!(
+            config.get_wallet_idle_lock(),
+            Some(std::time::Duration::from_secs(300))
+        );
+    }
 
     /// This method will take a relative path and make a case insentitive pattern
     // For some reason the case insensitive pattern doesn't work
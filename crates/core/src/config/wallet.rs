@@ -16,6 +16,36 @@ pub struct Wallet {
     pub use_embedded_node: bool,
     /// Chain type of wallet
     pub chain_type: ChainTypes,
+    /// Whether the wallet is opened in-process or driven over a remote Owner API
+    pub backend: WalletBackend,
+    /// Close the wallet after this many minutes without user input, never if None
+    pub idle_lock_minutes: Option<u32>,
+}
+
+/// Owner API implementation used to drive a wallet
+#[derive(Deserialize, Serialize, Clone, Debug, PartialEq, Eq)]
+pub enum WalletBackend {
+    /// Wallet data in `tld` is opened in-process
+    Local,
+    /// Wallet is hosted by a running grin-wallet and driven over its V3 (encrypted) Owner API
+    RemoteOwnerApi {
+        /// Owner API endpoint, i.e. http://127.0.0.1:3420/v3/owner
+        url: String,
+        /// Path to the remote wallet's `.owner_api_secret`, if basic auth is enabled
+        api_secret_path: Option<PathBuf>,
+    },
+}
+
+impl Default for WalletBackend {
+    fn default() -> Self {
+        WalletBackend::Local
+    }
+}
+
+impl WalletBackend {
+    pub fn is_remote(&self) -> bool {
+        matches!(self, WalletBackend::RemoteOwnerApi { .. })
+    }
 }
 
 impl Wallet {
@@ -25,6 +55,8 @@ impl Wallet {
             display_name,
             use_embedded_node: true,
             chain_type,
+            backend: WalletBackend::Local,
+            idle_lock_minutes: None,
         }
     }
 }
@@ -36,6 +68,8 @@ impl Default for Wallet {
             display_name: "Default".to_owned(),
             use_embedded_node: true,
             chain_type: ChainTypes::Mainnet,
+            backend: WalletBackend::Local,
+            idle_lock_minutes: None,
         }
     }
 }
\ No newline at end of file
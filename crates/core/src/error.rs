@@ -12,6 +12,10 @@ pub enum GrinWalletInterfaceError {
     OwnerAPINotInstantiated,
     #[error("Foreign API not Instantiated")]
     ForeignAPINotInstantiated,
+    #[error("Wallet not open")]
+    WalletNotOpen,
+    #[error("Password changed, but the wallet couldn't be re-opened with it: {0}")]
+    PasswordChangedReopenFailed(String),
     #[error("Invalid Slatepack Address")]
     InvalidSlatepackAddress,
     #[error("Can't load slatepack file at {file}")]
@@ -20,6 +24,87 @@ pub enum GrinWalletInterfaceError {
     InvalidTxLogState,
     #[error("Invalid Invoice Proof")]
     InvalidInvoiceProof,
+    #[error("Not a payment or invoice proof")]
+    InvalidProofFile,
+    #[error("Invalid kernel excess")]
+    InvalidKernelExcess,
+    #[error("Owner API call {method} failed: {message}")]
+    OwnerAPIRequest { method: String, message: String },
+    #[error("Unable to establish secure Owner API channel: {0}")]
+    OwnerAPISecureChannel(String),
+    #[error("Not supported when using a remote Owner API")]
+    NotSupportedByRemoteOwnerAPI,
+    #[error("Unable to deliver transaction to recipient: {0}")]
+    RecipientUnreachable(String),
+    #[error("Recipient may not have accepted the transaction: {0}")]
+    RecipientResponse(String),
+    #[error("Foreign listener error: {0}")]
+    ForeignListener(String),
+    #[error("Wallet operation cancelled")]
+    WalletJobCancelled,
+    #[error("Wallet worker is not running")]
+    WalletWorkerStopped,
+    #[error("Unable to set up wallet configuration in {path:?}: {message}")]
+    WalletConfig { path: PathBuf, message: String },
+    #[error("Unable to instantiate wallet in {path:?}: {message}")]
+    WalletInstantiation { path: PathBuf, message: String },
+    #[error("Invalid UTF8 wallet path: {path:?}")]
+    InvalidWalletPath { path: PathBuf },
+    #[error("MWMixnet servers are not configured")]
+    MixnetNotConfigured,
+    #[error("Invalid key for MWMixnet server {index}")]
+    InvalidMixnetKey { index: usize },
+    #[error("Output {commit} can't be swapped: {message}")]
+    MixnetOutputUnavailable { commit: String, message: String },
+    #[error("Unable to build coinswap request: {0}")]
+    MixnetRequest(String),
+    #[error("Coinswap not accepted by MWMixnet server: {0}")]
+    MixnetServer(String),
+    #[error("Contact name is missing")]
+    ContactNameMissing,
+    #[error("Address is already saved as contact {name}")]
+    DuplicateContact { name: String },
+    #[error(transparent)]
+    Http(#[from] isahc::Error),
+    #[error(transparent)]
+    HttpRequest(#[from] isahc::http::Error),
+    #[error(transparent)]
+    SerdeJson(#[from] serde_json::Error),
+    #[error(transparent)]
+    Io(#[from] std::io::Error),
+}
+
+#[derive(thiserror::Error, Debug)]
+pub enum GrinNodeError {
+    #[error("Unable to create node directory {path:?}: {source}")]
+    CreateDirectory {
+        path: PathBuf,
+        source: std::io::Error,
+    },
+    #[error("Unable to set up node API secret {path:?}: {message}")]
+    ApiSecret { path: PathBuf, message: String },
+    #[error("Unable to write default node config file {path:?}: {message}")]
+    WriteConfig { path: PathBuf, message: String },
+    #[error("Unable to load node config file {path:?}: {message}")]
+    LoadConfig { path: PathBuf, message: String },
+    #[error("Invalid UTF8 node path: {path:?}")]
+    InvalidNodePath { path: PathBuf },
+}
+
+#[derive(thiserror::Error, Debug)]
+pub enum QrError {
+    #[error("Unable to load image {path:?}: {message}")]
+    LoadImage { path: PathBuf, message: String },
+    #[error("No QR code found in {path:?}")]
+    NoCodeFound { path: PathBuf },
+    #[error("Unable to decode QR code: {0}")]
+    Decode(String),
+    #[error("Invalid QR frame header: {header}")]
+    InvalidFrameHeader { header: String },
+    #[error("Missing QR frame {index} of {total}")]
+    MissingFrame { index: usize, total: usize },
+    #[error("QR frames belong to different sequences")]
+    MismatchedFrames,
 }
 
 #[derive(thiserror::Error, Debug)]
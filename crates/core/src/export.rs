@@ -0,0 +1,152 @@
+use crate::wallet::TxLogEntry;
+
+use grin_util::ToHex;
+use serde_json::{Map, Value};
+
+use std::fmt::Display;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ExportFormat {
+    Csv,
+    Json,
+}
+
+impl ExportFormat {
+    pub const ALL: [ExportFormat; 2] = [ExportFormat::Csv, ExportFormat::Json];
+
+    pub const fn file_ext(&self) -> &'static str {
+        match self {
+            ExportFormat::Csv => "csv",
+            ExportFormat::Json => "json",
+        }
+    }
+}
+
+impl Display for ExportFormat {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ExportFormat::Csv => f.write_str("CSV"),
+            ExportFormat::Json => f.write_str("JSON"),
+        }
+    }
+}
+
+/// A column of exported data. CSV headers use the title, JSON objects the key
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ExportColumn {
+    pub key: String,
+    pub title: String,
+}
+
+/// Render `rows`, one value per column, in the given format. JSON keeps the values'
+/// types, CSV writes strings as is and nulls as empty fields
+pub fn export_table(
+    format: ExportFormat,
+    columns: &[ExportColumn],
+    rows: &[Vec<Value>],
+) -> Result<String, serde_json::Error> {
+    match format {
+        ExportFormat::Csv => {
+            let mut out = csv_line(columns.iter().map(|c| c.title.clone()));
+            for row in rows {
+                out.push_str(&csv_line(row.iter().map(csv_field)));
+            }
+            Ok(out)
+        }
+        ExportFormat::Json => {
+            let objects: Vec<Value> = rows
+                .iter()
+                .map(|row| {
+                    let object: Map<String, Value> = columns
+                        .iter()
+                        .map(|c| c.key.clone())
+                        .zip(row.iter().cloned())
+                        .collect();
+                    Value::Object(object)
+                })
+                .collect();
+            serde_json::to_string_pretty(&objects)
+        }
+    }
+}
+
+/// Kernel excess commitment of a transaction as hex, once known
+pub fn kernel_excess_hex(tx: &TxLogEntry) -> Option<String> {
+    tx.kernel_excess.as_ref().map(|k| k.to_hex())
+}
+
+fn csv_field(value: &Value) -> String {
+    match value {
+        Value::Null => String::new(),
+        Value::String(s) => s.clone(),
+        v => v.to_string(),
+    }
+}
+
+fn csv_line<I: Iterator<Item = String>>(fields: I) -> String {
+    let fields: Vec<String> = fields
+        .map(|f| {
+            if f.contains(|c| c == ',' || c == '"' || c == '\n' || c == '\r') {
+                format!("\"{}\"", f.replace('"', "\"\""))
+            } else {
+                f
+            }
+        })
+        .collect();
+    format!("{}\r\n", fields.join(","))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::wallet::TxLogEntryType;
+    use grin_keychain::Identifier;
+    use serde_json::json;
+
+    fn columns() -> Vec<ExportColumn> {
+        vec![
+            ExportColumn {
+                key: "tx_id".to_owned(),
+                title: "Id".to_owned(),
+            },
+            ExportColumn {
+                key: "tx_type".to_owned(),
+                title: "Type, as logged".to_owned(),
+            },
+            ExportColumn {
+                key: "tx_kernel".to_owned(),
+                title: "Kernel".to_owned(),
+            },
+        ]
+    }
+
+    #[test]
+    fn test_export_csv_escapes_fields() {
+        let rows = vec![
+            vec![json!(1), json!("Sent \"late\""), Value::Null],
+            vec![json!(2), json!("Received"), json!("08ab")],
+        ];
+        let csv = export_table(ExportFormat::Csv, &columns(), &rows).unwrap();
+        assert_eq!(
+            csv,
+            "Id,\"Type, as logged\",Kernel\r\n1,\"Sent \"\"late\"\"\",\r\n2,Received,08ab\r\n"
+        );
+    }
+
+    #[test]
+    fn test_export_json_uses_keys() {
+        let rows = vec![vec![json!(1), json!("Received"), Value::Null]];
+        let exported = export_table(ExportFormat::Json, &columns(), &rows).unwrap();
+        let value: Value = serde_json::from_str(&exported).unwrap();
+        assert_eq!(
+            value,
+            json!([{"tx_id": 1, "tx_type": "Received", "tx_kernel": null}])
+        );
+    }
+
+    #[test]
+    fn test_kernel_excess_hex_missing() {
+        let tx = TxLogEntry::new(Identifier::zero(), TxLogEntryType::TxReceived, 0);
+        assert_eq!(kernel_excess_hex(&tx), None);
+    }
+}
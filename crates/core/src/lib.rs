@@ -8,9 +8,11 @@ pub mod wallet;
 pub mod logger;
 pub mod node;
 pub mod error;
+pub mod export;
 pub mod fs;
 pub mod theme;
 pub mod network;
+pub mod qr;
 #[cfg(feature = "wgpu")]
 pub mod utility;
 
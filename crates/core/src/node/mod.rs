@@ -15,6 +15,7 @@ use std::time::Duration;
 
 use chrono::prelude::Utc;
 
+use crate::error::GrinNodeError;
 use crate::logger;
 
 pub use global::ChainTypes;
@@ -45,7 +46,7 @@ pub const API_SECRET_FILE_NAME: &str = ".api_secret";
 /// Foreign API secret
 pub const FOREIGN_API_SECRET_FILE_NAME: &str = ".foreign_api_secret";
 
-fn get_grin_node_default_path(chain_type: &global::ChainTypes) -> PathBuf {
+fn get_grin_node_default_path(chain_type: &global::ChainTypes) -> Result<PathBuf, GrinNodeError> {
     // Check if grin dir exists
     let mut grin_path = match dirs::home_dir() {
         Some(p) => p,
@@ -57,12 +58,13 @@ fn get_grin_node_default_path(chain_type: &global::ChainTypes) -> PathBuf {
     grin_path.push(GRIN_DEFAULT_DIR);
 
     if !grin_path.exists() {
-        if let Err(e) = fs::create_dir_all(grin_path.clone()) {
-            panic!("Unable to create default node path: {}", e);
-        }
+        fs::create_dir_all(&grin_path).map_err(|source| GrinNodeError::CreateDirectory {
+            path: grin_path.clone(),
+            source,
+        })?;
     }
 
-    grin_path
+    Ok(grin_path)
 }
 
 // include build information
@@ -161,6 +163,8 @@ pub struct NodeInterface {
     pub config: Option<GlobalConfig>,
     pub ui_sender: Option<iced_mpsc::Sender<UIMessage>>, //pub ui_rx: mpsc::Receiver<UIMessage>,
     pub node_started: bool,
+    /// Set when the last attempt to start the node failed, so it isn't retried on every message
+    pub start_failed: bool,
     controller_tx: Option<mpsc::Sender<ControllerMessage>>,
     handle: Option<std::thread::JoinHandle<()>>,
 }
@@ -172,6 +176,7 @@ impl NodeInterface {
             config: None,
             ui_sender: None,
             node_started: false,
+            start_failed: false,
             controller_tx: None,
             handle: None,
         }
@@ -182,38 +187,60 @@ impl NodeInterface {
     }
 
     /// Check that the api secret files exist and are valid
-    fn check_api_secret_files(&self, chain_type: &global::ChainTypes, secret_file_name: &str) {
-        let grin_path = get_grin_node_default_path(&chain_type);
+    fn check_api_secret_files(
+        &self,
+        chain_type: &global::ChainTypes,
+        secret_file_name: &str,
+    ) -> Result<(), GrinNodeError> {
+        let grin_path = get_grin_node_default_path(&chain_type)?;
         let mut api_secret_path = grin_path;
         api_secret_path.push(secret_file_name);
-        if !api_secret_path.exists() {
-            config::init_api_secret(&api_secret_path);
+        let result = if !api_secret_path.exists() {
+            config::init_api_secret(&api_secret_path)
         } else {
-            config::check_api_secret(&api_secret_path);
-        }
+            config::check_api_secret(&api_secret_path)
+        };
+        result.map_err(|e| GrinNodeError::ApiSecret {
+            path: api_secret_path,
+            message: e.to_string(),
+        })
     }
 
-    fn load_or_create_default_config(&mut self, chain_type: global::ChainTypes) -> GlobalConfig {
-        self.check_api_secret_files(&chain_type, API_SECRET_FILE_NAME);
-        self.check_api_secret_files(&chain_type, FOREIGN_API_SECRET_FILE_NAME);
+    fn load_or_create_default_config(
+        &mut self,
+        chain_type: global::ChainTypes,
+    ) -> Result<GlobalConfig, GrinNodeError> {
+        self.check_api_secret_files(&chain_type, API_SECRET_FILE_NAME)?;
+        self.check_api_secret_files(&chain_type, FOREIGN_API_SECRET_FILE_NAME)?;
 
-        let grin_path = get_grin_node_default_path(&chain_type);
+        let grin_path = get_grin_node_default_path(&chain_type)?;
 
         // Get path to default config file
         let mut config_path = grin_path.clone();
         config_path.push(SERVER_CONFIG_FILE_NAME);
+        let config_file = config_path
+            .to_str()
+            .ok_or_else(|| GrinNodeError::InvalidNodePath {
+                path: config_path.clone(),
+            })?;
 
         // Spit it out if it doesn't exist
         if !config_path.exists() {
             let mut default_config = GlobalConfig::for_chain(&chain_type);
             // update paths relative to current dir
             default_config.update_paths(&grin_path);
-            if let Err(e) = default_config.write_to_file(config_path.to_str().unwrap()) {
-                panic!("Unable to write default node config file: {}", e);
-            }
+            default_config
+                .write_to_file(config_file)
+                .map_err(|e| GrinNodeError::WriteConfig {
+                    path: config_path.clone(),
+                    message: e.to_string(),
+                })?;
         }
 
-        GlobalConfig::new(config_path.to_str().unwrap()).unwrap()
+        GlobalConfig::new(config_file).map_err(|e| GrinNodeError::LoadConfig {
+            path: config_path.clone(),
+            message: e.to_string(),
+        })
     }
 
     pub fn shutdown_server(&mut self, join: bool) {
@@ -232,16 +259,23 @@ impl NodeInterface {
         }
     }
 
-    pub fn restart_server(&mut self, chain_type: global::ChainTypes) {
+    pub fn restart_server(&mut self, chain_type: global::ChainTypes) -> Result<(), GrinNodeError> {
         self.shutdown_server(false);
-        self.start_server(chain_type);
+        self.start_server(chain_type)
     }
 
-    pub fn start_server(&mut self, chain_type: global::ChainTypes) {
+    pub fn start_server(&mut self, chain_type: global::ChainTypes) -> Result<(), GrinNodeError> {
         self.chain_type = Some(chain_type);
         global::set_global_chain_type(chain_type);
 
-        let node_config = self.load_or_create_default_config(chain_type);
+        let node_config = match self.load_or_create_default_config(chain_type) {
+            Ok(c) => c,
+            Err(e) => {
+                self.start_failed = true;
+                return Err(e);
+            }
+        };
+        self.start_failed = false;
 
         self.config = Some(node_config.clone());
 
@@ -321,5 +355,6 @@ impl NodeInterface {
             .unwrap();
 
         self.handle = Some(handle);
+        Ok(())
     }
 }
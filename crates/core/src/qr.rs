@@ -0,0 +1,187 @@
+use crate::error::QrError;
+
+use std::collections::BTreeMap;
+use std::path::Path;
+
+/// Prefix marking a QR code as one frame of a longer payload.
+const FRAME_PREFIX: &str = "grinqr:";
+
+/// Maximum number of payload characters carried by a single QR frame.
+/// Codes beyond this size get too dense to scan reliably from a screen.
+pub const QR_FRAME_MAX_LEN: usize = 500;
+
+/// Splits `data` into frames of at most `max_len` characters. A payload that
+/// fits into a single frame is returned as is, otherwise every frame is
+/// prefixed with `grinqr:<index>/<total>:` so it can be reassembled in any
+/// order by [`join_frames`].
+pub fn split_frames(data: &str, max_len: usize) -> Vec<String> {
+    let chars: Vec<char> = data.chars().collect();
+    if chars.len() <= max_len {
+        return vec![data.to_owned()];
+    }
+
+    let chunks: Vec<String> = chars
+        .chunks(max_len.max(1))
+        .map(|c| c.iter().collect())
+        .collect();
+    let total = chunks.len();
+
+    chunks
+        .into_iter()
+        .enumerate()
+        .map(|(i, chunk)| format!("{}{}/{}:{}", FRAME_PREFIX, i + 1, total, chunk))
+        .collect()
+}
+
+/// Reassembles the payload of frames produced by [`split_frames`]. Frames may
+/// be given in any order and duplicates are ignored.
+pub fn join_frames(frames: Vec<String>) -> Result<String, QrError> {
+    if frames.len() == 1 && !frames[0].starts_with(FRAME_PREFIX) {
+        return Ok(frames.into_iter().next().unwrap());
+    }
+
+    let mut total = None;
+    let mut parts = BTreeMap::new();
+
+    for frame in frames {
+        let (index, count, payload) = parse_frame(&frame)?;
+        if *total.get_or_insert(count) != count {
+            return Err(QrError::MismatchedFrames);
+        }
+        if let Some(existing) = parts.insert(index, payload.to_owned()) {
+            if existing != payload {
+                return Err(QrError::MismatchedFrames);
+            }
+        }
+    }
+
+    let total = total.unwrap_or(1);
+    if let Some(index) = (1..=total).find(|i| !parts.contains_key(i)) {
+        return Err(QrError::MissingFrame { index, total });
+    }
+
+    Ok(parts.into_values().collect())
+}
+
+fn parse_frame(frame: &str) -> Result<(usize, usize, &str), QrError> {
+    let invalid = || QrError::InvalidFrameHeader {
+        header: frame.chars().take(32).collect(),
+    };
+
+    let rest = frame.strip_prefix(FRAME_PREFIX).ok_or_else(invalid)?;
+    let (header, payload) = rest.split_once(':').ok_or_else(invalid)?;
+    let (index, total) = header.split_once('/').ok_or_else(invalid)?;
+    let index: usize = index.parse().map_err(|_| invalid())?;
+    let total: usize = total.parse().map_err(|_| invalid())?;
+
+    if index == 0 || index > total {
+        return Err(invalid());
+    }
+
+    Ok((index, total, payload))
+}
+
+/// Decodes the contents of all QR codes found in the image at `path`.
+pub fn decode_image(path: &Path) -> Result<Vec<String>, QrError> {
+    let image = image::open(path)
+        .map_err(|e| QrError::LoadImage {
+            path: path.to_owned(),
+            message: e.to_string(),
+        })?
+        .to_luma8();
+
+    let mut prepared = rqrr::PreparedImage::prepare_from_greyscale(
+        image.width() as usize,
+        image.height() as usize,
+        |x, y| image.get_pixel(x as u32, y as u32).0[0],
+    );
+
+    let grids = prepared.detect_grids();
+    if grids.is_empty() {
+        return Err(QrError::NoCodeFound {
+            path: path.to_owned(),
+        });
+    }
+
+    grids
+        .into_iter()
+        .map(|grid| {
+            grid.decode()
+                .map(|(_, content)| content)
+                .map_err(|e| QrError::Decode(e.to_string()))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_split_frames_single() {
+        let frames = split_frames("BEGINSLATEPACK. abc. ENDSLATEPACK.", 100);
+        assert_eq!(
+            frames,
+            vec!["BEGINSLATEPACK. abc. ENDSLATEPACK.".to_owned()]
+        );
+        assert_eq!(
+            join_frames(frames).unwrap(),
+            "BEGINSLATEPACK. abc. ENDSLATEPACK."
+        );
+    }
+
+    #[test]
+    fn test_split_and_join_frames() {
+        let data = "abcdefghijklmnopqrstuvwxyz".repeat(10);
+        let mut frames = split_frames(&data, 60);
+        assert_eq!(frames.len(), 5);
+        assert!(frames[0].starts_with("grinqr:1/5:"));
+        assert!(frames[4].starts_with("grinqr:5/5:"));
+
+        frames.reverse();
+        frames.push(frames[2].clone());
+        assert_eq!(join_frames(frames).unwrap(), data);
+    }
+
+    #[test]
+    fn test_join_frames_errors() {
+        let frames = split_frames(&"x".repeat(30), 10);
+
+        let missing = vec![frames[0].clone(), frames[2].clone()];
+        assert!(matches!(
+            join_frames(missing),
+            Err(QrError::MissingFrame { index: 2, total: 3 })
+        ));
+
+        let mixed = vec![frames[0].clone(), "grinqr:2/4:xx".to_owned()];
+        assert!(matches!(join_frames(mixed), Err(QrError::MismatchedFrames)));
+
+        let bad = vec![frames[0].clone(), "grinqr:0/3:xx".to_owned()];
+        assert!(matches!(
+            join_frames(bad),
+            Err(QrError::InvalidFrameHeader { .. })
+        ));
+    }
+
+    #[test]
+    fn test_decode_image() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("slatepack.png");
+        let content = "grinqr:1/2:BEGINSLATEPACK. 4H1qx1wHe668tFW";
+
+        let image = qrcode::QrCode::new(content)
+            .unwrap()
+            .render::<image::Luma<u8>>()
+            .build();
+        image.save(&path).unwrap();
+
+        assert_eq!(decode_image(&path).unwrap(), vec![content.to_owned()]);
+
+        let blank = image::GrayImage::from_pixel(64, 64, image::Luma([255]));
+        blank.save(&path).unwrap();
+        assert!(matches!(
+            decode_image(&path),
+            Err(QrError::NoCodeFound { .. })
+        ));
+    }
+}
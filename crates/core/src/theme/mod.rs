@@ -18,6 +18,7 @@ pub mod container;
 pub mod modal;
 pub mod header;
 pub mod picklist;
+pub mod qr_code;
 pub mod radio;
 pub mod scrollable;
 pub mod text;
@@ -30,6 +31,7 @@ pub use checkbox::CheckboxStyle;
 pub use container::ContainerStyle;
 pub use modal::ModalStyle;
 pub use picklist::PickListStyle;
+pub use qr_code::QrCodeStyle;
 pub use radio::RadioStyle;
 pub use scrollable::ScrollableStyle;
 pub use text_input::TextInputStyle;
@@ -64,6 +66,7 @@ pub type Card<'a, Message> = iced_aw::native::Card<'a, Message, Renderer>;
 pub type Modal<'a, Content, Message> = iced_aw::modal::Modal<'a, Content, Message>;
 pub type Header<'a, Message> = grin_gui_widgets::widget::header::Header<'a, Message, Renderer>;
 pub type TableRow<'a, Message> = grin_gui_widgets::widget::table_row::TableRow<'a, Message, Renderer>;
+pub type QrCode<'a> = grin_gui_widgets::widget::qr_code::QrCode<'a, Renderer>;
 
 
 #[derive(Debug, Clone, Copy, Default, Deserialize, Serialize)]
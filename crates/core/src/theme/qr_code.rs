@@ -0,0 +1,24 @@
+use super::Theme;
+use grin_gui_widgets::style::qr_code::{Appearance, StyleSheet};
+use iced::Color;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum QrCodeStyle {
+    #[default]
+    Default,
+}
+
+impl StyleSheet for Theme {
+    type Style = QrCodeStyle;
+
+    fn appearance(&self, style: &Self::Style) -> Appearance {
+        match style {
+            // Most scanners expect dark modules on a light background, so the
+            // default ignores the palette of dark themes.
+            QrCodeStyle::Default => Appearance {
+                dark: Color::BLACK,
+                light: Color::WHITE,
+            },
+        }
+    }
+}
@@ -0,0 +1,214 @@
+/// Address book of slatepack recipients, stored as json next to the wallet data
+use std::convert::TryFrom;
+use std::fmt;
+use std::path::Path;
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use super::{SlatepackAddress, TxLogEntry, TxLogEntryType};
+use crate::error::GrinWalletInterfaceError;
+
+pub const CONTACTS_FILE_NAME: &str = "contacts.json";
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Contact {
+    pub name: String,
+    pub address: String,
+    #[serde(default)]
+    pub notes: String,
+    /// When a transaction was last created for this contact
+    #[serde(default)]
+    pub last_used: Option<DateTime<Utc>>,
+}
+
+impl fmt::Display for Contact {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.name)
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AddressBook {
+    contacts: Vec<Contact>,
+}
+
+impl AddressBook {
+    /// Load the address book from `dir`, empty if none was saved yet
+    pub fn load(dir: &Path) -> Result<Self, GrinWalletInterfaceError> {
+        let path = dir.join(CONTACTS_FILE_NAME);
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+        let contents = std::fs::read_to_string(path)?;
+        Ok(serde_json::from_str(&contents)?)
+    }
+
+    pub fn save(&self, dir: &Path) -> Result<(), GrinWalletInterfaceError> {
+        let contents = serde_json::to_string_pretty(self)?;
+        std::fs::write(dir.join(CONTACTS_FILE_NAME), contents)?;
+        Ok(())
+    }
+
+    /// Contacts ordered by name
+    pub fn contacts(&self) -> Vec<Contact> {
+        let mut contacts = self.contacts.clone();
+        contacts.sort_by_key(|c| c.name.to_lowercase());
+        contacts
+    }
+
+    pub fn find(&self, address: &str) -> Option<&Contact> {
+        let address = address.trim();
+        self.contacts.iter().find(|c| c.address == address)
+    }
+
+    pub fn name_for(&self, address: &str) -> Option<String> {
+        self.find(address).map(|c| c.name.clone())
+    }
+
+    /// Add a contact, or replace the one saved under `previous_address` when editing
+    pub fn upsert(
+        &mut self,
+        previous_address: Option<&str>,
+        name: &str,
+        address: &str,
+        notes: &str,
+    ) -> Result<(), GrinWalletInterfaceError> {
+        let name = name.trim();
+        let address = address.trim();
+        if name.is_empty() {
+            return Err(GrinWalletInterfaceError::ContactNameMissing);
+        }
+        if SlatepackAddress::try_from(address).is_err() {
+            return Err(GrinWalletInterfaceError::InvalidSlatepackAddress);
+        }
+        if let Some(existing) = self.find(address) {
+            if previous_address.map(str::trim) != Some(address) {
+                return Err(GrinWalletInterfaceError::DuplicateContact {
+                    name: existing.name.clone(),
+                });
+            }
+        }
+
+        let last_used = previous_address
+            .and_then(|a| self.find(a))
+            .and_then(|c| c.last_used);
+        if let Some(previous) = previous_address {
+            self.remove(previous);
+        }
+        self.contacts.push(Contact {
+            name: name.to_owned(),
+            address: address.to_owned(),
+            notes: notes.trim().to_owned(),
+            last_used,
+        });
+        Ok(())
+    }
+
+    /// Returns whether a contact was removed
+    pub fn remove(&mut self, address: &str) -> bool {
+        let address = address.trim();
+        let len = self.contacts.len();
+        self.contacts.retain(|c| c.address != address);
+        self.contacts.len() != len
+    }
+
+    /// Record a transaction with the contact at `address`, returns false if there's none
+    pub fn mark_used(&mut self, address: &str, when: DateTime<Utc>) -> bool {
+        let address = address.trim();
+        match self.contacts.iter_mut().find(|c| c.address == address) {
+            Some(c) => {
+                c.last_used = Some(when);
+                true
+            }
+            None => false,
+        }
+    }
+}
+
+/// Slatepack address of the other party of a transaction, known from its payment proof
+pub fn counterparty_address(tx: &TxLogEntry) -> Option<String> {
+    let proof = tx.payment_proof.as_ref()?;
+    let key = match tx.tx_type {
+        TxLogEntryType::TxSent | TxLogEntryType::TxSentCancelled => &proof.receiver_address,
+        TxLogEntryType::TxReceived | TxLogEntryType::TxReceivedCancelled => &proof.sender_address,
+        _ => return None,
+    };
+    Some(SlatepackAddress::new(key).to_string())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use chrono::TimeZone;
+    use ed25519_dalek::{PublicKey, SecretKey};
+
+    fn address(seed: u8) -> String {
+        let secret = SecretKey::from_bytes(&[seed; 32]).unwrap();
+        SlatepackAddress::new(&PublicKey::from(&secret)).to_string()
+    }
+
+    #[test]
+    fn test_upsert_validates() {
+        let mut book = AddressBook::default();
+        book.upsert(None, " Bob ", &address(2), "").unwrap();
+        assert_eq!(book.name_for(&address(2)), Some("Bob".to_owned()));
+
+        assert!(matches!(
+            book.upsert(None, "  ", &address(3), ""),
+            Err(GrinWalletInterfaceError::ContactNameMissing)
+        ));
+        assert!(matches!(
+            book.upsert(None, "Carol", "grin1notanaddress", ""),
+            Err(GrinWalletInterfaceError::InvalidSlatepackAddress)
+        ));
+        assert!(matches!(
+            book.upsert(None, "Robert", &address(2), ""),
+            Err(GrinWalletInterfaceError::DuplicateContact { name }) if name == "Bob"
+        ));
+    }
+
+    #[test]
+    fn test_edit_keeps_last_used() {
+        let mut book = AddressBook::default();
+        book.upsert(None, "Bob", &address(2), "").unwrap();
+        let when = Utc.with_ymd_and_hms(2023, 1, 2, 3, 4, 5).unwrap();
+        assert!(book.mark_used(&address(2), when));
+        assert!(!book.mark_used(&address(9), when));
+
+        // Renaming under the same address, then moving to a new one
+        book.upsert(Some(&address(2)), "Robert", &address(2), "work")
+            .unwrap();
+        book.upsert(Some(&address(2)), "Robert", &address(4), "work")
+            .unwrap();
+
+        let contacts = book.contacts();
+        assert_eq!(contacts.len(), 1);
+        assert_eq!(contacts[0].address, address(4));
+        assert_eq!(contacts[0].notes, "work");
+        assert_eq!(contacts[0].last_used, Some(when));
+
+        assert!(book.remove(&address(4)));
+        assert!(!book.remove(&address(4)));
+        assert!(book.contacts().is_empty());
+    }
+
+    #[test]
+    fn test_save_and_load() {
+        let dir = tempfile::tempdir().unwrap();
+        assert_eq!(
+            AddressBook::load(dir.path()).unwrap(),
+            AddressBook::default()
+        );
+
+        let mut book = AddressBook::default();
+        book.upsert(None, "zed", &address(2), "").unwrap();
+        book.upsert(None, "Alice", &address(3), "").unwrap();
+        book.save(dir.path()).unwrap();
+
+        let loaded = AddressBook::load(dir.path()).unwrap();
+        assert_eq!(loaded, book);
+        let names: Vec<String> = loaded.contacts().into_iter().map(|c| c.name).collect();
+        assert_eq!(names, vec!["Alice".to_owned(), "zed".to_owned()]);
+    }
+}
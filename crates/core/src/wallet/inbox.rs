@@ -0,0 +1,213 @@
+/// Slatepacks dropped into the wallet's inbox folder, e.g. by a sync tool or mail client.
+/// The wallet watcher picks up new files while the wallet is open, and files are moved to
+/// the archive folder once they've been dealt with.
+use std::collections::HashMap;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::time::SystemTime;
+
+use super::{Slate, TxLogEntry};
+
+pub const INBOX_DIR_NAME: &str = "inbox";
+pub const ARCHIVE_DIR_NAME: &str = "archive";
+const SLATEPACK_EXT: &str = "slatepack";
+
+/// A slatepack file found in the inbox
+#[derive(Clone, Debug)]
+pub struct InboxSlatepack {
+    pub path: PathBuf,
+    /// Decrypted slate and the transaction it belongs to, if the wallet knows it already.
+    /// Files that can't be decrypted by this wallet are kept with the reason.
+    pub decoded: Result<(Slate, Option<TxLogEntry>), String>,
+}
+
+impl InboxSlatepack {
+    pub fn file_name(&self) -> String {
+        self.path
+            .file_name()
+            .map(|n| n.to_string_lossy().into_owned())
+            .unwrap_or_default()
+    }
+}
+
+/// Inbox folder within a wallet's slatepack directory
+pub fn inbox_dir(slatepack_dir: impl AsRef<Path>) -> PathBuf {
+    slatepack_dir.as_ref().join(INBOX_DIR_NAME)
+}
+
+/// Archive folder next to the inbox folder
+pub fn archive_dir(inbox_dir: &Path) -> PathBuf {
+    match inbox_dir.parent() {
+        Some(p) => p.join(ARCHIVE_DIR_NAME),
+        None => PathBuf::from(ARCHIVE_DIR_NAME),
+    }
+}
+
+/// Slatepack files currently in the inbox, oldest name first. The folder is created if needed.
+pub fn list_inbox(dir: &Path) -> io::Result<Vec<PathBuf>> {
+    fs::create_dir_all(dir)?;
+    let mut files: Vec<PathBuf> = fs::read_dir(dir)?
+        .filter_map(|e| e.ok())
+        .map(|e| e.path())
+        .filter(|p| p.is_file() && p.extension().map_or(false, |e| e == SLATEPACK_EXT))
+        .collect();
+    files.sort();
+    Ok(files)
+}
+
+/// Move a processed inbox file to the archive folder, without overwriting earlier archived
+/// files of the same name. Returns the archived path.
+pub fn archive_file(path: &Path) -> io::Result<PathBuf> {
+    let inbox = path.parent().unwrap_or_else(|| Path::new("."));
+    let archive = archive_dir(inbox);
+    fs::create_dir_all(&archive)?;
+
+    let file_name = path
+        .file_name()
+        .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidInput, "not a file"))?;
+    let mut target = archive.join(file_name);
+    let mut n = 1;
+    while target.exists() {
+        target = archive.join(format!("{}.{}", file_name.to_string_lossy(), n));
+        n += 1;
+    }
+    fs::rename(path, &target)?;
+    Ok(target)
+}
+
+/// Inbox files already looked at, so each is only decrypted once unless it's rewritten
+#[derive(Default)]
+pub struct InboxScanner {
+    dir: Option<PathBuf>,
+    known: HashMap<PathBuf, (Option<SystemTime>, InboxSlatepack)>,
+    // Whether the last scan was passed on yet
+    reported: bool,
+}
+
+impl InboxScanner {
+    /// Look at the inbox in `dir`, decoding new or changed files with `decode`.
+    /// Returns the full list of pending slatepacks only if it changed since the last scan.
+    pub fn scan<F>(&mut self, dir: &Path, decode: F) -> io::Result<Option<Vec<InboxSlatepack>>>
+    where
+        F: Fn(String) -> Result<(Slate, Option<TxLogEntry>), String>,
+    {
+        if self.dir.as_deref() != Some(dir) {
+            self.dir = Some(dir.to_owned());
+            self.known.clear();
+            self.reported = false;
+        }
+
+        let files = list_inbox(dir)?;
+        let mut changed = !self.reported || files.len() != self.known.len();
+        let mut known = HashMap::new();
+
+        for path in files {
+            let modified = fs::metadata(&path).and_then(|m| m.modified()).ok();
+            match self.known.remove(&path) {
+                Some((m, entry)) if m == modified => {
+                    known.insert(path, (m, entry));
+                }
+                _ => {
+                    let decoded = fs::read_to_string(&path)
+                        .map_err(|e| e.to_string())
+                        .and_then(|s| decode(s.trim().to_owned()));
+                    let entry = InboxSlatepack {
+                        path: path.clone(),
+                        decoded,
+                    };
+                    known.insert(path, (modified, entry));
+                    changed = true;
+                }
+            }
+        }
+
+        self.known = known;
+        self.reported = true;
+
+        if !changed {
+            return Ok(None);
+        }
+        let mut entries: Vec<InboxSlatepack> =
+            self.known.values().map(|(_, e)| e.clone()).collect();
+        entries.sort_by(|a, b| a.path.cmp(&b.path));
+        Ok(Some(entries))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn undecodable(_: String) -> Result<(Slate, Option<TxLogEntry>), String> {
+        Err("not for this wallet".to_owned())
+    }
+
+    #[test]
+    fn test_list_inbox_only_slatepacks() {
+        let dir = tempfile::tempdir().unwrap();
+        let inbox = inbox_dir(dir.path());
+        assert!(list_inbox(&inbox).unwrap().is_empty());
+
+        fs::write(inbox.join("b.slatepack"), "BEGINSLATEPACK.").unwrap();
+        fs::write(inbox.join("a.slatepack"), "BEGINSLATEPACK.").unwrap();
+        fs::write(inbox.join("notes.txt"), "ignored").unwrap();
+        fs::create_dir(inbox.join("sub.slatepack")).unwrap();
+
+        assert_eq!(
+            list_inbox(&inbox).unwrap(),
+            vec![inbox.join("a.slatepack"), inbox.join("b.slatepack")]
+        );
+    }
+
+    #[test]
+    fn test_archive_file_keeps_earlier_files() {
+        let dir = tempfile::tempdir().unwrap();
+        let inbox = inbox_dir(dir.path());
+        fs::create_dir_all(&inbox).unwrap();
+
+        let file = inbox.join("tx.slatepack");
+        fs::write(&file, "first").unwrap();
+        let first = archive_file(&file).unwrap();
+        assert_eq!(first, dir.path().join("archive").join("tx.slatepack"));
+        assert!(!file.exists());
+
+        fs::write(&file, "second").unwrap();
+        let second = archive_file(&file).unwrap();
+        assert_eq!(second, dir.path().join("archive").join("tx.slatepack.1"));
+        assert_eq!(fs::read_to_string(first).unwrap(), "first");
+        assert_eq!(fs::read_to_string(second).unwrap(), "second");
+    }
+
+    #[test]
+    fn test_scanner_reports_changes_only() {
+        let dir = tempfile::tempdir().unwrap();
+        let inbox = inbox_dir(dir.path());
+        let mut scanner = InboxScanner::default();
+
+        // An empty inbox is reported once so the UI starts out cleared
+        assert_eq!(scanner.scan(&inbox, undecodable).unwrap().unwrap().len(), 0);
+        assert!(scanner.scan(&inbox, undecodable).unwrap().is_none());
+
+        let file = inbox.join("tx.slatepack");
+        fs::write(&file, "BEGINSLATEPACK. garbage. ENDSLATEPACK.").unwrap();
+        let entries = scanner.scan(&inbox, undecodable).unwrap().unwrap();
+        assert_eq!(entries.len(), 1);
+        assert_eq!(entries[0].file_name(), "tx.slatepack");
+        assert_eq!(
+            entries[0].decoded.as_ref().unwrap_err(),
+            "not for this wallet"
+        );
+
+        // Already decoded files aren't decoded again
+        let entries = scanner.scan(&inbox, |_| panic!("decoded twice")).unwrap();
+        assert!(entries.is_none());
+
+        archive_file(&file).unwrap();
+        assert!(scanner
+            .scan(&inbox, undecodable)
+            .unwrap()
+            .unwrap()
+            .is_empty());
+    }
+}
@@ -0,0 +1,380 @@
+/// Foreign API listener run while a wallet is open, so other wallets can send to it
+/// directly. Speaks the same JSON-RPC as grin-wallet's `/v2/foreign` endpoint, with the
+/// calls themselves left to a handler.
+use grin_wallet_impls::tor::process::TorProcess;
+
+use serde_json::{json, Value};
+
+use std::io::{BufRead, BufReader, Read, Write};
+use std::net::{SocketAddr, TcpListener, TcpStream};
+use std::sync::atomic::{AtomicBool, AtomicU64, AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::thread::{self, JoinHandle};
+use std::time::Duration;
+
+use crate::error::GrinWalletInterfaceError;
+
+/// Give up on a connection that stops sending halfway through a request
+const READ_TIMEOUT_SECS: u64 = 30;
+/// Slates are small, anything bigger than this isn't one
+const MAX_BODY_LEN: usize = 10 * 1024 * 1024;
+/// Request line and headers together, a sender needs next to none
+const MAX_HEADER_LEN: u64 = 16 * 1024;
+/// Connections handled at once, further ones are turned away until one finishes
+const MAX_CONNECTIONS: usize = 16;
+
+/// Where a running listener can be reached
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ListenerInfo {
+    pub addr: String,
+    /// Onion address when also published as a Tor hidden service
+    pub onion_address: Option<String>,
+}
+
+pub struct ForeignListener {
+    addr: SocketAddr,
+    onion_address: Option<String>,
+    stop: Arc<AtomicBool>,
+    // Slates received since the listener started
+    received: Arc<AtomicU64>,
+    handle: Option<JoinHandle<()>>,
+    // Killed when dropped
+    tor_process: Option<TorProcess>,
+}
+
+impl ForeignListener {
+    /// Bind `addr` and answer requests until stopped, each connection on its own thread so
+    /// a slow sender doesn't hold up the rest. `handler` gets the method and params of each
+    /// call and returns its `Ok` value or an error message
+    pub fn start<F>(addr: &str, handler: F) -> Result<Self, GrinWalletInterfaceError>
+    where
+        F: Fn(&str, &Value) -> Result<Value, String> + Send + Sync + 'static,
+    {
+        let listener = TcpListener::bind(addr)
+            .map_err(|e| GrinWalletInterfaceError::ForeignListener(e.to_string()))?;
+        let addr = listener
+            .local_addr()
+            .map_err(|e| GrinWalletInterfaceError::ForeignListener(e.to_string()))?;
+        let stop = Arc::new(AtomicBool::new(false));
+        let received = Arc::new(AtomicU64::new(0));
+
+        let handler = Arc::new(handler);
+        let thread_stop = stop.clone();
+        let thread_received = received.clone();
+        let connections = Arc::new(AtomicUsize::new(0));
+        let handle = thread::Builder::new()
+            .name("foreign-listener".to_owned())
+            .spawn(move || {
+                for stream in listener.incoming() {
+                    if thread_stop.load(Ordering::SeqCst) {
+                        break;
+                    }
+                    let mut stream = match stream {
+                        Ok(s) => s,
+                        Err(e) => {
+                            log::warn!("Foreign listener failed to accept connection: {}", e);
+                            continue;
+                        }
+                    };
+                    if connections.fetch_add(1, Ordering::SeqCst) >= MAX_CONNECTIONS {
+                        connections.fetch_sub(1, Ordering::SeqCst);
+                        let _ = write_response(&mut stream, "503 Service Unavailable", &json!({}));
+                        continue;
+                    }
+
+                    let handler = handler.clone();
+                    let received = thread_received.clone();
+                    let conn_connections = connections.clone();
+                    let spawned = thread::Builder::new()
+                        .name("foreign-listener-conn".to_owned())
+                        .spawn(move || {
+                            if let Err(e) = handle_connection(stream, &*handler, &received) {
+                                log::warn!("Foreign listener request failed: {}", e);
+                            }
+                            conn_connections.fetch_sub(1, Ordering::SeqCst);
+                        });
+                    if let Err(e) = spawned {
+                        connections.fetch_sub(1, Ordering::SeqCst);
+                        log::warn!("Foreign listener failed to handle connection: {}", e);
+                    }
+                }
+            })
+            .map_err(|e| GrinWalletInterfaceError::ForeignListener(e.to_string()))?;
+
+        log::info!("Foreign listener started on {}", addr);
+        Ok(ForeignListener {
+            addr,
+            onion_address: None,
+            stop,
+            received,
+            handle: Some(handle),
+            tor_process: None,
+        })
+    }
+
+    pub fn addr(&self) -> SocketAddr {
+        self.addr
+    }
+
+    pub fn received(&self) -> u64 {
+        self.received.load(Ordering::SeqCst)
+    }
+
+    pub fn info(&self) -> ListenerInfo {
+        ListenerInfo {
+            addr: self.addr.to_string(),
+            onion_address: self.onion_address.clone(),
+        }
+    }
+
+    /// Keep the Tor process publishing this listener as a hidden service
+    pub fn set_tor(&mut self, process: TorProcess, onion_address: String) {
+        self.tor_process = Some(process);
+        self.onion_address = Some(onion_address);
+    }
+
+    /// Stop accepting connections. Requests already being handled finish on their own threads
+    pub fn stop(&mut self) {
+        if let Some(handle) = self.handle.take() {
+            self.stop.store(true, Ordering::SeqCst);
+            // Wake the accept loop so it sees the flag
+            let _ = TcpStream::connect(self.addr);
+            let _ = handle.join();
+            log::info!("Foreign listener on {} stopped", self.addr);
+        }
+        self.tor_process = None;
+        self.onion_address = None;
+    }
+}
+
+impl Drop for ForeignListener {
+    fn drop(&mut self) {
+        self.stop();
+    }
+}
+
+fn handle_connection<F>(
+    mut stream: TcpStream,
+    handler: &F,
+    received: &AtomicU64,
+) -> Result<(), std::io::Error>
+where
+    F: Fn(&str, &Value) -> Result<Value, String>,
+{
+    stream.set_read_timeout(Some(Duration::from_secs(READ_TIMEOUT_SECS)))?;
+    let mut reader = BufReader::new(stream.try_clone()?);
+
+    let (request_line, content_length) = match read_head(&mut reader)? {
+        Some(h) => h,
+        None => {
+            return write_response(
+                &mut stream,
+                "431 Request Header Fields Too Large",
+                &json!({}),
+            )
+        }
+    };
+
+    if !request_line.starts_with("POST ") {
+        return write_response(&mut stream, "405 Method Not Allowed", &json!({}));
+    }
+    if content_length > MAX_BODY_LEN {
+        return write_response(&mut stream, "413 Payload Too Large", &json!({}));
+    }
+    // Grown as the body arrives, a client only claiming a large body doesn't get the
+    // memory for it
+    let mut body = Vec::new();
+    reader
+        .by_ref()
+        .take(content_length as u64)
+        .read_to_end(&mut body)?;
+    if body.len() < content_length {
+        return Err(std::io::ErrorKind::UnexpectedEof.into());
+    }
+
+    let request: Value = match serde_json::from_slice(&body) {
+        Ok(r) => r,
+        Err(e) => {
+            let response = json!({
+                "jsonrpc": "2.0",
+                "id": null,
+                "error": {"code": -32700, "message": e.to_string()},
+            });
+            return write_response(&mut stream, "200 OK", &response);
+        }
+    };
+    let method = request["method"].as_str().unwrap_or_default();
+    let result = match handler(method, &request["params"]) {
+        Ok(v) => {
+            if matches!(method, "receive_tx" | "finalize_tx") {
+                received.fetch_add(1, Ordering::SeqCst);
+            }
+            json!({ "Ok": v })
+        }
+        Err(e) => json!({ "Err": { "GenericError": e } }),
+    };
+    let response = json!({
+        "jsonrpc": "2.0",
+        "id": request["id"],
+        "result": result,
+    });
+    write_response(&mut stream, "200 OK", &response)
+}
+
+/// Request line and content length, None if the headers don't end within `MAX_HEADER_LEN`
+fn read_head<R: BufRead>(reader: &mut R) -> Result<Option<(String, usize)>, std::io::Error> {
+    let mut limited = reader.take(MAX_HEADER_LEN);
+    let mut request_line = String::new();
+    limited.read_line(&mut request_line)?;
+    let mut content_length = 0;
+    loop {
+        let mut line = String::new();
+        if limited.read_line(&mut line)? == 0 {
+            // Out of bytes, either the limit or the end of the stream
+            if limited.limit() == 0 {
+                return Ok(None);
+            }
+            break;
+        }
+        let line = line.trim_end().to_lowercase();
+        if line.is_empty() {
+            break;
+        }
+        if let Some(l) = line.strip_prefix("content-length:") {
+            content_length = l.trim().parse().unwrap_or(0);
+        }
+    }
+    Ok(Some((request_line, content_length)))
+}
+
+fn write_response(
+    stream: &mut TcpStream,
+    status: &str,
+    body: &Value,
+) -> Result<(), std::io::Error> {
+    let body = body.to_string();
+    write!(
+        stream,
+        "HTTP/1.1 {}\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+        status,
+        body.len(),
+        body
+    )?;
+    stream.flush()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::wallet::tor::TorSender;
+    use crate::wallet::Slate;
+
+    /// Handler answering like a wallet that accepts every slate as is
+    fn echo_handler(method: &str, params: &Value) -> Result<Value, String> {
+        match method {
+            "check_version" => Ok(json!({
+                "foreign_api_version": 2,
+                "supported_slate_versions": ["V4"],
+            })),
+            "receive_tx" | "finalize_tx" => Ok(params[0].clone()),
+            _ => Err(format!("Unsupported method {}", method)),
+        }
+    }
+
+    fn post(addr: SocketAddr, request: &str) -> String {
+        let mut stream = TcpStream::connect(addr).unwrap();
+        stream.write_all(request.as_bytes()).unwrap();
+        let mut response = String::new();
+        stream.read_to_string(&mut response).unwrap();
+        response
+    }
+
+    #[test]
+    fn test_foreign_listener_receive() {
+        let listener = ForeignListener::start("127.0.0.1:0", echo_handler).unwrap();
+        let url = format!("http://{}/v2/foreign", listener.addr());
+        let slate = Slate::blank(2, false);
+
+        let ret = TorSender::with_url(&url).send_tx(&slate).unwrap();
+        assert_eq!(ret.id, slate.id);
+        assert_eq!(listener.received(), 1);
+    }
+
+    #[test]
+    fn test_foreign_listener_handler_error() {
+        let listener = ForeignListener::start("127.0.0.1:0", |_: &str, _: &Value| {
+            Err("Wallet is not open".to_owned())
+        })
+        .unwrap();
+        let url = format!("http://{}/v2/foreign", listener.addr());
+
+        match TorSender::with_url(&url).send_tx(&Slate::blank(2, false)) {
+            Err(GrinWalletInterfaceError::RecipientUnreachable(e)) => {
+                assert!(e.contains("Wallet is not open"))
+            }
+            r => panic!("expected RecipientUnreachable, got {:?}", r.map(|s| s.id)),
+        }
+        assert_eq!(listener.received(), 0);
+    }
+
+    #[test]
+    fn test_foreign_listener_finalize() {
+        let listener = ForeignListener::start("127.0.0.1:0", echo_handler).unwrap();
+        let body = json!({
+            "jsonrpc": "2.0",
+            "id": 1,
+            "method": "finalize_tx",
+            "params": [{"id": "0436430c-2b02-624c-2032-570501212b00"}],
+        })
+        .to_string();
+        let response = post(
+            listener.addr(),
+            &format!(
+                "POST /v2/foreign HTTP/1.1\r\nContent-Length: {}\r\n\r\n{}",
+                body.len(),
+                body
+            ),
+        );
+        assert!(response.starts_with("HTTP/1.1 200 OK"));
+        assert!(response.contains("\"Ok\""));
+        assert_eq!(listener.received(), 1);
+    }
+
+    #[test]
+    fn test_foreign_listener_limits_headers() {
+        let listener = ForeignListener::start("127.0.0.1:0", echo_handler).unwrap();
+        // Exactly as much as the listener reads, so it doesn't reset the connection over
+        // unread data before the response gets here
+        let mut request = "POST /v2/foreign HTTP/1.1\r\nX-Padding: ".to_owned();
+        request.push_str(&"x".repeat(MAX_HEADER_LEN as usize - request.len()));
+        let response = post(listener.addr(), &request);
+        assert!(response.starts_with("HTTP/1.1 431"));
+    }
+
+    #[test]
+    fn test_foreign_listener_concurrent_connections() {
+        let listener = ForeignListener::start("127.0.0.1:0", echo_handler).unwrap();
+        // Connects and then sends nothing, which used to hold up everyone else
+        let _idle = TcpStream::connect(listener.addr()).unwrap();
+
+        let url = format!("http://{}/v2/foreign", listener.addr());
+        let slate = Slate::blank(2, false);
+        let ret = TorSender::with_url(&url).send_tx(&slate).unwrap();
+        assert_eq!(ret.id, slate.id);
+    }
+
+    #[test]
+    fn test_foreign_listener_stop_releases_port() {
+        let mut listener = ForeignListener::start("127.0.0.1:0", echo_handler).unwrap();
+        let addr = listener.addr();
+        listener.stop();
+
+        // Port is free again and nothing answers on it
+        let rebound = TcpListener::bind(addr).unwrap();
+        drop(rebound);
+        let url = format!("http://{}/v2/foreign", addr);
+        assert!(TorSender::with_url(&url)
+            .send_tx(&Slate::blank(2, false))
+            .is_err());
+    }
+}
@@ -0,0 +1,741 @@
+/// Client side of MWMixnet (mwixnet) coinswaps. An output is handed to a chain of mix
+/// servers wrapped in an onion: each server peels its layer, adds its excess to the
+/// commitment and takes its fee, and the last one creates a fresh output that only our
+/// wallet can recognise. The onion and signature formats follow the mwixnet server.
+use blake2_rfc::blake2b::Blake2b;
+use chacha20::cipher::{KeyIvInit, StreamCipher};
+use chacha20::{ChaCha20, Key, Nonce};
+use chrono::{DateTime, Utc};
+use hmac::{Hmac, Mac};
+use sha2::Sha256;
+use x25519_dalek::{PublicKey as xPublicKey, StaticSecret as xSecretKey};
+
+use grin_util::secp::key::SecretKey;
+use grin_util::secp::pedersen::{Commitment, RangeProof};
+use grin_util::secp::Secp256k1;
+use grin_util::{from_hex, ToHex};
+
+use isahc::config::Configurable;
+use isahc::{ReadResponseExt, Request};
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+
+use std::path::Path;
+use std::time::Duration;
+
+use super::{OutputCommitMapping, OutputStatus};
+use crate::error::GrinWalletInterfaceError;
+
+/// Servers a swap goes through, one key each configured in `Config::mixnet_keys`
+pub const MIXNET_HOPS: usize = 3;
+
+/// Swaps handed to the servers from this wallet, kept in the wallet directory
+pub const MIXNET_SWAPS_FILE_NAME: &str = "mwixnet_swaps.json";
+
+const ONION_VERSION: u8 = 0;
+
+/// Key and nonce the stream cipher of each layer is derived with, as the servers expect
+const CIPHER_HMAC_KEY: &[u8] = b"MWIXNET";
+const CIPHER_NONCE: &[u8; 12] = b"NONCE1234567";
+
+/// The entry server may be an onion service, which takes a while to reach
+const REQUEST_TIMEOUT_SECS: u64 = 120;
+
+/// Parse a server's public key, 32 bytes of hex
+pub fn parse_server_key(key: &str) -> Option<xPublicKey> {
+    let bytes = from_hex(key.trim()).ok()?;
+    if bytes.len() != 32 || bytes.iter().all(|b| *b == 0) {
+        return None;
+    }
+    let mut key = [0u8; 32];
+    key.copy_from_slice(&bytes);
+    Some(xPublicKey::from(key))
+}
+
+/// Parse the configured keys, which must name exactly `MIXNET_HOPS` servers
+pub fn parse_server_keys(keys: &[String]) -> Result<Vec<xPublicKey>, GrinWalletInterfaceError> {
+    if keys.len() != MIXNET_HOPS || keys.iter().all(|k| k.trim().is_empty()) {
+        return Err(GrinWalletInterfaceError::MixnetNotConfigured);
+    }
+    keys.iter()
+        .enumerate()
+        .map(|(i, k)| {
+            parse_server_key(k).ok_or(GrinWalletInterfaceError::InvalidMixnetKey { index: i + 1 })
+        })
+        .collect()
+}
+
+fn request_err<E: std::fmt::Display>(e: E) -> GrinWalletInterfaceError {
+    GrinWalletInterfaceError::MixnetRequest(e.to_string())
+}
+
+fn random_secret_key(secp: &Secp256k1) -> Result<SecretKey, GrinWalletInterfaceError> {
+    SecretKey::from_slice(secp, &rand::random::<[u8; 32]>()).map_err(request_err)
+}
+
+fn random_x25519_key() -> xSecretKey {
+    xSecretKey::from(rand::random::<[u8; 32]>())
+}
+
+/// What one server learns when peeling its layer
+pub struct Hop {
+    pub server_pubkey: xPublicKey,
+    pub excess: SecretKey,
+    pub fee: u64,
+    /// Only the last server gets one, for the output it creates
+    pub rangeproof: Option<RangeProof>,
+}
+
+/// Payload of a layer, before encryption
+fn payload_bytes(next_ephemeral_pk: &[u8; 32], hop: &Hop) -> Vec<u8> {
+    let mut bytes = vec![ONION_VERSION];
+    bytes.extend_from_slice(next_ephemeral_pk);
+    bytes.extend_from_slice(&hop.excess.0);
+    bytes.extend_from_slice(&hop.fee.to_be_bytes());
+    match &hop.rangeproof {
+        Some(proof) => {
+            bytes.push(1);
+            bytes.extend_from_slice(&(proof.plen as u64).to_be_bytes());
+            bytes.extend_from_slice(&proof.proof[..proof.plen]);
+        }
+        None => bytes.push(0),
+    }
+    bytes
+}
+
+fn new_stream_cipher(shared_secret: &[u8]) -> Result<ChaCha20, GrinWalletInterfaceError> {
+    let mut mac = <Hmac<Sha256> as Mac>::new_from_slice(CIPHER_HMAC_KEY).map_err(request_err)?;
+    mac.update(shared_secret);
+    let key = mac.finalize().into_bytes();
+    Ok(ChaCha20::new(
+        Key::from_slice(&key[0..32]),
+        Nonce::from_slice(CIPHER_NONCE),
+    ))
+}
+
+/// Swap request as the entry server receives it. Every payload is encrypted for its own
+/// server and all servers before it, so each only ever reads its own layer.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Onion {
+    pub ephemeral_pubkey: xPublicKey,
+    pub commit: Commitment,
+    pub enc_payloads: Vec<Vec<u8>>,
+}
+
+impl Onion {
+    pub fn new(commit: Commitment, hops: &[Hop]) -> Result<Self, GrinWalletInterfaceError> {
+        let first_key = random_x25519_key();
+        let mut ephemeral_key = first_key.clone();
+        let mut shared_secrets = vec![];
+        let mut enc_payloads = vec![];
+        for (i, hop) in hops.iter().enumerate() {
+            let shared_secret = ephemeral_key.diffie_hellman(&hop.server_pubkey);
+            shared_secrets.push(shared_secret.as_bytes().to_vec());
+
+            // The next server gets a fresh key, the last one has nobody to pass on to
+            ephemeral_key = random_x25519_key();
+            let next_ephemeral_pk = if i < hops.len() - 1 {
+                *xPublicKey::from(&ephemeral_key).as_bytes()
+            } else {
+                [0u8; 32]
+            };
+            enc_payloads.push(payload_bytes(&next_ephemeral_pk, hop));
+        }
+
+        for i in (0..shared_secrets.len()).rev() {
+            let mut cipher = new_stream_cipher(&shared_secrets[i])?;
+            for payload in enc_payloads.iter_mut().skip(i) {
+                cipher.apply_keystream(payload);
+            }
+        }
+
+        Ok(Onion {
+            ephemeral_pubkey: xPublicKey::from(&first_key),
+            commit,
+            enc_payloads,
+        })
+    }
+
+    /// Serialized as the servers hash it for the ownership signature
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = self.ephemeral_pubkey.as_bytes().to_vec();
+        bytes.extend_from_slice(&self.commit.0);
+        bytes.extend_from_slice(&(self.enc_payloads.len() as u64).to_be_bytes());
+        for payload in &self.enc_payloads {
+            bytes.extend_from_slice(&(payload.len() as u64).to_be_bytes());
+            bytes.extend_from_slice(payload);
+        }
+        bytes
+    }
+
+    pub fn to_json(&self) -> Value {
+        json!({
+            "pubkey": self.ephemeral_pubkey.as_bytes().to_hex(),
+            "commit": self.commit.0.to_hex(),
+            "data": self.enc_payloads.iter().map(|p| p.to_hex()).collect::<Vec<_>>(),
+        })
+    }
+}
+
+/// Commitment signature, proving we know both the value and the blinding factor of the
+/// output being swapped without revealing either
+#[derive(Debug, Clone, PartialEq)]
+pub struct ComSignature {
+    pub_nonce: Commitment,
+    s: SecretKey,
+    t: SecretKey,
+}
+
+impl ComSignature {
+    pub fn sign(
+        secp: &Secp256k1,
+        amount: u64,
+        blind: &SecretKey,
+        msg: &[u8],
+    ) -> Result<Self, GrinWalletInterfaceError> {
+        let k_amount = amount_key(secp, amount)?;
+        let k_1 = random_secret_key(secp)?;
+        let k_2 = random_secret_key(secp)?;
+        let commit = secp.commit(amount, blind.clone()).map_err(request_err)?;
+        let pub_nonce = secp
+            .commit_blind(k_1.clone(), k_2.clone())
+            .map_err(request_err)?;
+        let e = challenge(secp, &commit, &pub_nonce, msg)?;
+
+        // s = k_1 + e * amount
+        let mut s = k_amount;
+        s.mul_assign(secp, &e).map_err(request_err)?;
+        s.add_assign(secp, &k_1).map_err(request_err)?;
+        // t = k_2 + e * blind
+        let mut t = blind.clone();
+        t.mul_assign(secp, &e).map_err(request_err)?;
+        t.add_assign(secp, &k_2).map_err(request_err)?;
+
+        Ok(ComSignature { pub_nonce, s, t })
+    }
+
+    pub fn verify(&self, secp: &Secp256k1, commit: &Commitment, msg: &[u8]) -> bool {
+        let check = || -> Result<bool, GrinWalletInterfaceError> {
+            let e = challenge(secp, commit, &self.pub_nonce, msg)?;
+            let lhs = secp
+                .commit_blind(self.s.clone(), self.t.clone())
+                .map_err(request_err)?;
+            let mut commit_e = commit.to_pubkey(secp).map_err(request_err)?;
+            commit_e.mul_assign(secp, &e).map_err(request_err)?;
+            let commit_e = Commitment::from_pubkey(secp, &commit_e).map_err(request_err)?;
+            let rhs = secp
+                .commit_sum(vec![commit_e, self.pub_nonce.clone()], vec![])
+                .map_err(request_err)?;
+            Ok(lhs == rhs)
+        };
+        check().unwrap_or(false)
+    }
+
+    pub fn to_hex(&self) -> String {
+        let mut bytes = self.pub_nonce.0.to_vec();
+        bytes.extend_from_slice(&self.s.0);
+        bytes.extend_from_slice(&self.t.0);
+        bytes.to_hex()
+    }
+}
+
+fn amount_key(secp: &Secp256k1, amount: u64) -> Result<SecretKey, GrinWalletInterfaceError> {
+    let mut bytes = [0u8; 32];
+    bytes[24..].copy_from_slice(&amount.to_be_bytes());
+    SecretKey::from_slice(secp, &bytes).map_err(request_err)
+}
+
+fn challenge(
+    secp: &Secp256k1,
+    commit: &Commitment,
+    pub_nonce: &Commitment,
+    msg: &[u8],
+) -> Result<SecretKey, GrinWalletInterfaceError> {
+    let mut hasher = Blake2b::new(32);
+    hasher.update(&commit.0);
+    hasher.update(&pub_nonce.0);
+    hasher.update(msg);
+    SecretKey::from_slice(secp, hasher.finalize().as_bytes()).map_err(request_err)
+}
+
+/// Everything the entry server needs for a swap
+#[derive(Debug, Clone)]
+pub struct SwapReq {
+    pub onion: Onion,
+    pub comsig: ComSignature,
+}
+
+impl SwapReq {
+    /// Swap of the output `value`/`blind_in` for one with `blind_out`, less `fee_per_hop`
+    /// for each server. `rangeproof` must prove the new output.
+    pub fn new(
+        secp: &Secp256k1,
+        value: u64,
+        blind_in: &SecretKey,
+        blind_out: &SecretKey,
+        rangeproof: RangeProof,
+        server_keys: &[xPublicKey],
+        fee_per_hop: u64,
+    ) -> Result<Self, GrinWalletInterfaceError> {
+        let commit = secp.commit(value, blind_in.clone()).map_err(request_err)?;
+
+        // Servers add their excess to the commitment, together they have to turn our
+        // blinding factor into the new one
+        let mut excesses = vec![];
+        for _ in 1..server_keys.len() {
+            excesses.push(random_secret_key(secp)?);
+        }
+        let mut negative = vec![blind_in.clone()];
+        negative.extend(excesses.iter().cloned());
+        let last_excess = secp
+            .blind_sum(vec![blind_out.clone()], negative)
+            .map_err(request_err)?;
+        excesses.push(last_excess);
+
+        let mut rangeproof = Some(rangeproof);
+        let hops: Vec<Hop> = server_keys
+            .iter()
+            .zip(excesses.into_iter())
+            .enumerate()
+            .map(|(i, (key, excess))| Hop {
+                server_pubkey: *key,
+                excess,
+                fee: fee_per_hop,
+                rangeproof: if i == server_keys.len() - 1 {
+                    rangeproof.take()
+                } else {
+                    None
+                },
+            })
+            .collect();
+
+        let onion = Onion::new(commit, &hops)?;
+        let comsig = ComSignature::sign(secp, value, blind_in, &onion.to_bytes())?;
+        Ok(SwapReq { onion, comsig })
+    }
+
+    pub fn to_json(&self) -> Value {
+        json!({
+            "onion": self.onion.to_json(),
+            "comsig": self.comsig.to_hex(),
+        })
+    }
+}
+
+/// Talks to the entry server of the mix, optionally through a Tor SOCKS proxy
+#[derive(Debug, Clone)]
+pub struct MixnetClient {
+    url: String,
+    socks_proxy_addr: Option<String>,
+}
+
+impl MixnetClient {
+    /// Client for the server at `url`. The proxy is only used for onion addresses.
+    pub fn new(url: &str, socks_proxy_addr: &str) -> Result<Self, GrinWalletInterfaceError> {
+        let url = url.trim();
+        if url.is_empty() {
+            return Err(GrinWalletInterfaceError::MixnetNotConfigured);
+        }
+        let is_onion = url
+            .parse::<isahc::http::Uri>()
+            .ok()
+            .and_then(|u| u.host().map(|h| h.ends_with(".onion")))
+            .unwrap_or(false);
+        Ok(MixnetClient {
+            url: url.to_owned(),
+            socks_proxy_addr: if is_onion {
+                Some(socks_proxy_addr.to_owned())
+            } else {
+                None
+            },
+        })
+    }
+
+    /// Hand the swap to the entry server. It only checks the request here, the swap itself
+    /// happens in the server's next mixing round.
+    pub fn swap(&self, req: &SwapReq) -> Result<(), GrinWalletInterfaceError> {
+        let unreachable = |e: String| GrinWalletInterfaceError::MixnetServer(e);
+        let body = json!({
+            "jsonrpc": "2.0",
+            "method": "swap",
+            "id": 1,
+            "params": [req.to_json()],
+        });
+        let mut builder = Request::post(&self.url)
+            .header("content-type", "application/json")
+            .timeout(Duration::from_secs(REQUEST_TIMEOUT_SECS));
+        if let Some(p) = &self.socks_proxy_addr {
+            let proxy = format!("socks5h://{}", p)
+                .parse()
+                .map_err(|e: isahc::http::uri::InvalidUri| unreachable(e.to_string()))?;
+            builder = builder.proxy(Some(proxy));
+        }
+        let request = builder.body(serde_json::to_vec(&body)?)?;
+        let mut response = isahc::send(request).map_err(|e| unreachable(e.to_string()))?;
+        if !response.status().is_success() {
+            return Err(unreachable(format!("HTTP status {}", response.status())));
+        }
+        let response: Value = response.json().map_err(|e| unreachable(e.to_string()))?;
+        if !response["error"].is_null() {
+            let message = response["error"]["message"]
+                .as_str()
+                .map(|m| m.to_owned())
+                .unwrap_or_else(|| response["error"].to_string());
+            return Err(unreachable(message));
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum SwapStatus {
+    /// Accepted by the entry server, waiting for the mix to confirm on chain
+    Pending,
+    /// The new output confirmed
+    Completed,
+    /// Turned down by the entry server
+    Failed(String),
+    /// Given up on before it completed, the input was unlocked again
+    Abandoned,
+}
+
+/// A swap submitted from this wallet
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct MixnetSwap {
+    pub input_commit: String,
+    pub output_commit: String,
+    /// Value of the new output, after fees
+    pub value: u64,
+    pub fee: u64,
+    pub submitted: DateTime<Utc>,
+    pub status: SwapStatus,
+}
+
+impl MixnetSwap {
+    /// Move a pending swap along once the wallet sees its new output confirmed. Returns
+    /// whether anything changed.
+    pub fn update_status(&mut self, outputs: &[OutputCommitMapping]) -> bool {
+        if self.status != SwapStatus::Pending {
+            return false;
+        }
+        let confirmed = outputs.iter().any(|o| {
+            o.output.commit.as_ref() == Some(&self.output_commit)
+                && matches!(o.output.status, OutputStatus::Unspent | OutputStatus::Spent)
+        });
+        if confirmed {
+            self.status = SwapStatus::Completed;
+        }
+        confirmed
+    }
+}
+
+/// Swaps recorded in the wallet directory `dir`, none if there's no record yet
+pub fn load_swaps(dir: &Path) -> Result<Vec<MixnetSwap>, GrinWalletInterfaceError> {
+    let path = dir.join(MIXNET_SWAPS_FILE_NAME);
+    if !path.exists() {
+        return Ok(vec![]);
+    }
+    let contents = std::fs::read_to_string(path)?;
+    Ok(serde_json::from_str(&contents)?)
+}
+
+pub fn save_swaps(dir: &Path, swaps: &[MixnetSwap]) -> Result<(), GrinWalletInterfaceError> {
+    let contents = serde_json::to_string_pretty(swaps)?;
+    std::fs::write(dir.join(MIXNET_SWAPS_FILE_NAME), contents)?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use grin_util::static_secp_instance;
+    use std::io::{BufRead, BufReader, Read, Write};
+    use std::net::TcpListener;
+    use std::sync::mpsc;
+    use std::thread;
+
+    /// A layer as a server sees it once peeled
+    struct Peeled {
+        next_ephemeral_pk: [u8; 32],
+        excess: SecretKey,
+        fee: u64,
+        has_rangeproof: bool,
+    }
+
+    /// What a server does with the onion: decrypt every payload with its shared secret
+    /// and read the first one
+    fn peel(
+        secp: &Secp256k1,
+        server_key: &xSecretKey,
+        ephemeral_pubkey: &xPublicKey,
+        enc_payloads: &mut Vec<Vec<u8>>,
+    ) -> Peeled {
+        let shared_secret = server_key.diffie_hellman(ephemeral_pubkey);
+        let mut cipher = new_stream_cipher(shared_secret.as_bytes()).unwrap();
+        for payload in enc_payloads.iter_mut() {
+            cipher.apply_keystream(payload);
+        }
+        let payload = enc_payloads.remove(0);
+        assert_eq!(payload[0], ONION_VERSION);
+        let mut next_ephemeral_pk = [0u8; 32];
+        next_ephemeral_pk.copy_from_slice(&payload[1..33]);
+        let mut fee = [0u8; 8];
+        fee.copy_from_slice(&payload[65..73]);
+        Peeled {
+            next_ephemeral_pk,
+            excess: SecretKey::from_slice(secp, &payload[33..65]).unwrap(),
+            fee: u64::from_be_bytes(fee),
+            has_rangeproof: payload[73] == 1,
+        }
+    }
+
+    fn server_keys() -> (Vec<xSecretKey>, Vec<xPublicKey>) {
+        let secret: Vec<xSecretKey> = (0..MIXNET_HOPS).map(|_| random_x25519_key()).collect();
+        let public = secret.iter().map(xPublicKey::from).collect();
+        (secret, public)
+    }
+
+    fn swap_req(server_keys: &[xPublicKey]) -> (SwapReq, SecretKey) {
+        let secp_inst = static_secp_instance();
+        let secp = secp_inst.lock();
+        let blind_in = random_secret_key(&secp).unwrap();
+        let blind_out = random_secret_key(&secp).unwrap();
+        let rangeproof = secp.bullet_proof(
+            5_000_000_000 - 3 * 50_000_000,
+            blind_out.clone(),
+            random_secret_key(&secp).unwrap(),
+            random_secret_key(&secp).unwrap(),
+            None,
+            None,
+        );
+        let req = SwapReq::new(
+            &secp,
+            5_000_000_000,
+            &blind_in,
+            &blind_out,
+            rangeproof,
+            server_keys,
+            50_000_000,
+        )
+        .unwrap();
+        (req, blind_out)
+    }
+
+    #[test]
+    fn test_parse_server_keys() {
+        let valid = "97444ae673bb92c713c1a2f7b8882ffbfc1c67401a280a775dce1a8651584332";
+        assert!(parse_server_key(valid).is_some());
+        assert!(parse_server_key("97444ae673bb92c7").is_none());
+        assert!(parse_server_key("not hex").is_none());
+        assert!(parse_server_key(&"00".repeat(32)).is_none());
+
+        let keys = vec![valid.to_owned(), valid.to_owned(), "zz".to_owned()];
+        assert!(matches!(
+            parse_server_keys(&keys),
+            Err(GrinWalletInterfaceError::InvalidMixnetKey { index: 3 })
+        ));
+        assert!(matches!(
+            parse_server_keys(&vec![String::new(); 3]),
+            Err(GrinWalletInterfaceError::MixnetNotConfigured)
+        ));
+        assert_eq!(
+            parse_server_keys(&vec![valid.to_owned(); 3]).unwrap().len(),
+            3
+        );
+    }
+
+    #[test]
+    fn test_onion_layers_end_in_new_output() {
+        let (secret, public) = server_keys();
+        let (req, blind_out) = swap_req(&public);
+
+        let secp_inst = static_secp_instance();
+        let secp = secp_inst.lock();
+        let mut commit = req.onion.commit.clone();
+        let mut ephemeral_pubkey = req.onion.ephemeral_pubkey;
+        let mut enc_payloads = req.onion.enc_payloads.clone();
+
+        for (i, key) in secret.iter().enumerate() {
+            let peeled = peel(&secp, key, &ephemeral_pubkey, &mut enc_payloads);
+            assert_eq!(peeled.fee, 50_000_000);
+            assert_eq!(peeled.has_rangeproof, i == MIXNET_HOPS - 1);
+
+            let excess = secp.commit(0, peeled.excess).unwrap();
+            let fee = secp.commit_value(peeled.fee).unwrap();
+            commit = secp.commit_sum(vec![commit, excess], vec![fee]).unwrap();
+            ephemeral_pubkey = xPublicKey::from(peeled.next_ephemeral_pk);
+        }
+        assert!(enc_payloads.is_empty());
+
+        let expected = secp
+            .commit(5_000_000_000 - 3 * 50_000_000, blind_out)
+            .unwrap();
+        assert_eq!(commit, expected);
+    }
+
+    #[test]
+    fn test_comsig() {
+        let (_, public) = server_keys();
+        let (req, _) = swap_req(&public);
+
+        let secp_inst = static_secp_instance();
+        let secp = secp_inst.lock();
+        let msg = req.onion.to_bytes();
+        assert!(req.comsig.verify(&secp, &req.onion.commit, &msg));
+        assert!(!req
+            .comsig
+            .verify(&secp, &req.onion.commit, b"another onion"));
+    }
+
+    /// Minimal stand-in for the entry server. Checks the ownership signature and that its
+    /// layer can be read, like the real server does before accepting a swap.
+    fn mock_mix_server(server_key: xSecretKey) -> (String, mpsc::Receiver<bool>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}/v1", listener.local_addr().unwrap());
+        let (tx, rx) = mpsc::channel();
+
+        thread::spawn(move || {
+            for stream in listener.incoming() {
+                let mut stream = stream.unwrap();
+                let mut reader = BufReader::new(stream.try_clone().unwrap());
+
+                let mut content_length = 0;
+                loop {
+                    let mut line = String::new();
+                    reader.read_line(&mut line).unwrap();
+                    let line = line.trim_end().to_lowercase();
+                    if line.is_empty() {
+                        break;
+                    }
+                    if let Some(l) = line.strip_prefix("content-length:") {
+                        content_length = l.trim().parse().unwrap();
+                    }
+                }
+                let mut body = vec![0u8; content_length];
+                reader.read_exact(&mut body).unwrap();
+                let request: Value = serde_json::from_slice(&body).unwrap();
+                assert_eq!(request["method"], "swap");
+
+                let swap = &request["params"][0];
+                let hex = |v: &Value| from_hex(v.as_str().unwrap()).unwrap();
+                let mut pubkey = [0u8; 32];
+                pubkey.copy_from_slice(&hex(&swap["onion"]["pubkey"]));
+                let onion = Onion {
+                    ephemeral_pubkey: xPublicKey::from(pubkey),
+                    commit: Commitment::from_vec(hex(&swap["onion"]["commit"])),
+                    enc_payloads: swap["onion"]["data"]
+                        .as_array()
+                        .unwrap()
+                        .iter()
+                        .map(hex)
+                        .collect(),
+                };
+                let comsig = hex(&swap["comsig"]);
+
+                let secp_inst = static_secp_instance();
+                let secp = secp_inst.lock();
+                let comsig = ComSignature {
+                    pub_nonce: Commitment::from_vec(comsig[0..33].to_vec()),
+                    s: SecretKey::from_slice(&secp, &comsig[33..65]).unwrap(),
+                    t: SecretKey::from_slice(&secp, &comsig[65..97]).unwrap(),
+                };
+                let valid = comsig.verify(&secp, &onion.commit, &onion.to_bytes());
+                if valid {
+                    let mut enc_payloads = onion.enc_payloads.clone();
+                    peel(
+                        &secp,
+                        &server_key,
+                        &onion.ephemeral_pubkey,
+                        &mut enc_payloads,
+                    );
+                }
+                let _ = tx.send(valid);
+
+                let response = if valid {
+                    json!({"jsonrpc": "2.0", "id": request["id"], "result": "success"})
+                } else {
+                    json!({"jsonrpc": "2.0", "id": request["id"],
+                        "error": {"code": -32602, "message": "InvalidComSignature"}})
+                };
+                let body = response.to_string();
+                write!(
+                    stream,
+                    "HTTP/1.1 200 OK\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                    body.len(),
+                    body
+                )
+                .unwrap();
+            }
+        });
+
+        (url, rx)
+    }
+
+    #[test]
+    fn test_swap_accepted() {
+        let (secret, public) = server_keys();
+        let (url, rx) = mock_mix_server(secret[0].clone());
+        let (req, _) = swap_req(&public);
+
+        MixnetClient::new(&url, "127.0.0.1:9050")
+            .unwrap()
+            .swap(&req)
+            .unwrap();
+        assert!(rx.recv().unwrap());
+    }
+
+    #[test]
+    fn test_swap_rejected() {
+        let (secret, public) = server_keys();
+        let (url, rx) = mock_mix_server(secret[0].clone());
+        let (mut req, _) = swap_req(&public);
+        // Signature no longer covers the onion
+        req.onion.enc_payloads.pop();
+
+        match MixnetClient::new(&url, "127.0.0.1:9050")
+            .unwrap()
+            .swap(&req)
+        {
+            Err(GrinWalletInterfaceError::MixnetServer(m)) => {
+                assert_eq!(m, "InvalidComSignature")
+            }
+            r => panic!("expected MixnetServer error, got {:?}", r),
+        }
+        assert!(!rx.recv().unwrap());
+    }
+
+    #[test]
+    fn test_swap_status() {
+        let mut swap = MixnetSwap {
+            input_commit: "08aa".to_owned(),
+            output_commit: "09bb".to_owned(),
+            value: 4_850_000_000,
+            fee: 150_000_000,
+            submitted: Utc::now(),
+            status: SwapStatus::Pending,
+        };
+        assert!(!swap.update_status(&[]));
+        assert_eq!(swap.status, SwapStatus::Pending);
+
+        let mut abandoned = swap.clone();
+        abandoned.status = SwapStatus::Abandoned;
+
+        let dir = tempfile::tempdir().unwrap();
+        assert!(load_swaps(dir.path()).unwrap().is_empty());
+        save_swaps(dir.path(), &[swap.clone(), abandoned.clone()]).unwrap();
+        assert_eq!(load_swaps(dir.path()).unwrap(), vec![swap, abandoned]);
+    }
+
+    #[test]
+    fn test_client_proxy_only_for_onion() {
+        let onion = "http://2u7xayajwr2dzbshswdnkzhvx3fmrwg7pwttmegqmyksdhy3o6ctm7id.onion/v1";
+        let client = MixnetClient::new(onion, "127.0.0.1:9050").unwrap();
+        assert_eq!(client.socks_proxy_addr.as_deref(), Some("127.0.0.1:9050"));
+        let client = MixnetClient::new("http://127.0.0.1:3000/v1", "127.0.0.1:9050").unwrap();
+        assert_eq!(client.socks_proxy_addr, None);
+        assert!(matches!(
+            MixnetClient::new(" ", "127.0.0.1:9050"),
+            Err(GrinWalletInterfaceError::MixnetNotConfigured)
+        ));
+    }
+}
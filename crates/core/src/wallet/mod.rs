@@ -1,18 +1,35 @@
 /// Placeholder for all wallet calls
-/// Should eventually feature async calls that work via local wallet or remote owner API
+/// Async calls work via the local wallet or a remote V3 owner API, see `remote`
+pub mod contacts;
+pub mod inbox;
+pub mod listener;
+pub mod mixnet;
+pub mod node_client;
+pub mod password;
+pub mod proof;
+pub mod recovery;
+pub mod remote;
+pub mod subscriber;
+pub mod tor;
+pub mod worker;
+
 use grin_wallet::cmd::wallet_args::inst_wallet;
 use grin_wallet_api::{Foreign, Owner};
 use grin_wallet_config::{self, GlobalWalletConfig};
 use grin_wallet_controller::command::InitArgs;
 use grin_wallet_impls::DefaultLCProvider;
+use grin_wallet_libwallet::slate_versions::{SlateVersion, VersionedSlate};
 use grin_wallet_libwallet::{NodeClient, WalletInst, WalletLCProvider};
 
 pub use grin_core::global;
 use grin_core::{self};
 use grin_keychain as keychain;
-use grin_util::{file, Mutex, ZeroingString};
+use grin_util::secp::key::SecretKey;
+use grin_util::{file, Mutex, ToHex, ZeroingString};
+pub use zeroize::Zeroizing;
 
 use super::node::amount_to_hr_string;
+use chrono::NaiveDate;
 use std::path::PathBuf;
 use std::sync::{Arc, RwLock};
 
@@ -20,24 +37,39 @@ use dirs;
 
 // Re-exports
 pub use global::ChainTypes;
+pub use grin_wallet_config::config_file_exists;
 pub use grin_wallet_impls::HTTPNodeClient;
 pub use grin_wallet_libwallet::{
-    InitTxArgs, RetrieveTxQueryArgs, RetrieveTxQuerySortOrder, Slate, SlateState, Slatepack,
-    SlatepackAddress, StatusMessage, TxLogEntry, TxLogEntryType, WalletInfo, 
+    AcctPathMapping, InitTxArgs, IssueInvoiceTxArgs, NodeHeightResult, OutputCommitMapping,
+    OutputData, OutputStatus, PaymentProof, RetrieveTxQueryArgs, RetrieveTxQuerySortOrder, Slate,
+    SlateState, Slatepack, SlatepackAddress, StatusMessage, TxLogEntry, TxLogEntryType, WalletInfo,
 };
+pub use node_client::{InterruptibleNodeClient, NodeInterrupt};
 
 pub use grin_wallet_libwallet::contract::types::{
-    ContractNewArgsAPI, ContractRevokeArgsAPI, ContractSetupArgsAPI, ProofArgs
+    ContractNewArgsAPI, ContractRevokeArgsAPI, ContractSetupArgsAPI, OutputSelectionArgs, ProofArgs,
 };
 
 pub use grin_wallet_libwallet::contract::proofs::InvoiceProof;
 
+use crate::config::WalletBackend;
 use crate::error::GrinWalletInterfaceError;
 use crate::logger;
+use listener::{ForeignListener, ListenerInfo};
+use mixnet::{MixnetClient, MixnetSwap, SwapReq, SwapStatus, MIXNET_HOPS};
+use proof::{
+    confirmed_between, parse_kernel_excess, proof_file_name, verify_payment_proof_signatures,
+    KernelStatus, ProofFile, ProofVerdict,
+};
+use remote::RemoteOwner;
+use tor::TorSender;
+use worker::JobContext;
 
+use grin_core::consensus::{INPUT_WEIGHT, KERNEL_WEIGHT, OUTPUT_WEIGHT};
+use grin_core::libtx::proof::{self as rangeproof, ProofBuilder};
+use keychain::{Keychain, SwitchCommitmentType};
 use std::convert::TryFrom;
 
-
 /// Wallet configuration file name
 pub const WALLET_CONFIG_FILE_NAME: &str = "grin-wallet.toml";
 
@@ -54,6 +86,8 @@ pub const GRIN_WALLET_DEFAULT_DIR: &str = "default";
 pub const API_SECRET_FILE_NAME: &str = ".foreign_api_secret";
 /// Owner API secret
 pub const OWNER_API_SECRET_FILE_NAME: &str = ".owner_api_secret";
+/// Account every wallet is created with, and which is active when a wallet is opened
+pub const DEFAULT_ACCOUNT_LABEL: &str = "default";
 
 /// TODO - this differs from the default directory in 5.x,
 /// need to reconcile this with existing installs somehow
@@ -86,9 +120,12 @@ pub fn create_grin_wallet_path(chain_type: &global::ChainTypes, sub_dir: &str) -
     grin_path
 }
 
+/// Password or recovery phrase, wiped from memory when dropped
+pub type SecretString = Zeroizing<String>;
+
 pub type WalletInterfaceHttpNodeClient = WalletInterface<
-    DefaultLCProvider<'static, HTTPNodeClient, keychain::ExtKeychain>,
-    HTTPNodeClient,
+    DefaultLCProvider<'static, InterruptibleNodeClient<HTTPNodeClient>, keychain::ExtKeychain>,
+    InterruptibleNodeClient<HTTPNodeClient>,
 >;
 
 pub struct WalletInterface<L, C>
@@ -104,10 +141,20 @@ where
     pub foreign_api: Option<Foreign<'static, L, C, keychain::ExtKeychain>>,
     // Simple flag to check whether wallet has been opened
     wallet_is_open: bool,
+    // Token from opening the wallet, the keychain held in memory is masked with it
+    keychain_mask: Option<SecretKey>,
     // Hold on to check node foreign API secret for now
     pub check_node_foreign_api_secret_path: Option<String>,
     // Whether to use embedded node for check node
     use_embedded_node: bool,
+    // Local or remote owner API, as configured for the current wallet
+    backend: WalletBackend,
+    // Set in place of owner_api when the wallet is opened over a remote owner API
+    remote_owner: Option<RemoteOwner>,
+    // Label of the account (parent key id) wallet calls currently operate on
+    active_account: String,
+    // Answers other wallets' sends while the wallet is open
+    foreign_listener: Option<ForeignListener>,
 
     node_client: C,
 }
@@ -135,6 +182,57 @@ pub fn parse_abs_tx_amount_fee(tx: &TxLogEntry, subtract_fee_from_amt: bool) ->
     )
 }
 
+/// What a send would cost, worked out without locking any outputs
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TxEstimate {
+    pub amount: u64,
+    pub fee: u64,
+    /// Sum of the inputs that would fund the send
+    pub inputs_total: u64,
+    pub num_inputs: u64,
+    pub change: u64,
+}
+
+impl TxEstimate {
+    /// Fails the way libwallet's own selection does if the inputs don't cover amount and fee
+    pub fn new(
+        amount: u64,
+        fee: u64,
+        inputs_total: u64,
+        num_inputs: u64,
+    ) -> Result<Self, GrinWalletInterfaceError> {
+        let needed = amount.saturating_add(fee);
+        if inputs_total < needed {
+            return Err(grin_wallet_libwallet::Error::NotEnoughFunds {
+                available: inputs_total,
+                available_disp: amount_to_hr_string(inputs_total, false),
+                needed,
+                needed_disp: amount_to_hr_string(needed, false),
+            }
+            .into());
+        }
+        Ok(Self {
+            amount,
+            fee,
+            inputs_total,
+            num_inputs,
+            change: inputs_total - needed,
+        })
+    }
+
+    /// Fee of a send with a single kernel
+    pub fn fee_for(num_inputs: u64, num_outputs: u64, fee_base: u64) -> u64 {
+        (num_inputs * INPUT_WEIGHT + num_outputs * OUTPUT_WEIGHT + KERNEL_WEIGHT) * fee_base
+    }
+
+    /// An estimate only returns the fee and the selected total, so recover the number
+    /// of inputs from the fee's weight
+    pub fn num_inputs_from_fee(fee: u64, num_outputs: u64, fee_base: u64) -> u64 {
+        let weight = fee / fee_base;
+        weight.saturating_sub(num_outputs * OUTPUT_WEIGHT + KERNEL_WEIGHT) / INPUT_WEIGHT
+    }
+}
+
 impl<L, C> WalletInterface<L, C>
 where
     L: WalletLCProvider<'static, C, grin_keychain::keychain::ExtKeychain>,
@@ -147,12 +245,21 @@ where
             owner_api: None,
             foreign_api: None,
             wallet_is_open: false,
+            keychain_mask: None,
             check_node_foreign_api_secret_path: None,
             node_client,
             use_embedded_node: true,
+            backend: WalletBackend::Local,
+            remote_owner: None,
+            active_account: DEFAULT_ACCOUNT_LABEL.to_owned(),
+            foreign_listener: None,
         }
     }
 
+    pub fn node_client(&self) -> &C {
+        &self.node_client
+    }
+
     fn set_chain_type(&mut self, chain_type: global::ChainTypes) {
         self.chain_type = Some(chain_type);
     }
@@ -189,6 +296,27 @@ where
         w.use_embedded_node = value;
     }
 
+    pub fn set_backend(
+        wallet_interface: Arc<RwLock<WalletInterface<L, C>>>,
+        backend: WalletBackend,
+    ) {
+        let mut w = wallet_interface.write().unwrap();
+        if w.backend != backend {
+            w.owner_api = None;
+            w.foreign_api = None;
+            w.remote_owner = None;
+        }
+        w.backend = backend;
+    }
+
+    pub fn is_remote(&self) -> bool {
+        self.backend.is_remote()
+    }
+
+    pub fn active_account(&self) -> &str {
+        &self.active_account
+    }
+
     /// Sets the top level directory of the wallet and creates default config if config
     /// doesn't already exist. The initial config is created based off of the chain type.
     fn inst_wallet(
@@ -203,16 +331,27 @@ where
         // path for config file
         let data_path = Some(top_level_directory.clone());
 
+        let config_error = |message: String| GrinWalletInterfaceError::WalletConfig {
+            path: top_level_directory.clone(),
+            message,
+        };
+
         // creates default config file for chain type at data path
-        let config =
-            grin_wallet_config::initial_setup_wallet(&chain_type, data_path, true).unwrap();
+        let config = grin_wallet_config::initial_setup_wallet(&chain_type, data_path, true)
+            .map_err(|e| config_error(e.to_string()))?;
+
+        let members = config
+            .members
+            .clone()
+            .ok_or_else(|| config_error("missing wallet configuration members".to_owned()))?;
 
         // Update logging config
-        let mut logging_config = config.members.as_ref().unwrap().logging.clone().unwrap();
-        logging_config.tui_running = Some(false);
-        logger::update_logging_config(logger::LogArea::Wallet, logging_config);
+        if let Some(mut logging_config) = members.logging {
+            logging_config.tui_running = Some(false);
+            logger::update_logging_config(logger::LogArea::Wallet, logging_config);
+        }
 
-        let wallet_config = config.clone().members.unwrap().wallet;
+        let wallet_config = members.wallet;
 
         // Set node client address and Foreign API Secret if needed
         if w.use_embedded_node {
@@ -225,16 +364,23 @@ where
         }
 
         let wallet_inst =
-            inst_wallet(wallet_config.clone(), w.node_client.clone()).unwrap_or_else(|e| {
-                println!("{}", e);
-                std::process::exit(1);
-            });
+            inst_wallet(wallet_config.clone(), w.node_client.clone()).map_err(|e| {
+                GrinWalletInterfaceError::WalletInstantiation {
+                    path: top_level_directory.clone(),
+                    message: e.to_string(),
+                }
+            })?;
 
         {
+            let top_level_directory = top_level_directory.to_str().ok_or_else(|| {
+                GrinWalletInterfaceError::InvalidWalletPath {
+                    path: top_level_directory.clone(),
+                }
+            })?;
             let mut wallet_lock = wallet_inst.lock();
-            let lc = wallet_lock.lc_provider().unwrap();
+            let lc = wallet_lock.lc_provider()?;
             // set top level directory
-            lc.set_top_level_directory(top_level_directory.to_str().unwrap());
+            lc.set_top_level_directory(top_level_directory)?;
         }
 
         w.config = Some(config);
@@ -262,12 +408,12 @@ where
 
     pub async fn init(
         wallet_interface: Arc<RwLock<WalletInterface<L, C>>>,
-        password: String,
+        password: SecretString,
         top_level_directory: PathBuf,
         display_name: String,
         chain_type: global::ChainTypes,
-        recovery_phrase: Option<String>,
-    ) -> Result<(String, String, String, global::ChainTypes), GrinWalletInterfaceError> {
+        recovery_phrase: Option<SecretString>,
+    ) -> Result<(String, SecretString, String, global::ChainTypes), GrinWalletInterfaceError> {
         WalletInterface::inst_apis(
             wallet_interface.clone(),
             chain_type,
@@ -276,12 +422,12 @@ where
 
         let w = wallet_interface.read().unwrap();
 
-        let recover_length = recovery_phrase.clone().map(|f| f.len()).unwrap_or(32);
-        let recover_phrase = recovery_phrase.map(|f| ZeroingString::from(f));
+        let recover_length = recovery_phrase.as_ref().map(|f| f.len()).unwrap_or(32);
+        let recover_phrase = recovery_phrase.map(|f| ZeroingString::from(f.as_str()));
 
         let args = InitArgs {
             list_length: recover_length,
-            password: password.clone().into(),
+            password: ZeroingString::from(password.as_str()),
             config: w.config.clone().unwrap().clone().members.unwrap().wallet,
             recovery_phrase: recover_phrase.clone(),
             restore: recover_phrase.is_some(),
@@ -326,9 +472,10 @@ where
                     p.get_top_level_directory()?
                 };
 
-                (tld, o.get_mnemonic(None, args.password)?.to_string())
+                let phrase = o.get_mnemonic(None, args.password)?;
+                (tld, Zeroizing::new(phrase.to_string()))
             }
-            None => ("".to_string(), "".to_string()),
+            None => ("".to_string(), Default::default()),
         };
 
         Ok((tld, ret_phrase, display_name, chain_type))
@@ -336,10 +483,34 @@ where
 
     pub async fn open_wallet(
         wallet_interface: Arc<RwLock<WalletInterface<L, C>>>,
-        password: String,
+        password: SecretString,
         top_level_directory: PathBuf,
         chain_type: global::ChainTypes,
     ) -> Result<(), GrinWalletInterfaceError> {
+        let backend = wallet_interface.read().unwrap().backend.clone();
+        if let WalletBackend::RemoteOwnerApi {
+            url,
+            api_secret_path,
+        } = backend
+        {
+            let api_secret =
+                file::get_first_line(api_secret_path.map(|p| p.to_string_lossy().to_string()));
+            let mut r = RemoteOwner::new(&url, api_secret);
+            r.init_secure_api()?;
+            r.open_wallet(&password)?;
+            r.start_updater(std::time::Duration::from_secs(60))?;
+            global::set_local_chain_type(chain_type);
+
+            let mut w = wallet_interface.write().unwrap();
+            w.owner_api = None;
+            w.foreign_api = None;
+            w.remote_owner = Some(r);
+            w.active_account = DEFAULT_ACCOUNT_LABEL.to_owned();
+            w.wallet_is_open = true;
+            w.set_chain_type(chain_type);
+            return Ok(());
+        }
+
         WalletInterface::inst_apis(
             wallet_interface.clone(),
             chain_type,
@@ -349,10 +520,14 @@ where
         let mut w = wallet_interface.write().unwrap();
 
         if let Some(o) = &w.owner_api {
-            // ignoring secret key
-            let _ = o.open_wallet(None, password.into(), false)?;
+            let mask = o.open_wallet(None, ZeroingString::from(password.as_str()), true)?;
             // Start the updater
-            o.start_updater(None, std::time::Duration::from_secs(60))?;
+            o.start_updater(mask.as_ref(), std::time::Duration::from_secs(60))?;
+            // The foreign API needs the mask to get at the keychain too
+            let foreign = Foreign::new(o.wallet_inst.clone(), mask.clone(), None, false);
+            w.foreign_api = Some(foreign);
+            w.keychain_mask = mask;
+            w.active_account = DEFAULT_ACCOUNT_LABEL.to_owned();
             w.wallet_is_open = true;
             // set wallet interface chain type
             w.set_chain_type(chain_type);
@@ -365,20 +540,199 @@ where
     pub async fn close_wallet(
         wallet_interface: Arc<RwLock<WalletInterface<L, C>>>,
     ) -> Result<(), GrinWalletInterfaceError> {
+        WalletInterface::stop_foreign_listener(wallet_interface.clone());
         let mut w = wallet_interface.write().unwrap();
+        if let Some(r) = w.remote_owner.as_ref() {
+            r.stop_updater()?;
+        }
+        if let Some(mut r) = w.remote_owner.take() {
+            w.wallet_is_open = false;
+            r.close_wallet()?;
+            return Ok(());
+        }
         if let Some(o) = &w.owner_api {
-            o.close_wallet(None);
+            o.stop_updater()?;
+            o.close_wallet(None)?;
             w.wallet_is_open = false;
+            w.keychain_mask = None;
             return Ok(());
         } else {
             return Err(GrinWalletInterfaceError::OwnerAPINotInstantiated);
         }
     }
 
+    /// Change the open wallet's password, then re-open it with the new one so nothing keeps
+    /// running on the old password. If the change fails the wallet stays open as it was. Once
+    /// it's made the new password is in effect, and if the wallet can't be re-opened with it
+    /// it's left closed and `PasswordChangedReopenFailed` is returned.
+    pub async fn change_password(
+        wallet_interface: Arc<RwLock<WalletInterface<L, C>>>,
+        old_password: SecretString,
+        new_password: SecretString,
+    ) -> Result<(), GrinWalletInterfaceError> {
+        let mut w = wallet_interface.write().unwrap();
+        if !w.wallet_is_open {
+            return Err(GrinWalletInterfaceError::WalletNotOpen);
+        }
+        let reopened = if let Some(r) = w.remote_owner.as_mut() {
+            r.change_password(&old_password, &new_password)?;
+            (|| -> Result<(), GrinWalletInterfaceError> {
+                r.close_wallet()?;
+                r.init_secure_api()?;
+                r.open_wallet(&new_password)?;
+                r.start_updater(std::time::Duration::from_secs(60))?;
+                Ok(())
+            })()
+        } else if let Some(o) = &w.owner_api {
+            o.change_password(
+                None,
+                ZeroingString::from(old_password.as_str()),
+                ZeroingString::from(new_password.as_str()),
+            )?;
+            let reopened = (|| -> Result<_, GrinWalletInterfaceError> {
+                o.stop_updater()?;
+                o.close_wallet(None)?;
+                let mask = o.open_wallet(None, ZeroingString::from(new_password.as_str()), true)?;
+                o.start_updater(mask.as_ref(), std::time::Duration::from_secs(60))?;
+                let foreign = Foreign::new(o.wallet_inst.clone(), mask.clone(), None, false);
+                Ok((foreign, mask))
+            })();
+            reopened.map(|(foreign, mask)| {
+                w.foreign_api = Some(foreign);
+                w.keychain_mask = mask;
+            })
+        } else {
+            return Err(GrinWalletInterfaceError::OwnerAPINotInstantiated);
+        };
+
+        if let Err(e) = reopened {
+            w.wallet_is_open = false;
+            w.keychain_mask = None;
+            w.foreign_api = None;
+            drop(w);
+            WalletInterface::stop_foreign_listener(wallet_interface);
+            return Err(GrinWalletInterfaceError::PasswordChangedReopenFailed(
+                e.to_string(),
+            ));
+        }
+        Ok(())
+    }
+
+    /// Start answering foreign API calls on `addr`, optionally also published as a Tor hidden
+    /// service at the wallet's slatepack address. Any listener already running is replaced
+    pub async fn start_foreign_listener(
+        wallet_interface: Arc<RwLock<WalletInterface<L, C>>>,
+        addr: String,
+        use_tor: bool,
+    ) -> Result<ListenerInfo, GrinWalletInterfaceError> {
+        {
+            let w = wallet_interface.read().unwrap();
+            // A remote wallet runs its own listener
+            if w.remote_owner.is_some() {
+                return Err(GrinWalletInterfaceError::NotSupportedByRemoteOwnerAPI);
+            }
+            if w.foreign_api.is_none() {
+                return Err(GrinWalletInterfaceError::ForeignAPINotInstantiated);
+            }
+        }
+        WalletInterface::stop_foreign_listener(wallet_interface.clone());
+
+        let handler_wallet = wallet_interface.clone();
+        let mut listener = ForeignListener::start(&addr, move |method, params| {
+            let w = handler_wallet.read().unwrap();
+            w.handle_foreign_call(method, params)
+        })?;
+
+        if use_tor {
+            let w = wallet_interface.read().unwrap();
+            let o = match &w.owner_api {
+                Some(o) => o,
+                None => return Err(GrinWalletInterfaceError::OwnerAPINotInstantiated),
+            };
+            let tor_config = w
+                .config
+                .as_ref()
+                .and_then(|c| c.members.as_ref())
+                .and_then(|m| m.tor.clone())
+                .unwrap_or_default();
+            let (process, address) = grin_wallet_controller::controller::init_tor_listener(
+                o.wallet_inst.clone(),
+                Arc::new(Mutex::new(w.keychain_mask.clone())),
+                &listener.addr().to_string(),
+                tor_config.bridge,
+                tor_config.proxy,
+            )?;
+            let onion = grin_wallet_util::OnionV3Address::try_from(&address)
+                .map_err(|e| GrinWalletInterfaceError::ForeignListener(e.to_string()))?;
+            listener.set_tor(process, format!("{}.onion", onion));
+        }
+
+        let info = listener.info();
+        wallet_interface.write().unwrap().foreign_listener = Some(listener);
+        Ok(info)
+    }
+
+    pub fn stop_foreign_listener(wallet_interface: Arc<RwLock<WalletInterface<L, C>>>) {
+        // Taken out first, the listener thread needs the lock to finish a pending request
+        let listener = wallet_interface.write().unwrap().foreign_listener.take();
+        if let Some(mut l) = listener {
+            l.stop();
+        }
+    }
+
+    /// Number of slates received by the running listener, if there is one
+    pub fn foreign_listener_received(&self) -> Option<u64> {
+        self.foreign_listener.as_ref().map(|l| l.received())
+    }
+
+    /// Foreign API calls other wallets may make while sending to us
+    fn handle_foreign_call(
+        &self,
+        method: &str,
+        params: &serde_json::Value,
+    ) -> Result<serde_json::Value, String> {
+        let f = match &self.foreign_api {
+            Some(f) if self.wallet_is_open => f,
+            _ => return Err("Wallet is not open".to_owned()),
+        };
+        match method {
+            "check_version" => {
+                let version = f.check_version().map_err(|e| e.to_string())?;
+                serde_json::to_value(version).map_err(|e| e.to_string())
+            }
+            "receive_tx" => {
+                let slate: VersionedSlate =
+                    serde_json::from_value(params[0].clone()).map_err(|e| e.to_string())?;
+                let dest_acct_name = params[1].as_str();
+                let ret_slate = f
+                    .receive_tx(&Slate::from(slate), dest_acct_name, None)
+                    .map_err(|e| e.to_string())?;
+                let ret_slate = VersionedSlate::into_version(ret_slate, SlateVersion::V4)
+                    .map_err(|e| e.to_string())?;
+                serde_json::to_value(ret_slate).map_err(|e| e.to_string())
+            }
+            // A payer returning one of our invoices, finalized and posted like grin-wallet does
+            "finalize_tx" => {
+                let slate: VersionedSlate =
+                    serde_json::from_value(params[0].clone()).map_err(|e| e.to_string())?;
+                let ret_slate = f
+                    .finalize_tx(&Slate::from(slate), true)
+                    .map_err(|e| e.to_string())?;
+                let ret_slate = VersionedSlate::into_version(ret_slate, SlateVersion::V4)
+                    .map_err(|e| e.to_string())?;
+                serde_json::to_value(ret_slate).map_err(|e| e.to_string())
+            }
+            _ => Err(format!("Unsupported method {}", method)),
+        }
+    }
+
     pub fn get_wallet_updater_status(
         wallet_interface: Arc<RwLock<WalletInterface<L, C>>>,
     ) -> Result<Vec<StatusMessage>, GrinWalletInterfaceError> {
         let w = wallet_interface.read().unwrap();
+        if let Some(r) = &w.remote_owner {
+            return r.get_updater_messages(1);
+        }
         if let Some(o) = &w.owner_api {
             let res = o.get_updater_messages(1)?;
             return Ok(res);
@@ -389,6 +743,7 @@ where
 
     pub fn encrypt_slatepack(
         api: &Owner<L, C, keychain::ExtKeychain>,
+        keychain_mask: Option<&SecretKey>,
         dest: &str,
         unenc_slate: &Slate,
     ) -> Result<String, GrinWalletInterfaceError> {
@@ -401,7 +756,7 @@ where
             Some(a) => vec![a],
             None => vec![],
         };
-        Ok(api.create_slatepack_message(None, &unenc_slate, Some(0), recipients)?)
+        Ok(api.create_slatepack_message(keychain_mask, &unenc_slate, Some(0), recipients)?)
     }
 
     /// Attempt to decode and decrypt a given slatepack, also return associated transaction (if we can find it)
@@ -410,10 +765,19 @@ where
         slatepack: String,
     ) -> Result<(Slatepack, Slate, Option<TxLogEntry>), GrinWalletInterfaceError> {
         let w = wallet_interface.read().unwrap();
+        if let Some(r) = &w.remote_owner {
+            let sp = r.decode_slatepack_message(slatepack.clone(), vec![0])?;
+            let slate = r.slate_from_slatepack_message(slatepack, vec![0])?;
+            let txs = r.retrieve_txs(false, None, Some(slate.id.to_string()))?;
+            return Ok((sp, slate, txs.1.into_iter().next()));
+        }
         if let Some(o) = &w.owner_api {
-            let sp = o.decode_slatepack_message(None, slatepack.clone(), vec![0])?;
-            let slate = o.slate_from_slatepack_message(None, slatepack, vec![0])?;
-            let txs = o.retrieve_txs(None, false, None, Some(slate.id), None)?;
+            let sp =
+                o.decode_slatepack_message(w.keychain_mask.as_ref(), slatepack.clone(), vec![0])?;
+            let slate =
+                o.slate_from_slatepack_message(w.keychain_mask.as_ref(), slatepack, vec![0])?;
+            let txs =
+                o.retrieve_txs(w.keychain_mask.as_ref(), false, None, Some(slate.id), None)?;
             let ret_tx = if txs.1.len() > 0 {
                 Some(txs.1[0].clone())
             } else {
@@ -425,13 +789,36 @@ where
         }
     }
 
+    /// Rescan the chain from `start_height` (or genesis), repairing outputs and transactions.
+    /// Progress arrives as `StatusMessage::Scanning` through `get_wallet_updater_status`.
+    /// Only a read lock is held so status can be polled while the scan runs.
+    pub async fn scan(
+        wallet_interface: Arc<RwLock<WalletInterface<L, C>>>,
+        start_height: Option<u64>,
+        delete_unconfirmed: bool,
+    ) -> Result<(), GrinWalletInterfaceError> {
+        let w = wallet_interface.read().unwrap();
+        if let Some(r) = &w.remote_owner {
+            return r.scan(start_height, delete_unconfirmed);
+        }
+        if let Some(o) = &w.owner_api {
+            o.scan(w.keychain_mask.as_ref(), start_height, delete_unconfirmed)?;
+            return Ok(());
+        } else {
+            return Err(GrinWalletInterfaceError::OwnerAPINotInstantiated);
+        }
+    }
+
     pub async fn get_wallet_info(
         wallet_interface: Arc<RwLock<WalletInterface<L, C>>>,
         refresh_from_node: bool,
     ) -> Result<(bool, WalletInfo), GrinWalletInterfaceError> {
         let w = wallet_interface.read().unwrap();
+        if let Some(r) = &w.remote_owner {
+            return r.retrieve_summary_info(refresh_from_node, 2);
+        }
         if let Some(o) = &w.owner_api {
-            let res = o.retrieve_summary_info(None, refresh_from_node, 2)?;
+            let res = o.retrieve_summary_info(w.keychain_mask.as_ref(), refresh_from_node, 2)?;
             return Ok(res);
         } else {
             return Err(GrinWalletInterfaceError::OwnerAPINotInstantiated);
@@ -443,11 +830,19 @@ where
         query_args: Option<RetrieveTxQueryArgs>,
     ) -> Result<(bool, Vec<TxLogEntry>), GrinWalletInterfaceError> {
         let w = wallet_interface.read().unwrap();
+        if let Some(r) = &w.remote_owner {
+            let (refreshed, txs) = r.retrieve_txs(true, None, None)?;
+            let txs = match query_args {
+                Some(q) => remote::apply_tx_query_args(txs, &q),
+                None => txs,
+            };
+            return Ok((refreshed, txs));
+        }
         if let Some(o) = &w.owner_api {
-            let res = o.retrieve_txs(None, true, None, None, query_args)?;
+            let res = o.retrieve_txs(w.keychain_mask.as_ref(), true, None, None, query_args)?;
             /*for tx in &mut res.1 {
                 if tx.amount_credited == 0 && tx.amount_debited == 0 {
-                    let saved_tx = o.get_stored_tx(None, Some(tx.id), None);
+                    let saved_tx = o.get_stored_tx(w.keychain_mask.as_ref(), Some(tx.id), None);
                     if let Ok(st) = saved_tx {
                         // Todo: have to check more things here, this is just for tx display
                         if let Some(s) = st {
@@ -463,18 +858,381 @@ where
         }
     }
 
+    /// Transactions as the wallet last stored them, without asking the node first. Cheap
+    /// enough to poll, the wallet's own updater keeps them current.
+    pub fn get_stored_txs(
+        wallet_interface: Arc<RwLock<WalletInterface<L, C>>>,
+    ) -> Result<Vec<TxLogEntry>, GrinWalletInterfaceError> {
+        let w = wallet_interface.read().unwrap();
+        if let Some(r) = &w.remote_owner {
+            return Ok(r.retrieve_txs(false, None, None)?.1);
+        }
+        if let Some(o) = &w.owner_api {
+            let (_, txs) = o.retrieve_txs(w.keychain_mask.as_ref(), false, None, None, None)?;
+            return Ok(txs);
+        } else {
+            return Err(GrinWalletInterfaceError::OwnerAPINotInstantiated);
+        }
+    }
+
+    /// Outputs of the active account along with the chain height they were checked against,
+    /// needed to work out confirmations and coinbase maturity
+    pub async fn get_outputs(
+        wallet_interface: Arc<RwLock<WalletInterface<L, C>>>,
+        include_spent: bool,
+        refresh_from_node: bool,
+    ) -> Result<(bool, u64, Vec<OutputCommitMapping>), GrinWalletInterfaceError> {
+        let w = wallet_interface.read().unwrap();
+        if let Some(r) = &w.remote_owner {
+            let (refreshed, outputs) =
+                r.retrieve_outputs(include_spent, refresh_from_node, None)?;
+            let height = r.node_height()?.height;
+            return Ok((refreshed, height, outputs));
+        }
+        if let Some(o) = &w.owner_api {
+            let (refreshed, outputs) = o.retrieve_outputs(
+                w.keychain_mask.as_ref(),
+                include_spent,
+                refresh_from_node,
+                None,
+            )?;
+            let height = o.node_height(w.keychain_mask.as_ref())?.height;
+            return Ok((refreshed, height, outputs));
+        } else {
+            return Err(GrinWalletInterfaceError::OwnerAPINotInstantiated);
+        }
+    }
+
+    /// Hand an unspent output to the MWMixnet servers for a coinswap. Once the entry server
+    /// accepts it the output is locked, and the one the mix will create is recorded so the
+    /// wallet picks it up when it confirms.
+    pub async fn mixnet_swap(
+        wallet_interface: Arc<RwLock<WalletInterface<L, C>>>,
+        commit: String,
+        server_keys: Vec<String>,
+        fee_per_hop: u64,
+        client: MixnetClient,
+    ) -> Result<MixnetSwap, GrinWalletInterfaceError> {
+        let server_keys = mixnet::parse_server_keys(&server_keys)?;
+        let unavailable = |message: &str| GrinWalletInterfaceError::MixnetOutputUnavailable {
+            commit: commit.clone(),
+            message: message.to_owned(),
+        };
+        let request_err = |e: String| GrinWalletInterfaceError::MixnetRequest(e);
+
+        let fee = fee_per_hop
+            .checked_mul(MIXNET_HOPS as u64)
+            .ok_or_else(|| request_err("Mix fee per hop is too large".to_owned()))?;
+
+        // The wallet is only held while the swap is built and again while it's saved, the
+        // server may be slow to reach
+        let (req, input, output) = {
+            let w = wallet_interface.read().unwrap();
+            if w.remote_owner.is_some() {
+                // The blinding factors never leave a remote wallet
+                return Err(GrinWalletInterfaceError::NotSupportedByRemoteOwnerAPI);
+            }
+            let o = match &w.owner_api {
+                Some(o) => o,
+                None => return Err(GrinWalletInterfaceError::OwnerAPINotInstantiated),
+            };
+            let mask = w.keychain_mask.as_ref();
+
+            let mut w_lock = o.wallet_inst.lock();
+            let lc = w_lock.lc_provider()?;
+            let backend = lc.wallet_inst()?;
+            let keychain = backend.keychain(mask)?;
+            let parent_key_id = backend.parent_key_id();
+
+            let input = backend
+                .iter()
+                .find(|o| o.root_key_id == parent_key_id && o.commit.as_ref() == Some(&commit))
+                .ok_or_else(|| unavailable("not found in the active account"))?;
+            if input.status != OutputStatus::Unspent {
+                return Err(unavailable("only unspent outputs can be swapped"));
+            }
+            if input.value <= fee {
+                return Err(unavailable("value doesn't cover the mix fees"));
+            }
+            let value = input.value - fee;
+
+            let blind_in = keychain
+                .derive_key(input.value, &input.key_id, SwitchCommitmentType::Regular)
+                .map_err(|e| request_err(e.to_string()))?;
+            let key_id = backend.next_child(mask)?;
+            let blind_out = keychain
+                .derive_key(value, &key_id, SwitchCommitmentType::Regular)
+                .map_err(|e| request_err(e.to_string()))?;
+            let commit_out = keychain
+                .commit(value, &key_id, SwitchCommitmentType::Regular)
+                .map_err(|e| request_err(e.to_string()))?;
+            let proof = rangeproof::create(
+                &keychain,
+                &ProofBuilder::new(&keychain),
+                value,
+                &key_id,
+                SwitchCommitmentType::Regular,
+                commit_out,
+                None,
+            )
+            .map_err(|e| request_err(e.to_string()))?;
+
+            let req = SwapReq::new(
+                keychain.secp(),
+                input.value,
+                &blind_in,
+                &blind_out,
+                proof,
+                &server_keys,
+                fee_per_hop,
+            )?;
+            let output = OutputData {
+                root_key_id: parent_key_id,
+                key_id: key_id.clone(),
+                n_child: key_id.to_path().last_path_index(),
+                commit: Some(commit_out.0.to_hex()),
+                mmr_index: None,
+                value,
+                status: OutputStatus::Unconfirmed,
+                height: backend.last_confirmed_height()?,
+                lock_height: 0,
+                is_coinbase: false,
+                tx_log_entry: None,
+            };
+            (req, input, output)
+        };
+
+        let mut swap = MixnetSwap {
+            input_commit: commit.clone(),
+            output_commit: output.commit.clone().unwrap_or_default(),
+            value: output.value,
+            fee,
+            submitted: chrono::Utc::now(),
+            status: SwapStatus::Pending,
+        };
+        if let Err(e) = client.swap(&req) {
+            swap.status = SwapStatus::Failed(e.to_string());
+            return Ok(swap);
+        }
+
+        let w = wallet_interface.read().unwrap();
+        // Closed while the server was asked, should the mix go through a rescan finds it
+        let o = match &w.owner_api {
+            Some(o) => o,
+            None => return Err(GrinWalletInterfaceError::OwnerAPINotInstantiated),
+        };
+        let mask = w.keychain_mask.as_ref();
+        let mut w_lock = o.wallet_inst.lock();
+        let lc = w_lock.lc_provider()?;
+        let backend = lc.wallet_inst()?;
+        // Read again, the listener or updater may have moved it on in the meantime
+        let mut input = match backend.iter().find(|o| o.key_id == input.key_id) {
+            Some(i) if i.status == OutputStatus::Unspent => i,
+            _ => {
+                swap.status = SwapStatus::Failed(
+                    "Output was spent or locked while the swap was submitted".to_owned(),
+                );
+                return Ok(swap);
+            }
+        };
+        let mut batch = backend.batch(mask)?;
+        batch.lock_output(&mut input)?;
+        batch.save(output)?;
+        batch.commit()?;
+        Ok(swap)
+    }
+
+    /// Give up on a swap the mix hasn't completed, unlocking its input and dropping the
+    /// output it was to create. Should the mix go through after all, a rescan finds it.
+    pub async fn mixnet_abandon_swap(
+        wallet_interface: Arc<RwLock<WalletInterface<L, C>>>,
+        mut swap: MixnetSwap,
+    ) -> Result<MixnetSwap, GrinWalletInterfaceError> {
+        let w = wallet_interface.read().unwrap();
+        if w.remote_owner.is_some() {
+            return Err(GrinWalletInterfaceError::NotSupportedByRemoteOwnerAPI);
+        }
+        let o = match &w.owner_api {
+            Some(o) => o,
+            None => return Err(GrinWalletInterfaceError::OwnerAPINotInstantiated),
+        };
+        let mask = w.keychain_mask.as_ref();
+
+        let mut w_lock = o.wallet_inst.lock();
+        let lc = w_lock.lc_provider()?;
+        let backend = lc.wallet_inst()?;
+        let input = backend
+            .iter()
+            .find(|o| o.commit.as_ref() == Some(&swap.input_commit));
+        let output = backend
+            .iter()
+            .find(|o| o.commit.as_ref() == Some(&swap.output_commit));
+        if let Some(o) = &output {
+            if o.status != OutputStatus::Unconfirmed {
+                return Err(GrinWalletInterfaceError::MixnetOutputUnavailable {
+                    commit: swap.output_commit.clone(),
+                    message: "the swap has already gone through".to_owned(),
+                });
+            }
+        }
+
+        let mut batch = backend.batch(mask)?;
+        if let Some(mut input) = input {
+            if input.status == OutputStatus::Locked {
+                input.status = OutputStatus::Unspent;
+                batch.save(input)?;
+            }
+        }
+        if let Some(output) = output {
+            batch.delete(&output.key_id, &output.mmr_index)?;
+        }
+        batch.commit()?;
+        swap.status = SwapStatus::Abandoned;
+        Ok(swap)
+    }
+
     pub async fn get_slatepack_address(
         wallet_interface: Arc<RwLock<WalletInterface<L, C>>>,
     ) -> Result<(String, SlatepackAddress), GrinWalletInterfaceError> {
         let w = wallet_interface.read().unwrap();
+        if let Some(r) = &w.remote_owner {
+            let res = r.get_slatepack_address(0)?;
+            return Ok((res.to_string(), res));
+        }
         if let Some(o) = &w.owner_api {
-            let res = o.get_slatepack_address(None, 0)?;
+            let res = o.get_slatepack_address(w.keychain_mask.as_ref(), 0)?;
             return Ok((res.to_string(), res));
         } else {
             return Err(GrinWalletInterfaceError::OwnerAPINotInstantiated);
         }
     }
 
+    pub async fn get_accounts(
+        wallet_interface: Arc<RwLock<WalletInterface<L, C>>>,
+    ) -> Result<Vec<AcctPathMapping>, GrinWalletInterfaceError> {
+        let w = wallet_interface.read().unwrap();
+        if let Some(r) = &w.remote_owner {
+            return r.accounts();
+        }
+        if let Some(o) = &w.owner_api {
+            let res = o.accounts(w.keychain_mask.as_ref())?;
+            return Ok(res);
+        } else {
+            return Err(GrinWalletInterfaceError::OwnerAPINotInstantiated);
+        }
+    }
+
+    pub async fn create_account(
+        wallet_interface: Arc<RwLock<WalletInterface<L, C>>>,
+        label: String,
+    ) -> Result<String, GrinWalletInterfaceError> {
+        let w = wallet_interface.write().unwrap();
+        if let Some(r) = &w.remote_owner {
+            r.create_account_path(&label)?;
+            return Ok(label);
+        }
+        if let Some(o) = &w.owner_api {
+            o.create_account_path(w.keychain_mask.as_ref(), &label)?;
+            return Ok(label);
+        } else {
+            return Err(GrinWalletInterfaceError::OwnerAPINotInstantiated);
+        }
+    }
+
+    /// Switch the account all subsequent calls (balances, tx listing, sending) operate on
+    pub async fn set_active_account(
+        wallet_interface: Arc<RwLock<WalletInterface<L, C>>>,
+        label: String,
+    ) -> Result<String, GrinWalletInterfaceError> {
+        let mut w = wallet_interface.write().unwrap();
+        if let Some(r) = &w.remote_owner {
+            r.set_active_account(&label)?;
+        } else if let Some(o) = &w.owner_api {
+            o.set_active_account(w.keychain_mask.as_ref(), &label)?;
+        } else {
+            return Err(GrinWalletInterfaceError::OwnerAPINotInstantiated);
+        }
+        w.active_account = label.clone();
+        Ok(label)
+    }
+
+    /// Summary info for every account. Briefly makes each account active in turn,
+    /// restoring the previously active account before returning
+    pub async fn get_account_balances(
+        wallet_interface: Arc<RwLock<WalletInterface<L, C>>>,
+        refresh_from_node: bool,
+    ) -> Result<Vec<(AcctPathMapping, WalletInfo)>, GrinWalletInterfaceError> {
+        let w = wallet_interface.write().unwrap();
+        // Each account is made active in turn, the active one is put back however that went
+        if let Some(r) = &w.remote_owner {
+            let res = (|| -> Result<_, GrinWalletInterfaceError> {
+                let mut res = vec![];
+                for acct in r.accounts()? {
+                    r.set_active_account(&acct.label)?;
+                    let (_, info) = r.retrieve_summary_info(refresh_from_node, 2)?;
+                    res.push((acct, info));
+                }
+                Ok(res)
+            })();
+            let restored = r
+                .set_active_account(&w.active_account)
+                .map_err(GrinWalletInterfaceError::from);
+            return res.and_then(|res| restored.map(|_| res));
+        }
+        if let Some(o) = &w.owner_api {
+            let mask = w.keychain_mask.as_ref();
+            let res = (|| -> Result<_, GrinWalletInterfaceError> {
+                let mut res = vec![];
+                for acct in o.accounts(mask)? {
+                    o.set_active_account(mask, &acct.label)?;
+                    let (_, info) = o.retrieve_summary_info(mask, refresh_from_node, 2)?;
+                    res.push((acct, info));
+                }
+                Ok(res)
+            })();
+            let restored = o
+                .set_active_account(mask, &w.active_account)
+                .map_err(GrinWalletInterfaceError::from);
+            return res.and_then(|res| restored.map(|_| res));
+        } else {
+            return Err(GrinWalletInterfaceError::OwnerAPINotInstantiated);
+        }
+    }
+
+    /// Dry run of a send, nothing is locked. With `coin_control` (number and total of
+    /// hand-picked inputs) the wallet isn't consulted since the inputs are already known
+    pub async fn estimate_tx(
+        wallet_interface: Arc<RwLock<WalletInterface<L, C>>>,
+        mut init_args: InitTxArgs,
+        coin_control: Option<(u64, u64)>,
+    ) -> Result<TxEstimate, GrinWalletInterfaceError> {
+        let fee_base = global::get_accept_fee_base();
+        // Recipient output plus change
+        let num_outputs = init_args.num_change_outputs as u64 + 1;
+        if let Some((num_inputs, inputs_total)) = coin_control {
+            let fee = TxEstimate::fee_for(num_inputs, num_outputs, fee_base);
+            return TxEstimate::new(init_args.amount, fee, inputs_total, num_inputs);
+        }
+
+        init_args.estimate_only = Some(true);
+        let w = wallet_interface.read().unwrap();
+        // An estimate comes back with amount set to the total of the selected inputs
+        let slate = if let Some(r) = &w.remote_owner {
+            r.init_send_tx(init_args.clone())?
+        } else if let Some(o) = &w.owner_api {
+            o.init_send_tx(w.keychain_mask.as_ref(), init_args.clone())?
+        } else {
+            return Err(GrinWalletInterfaceError::OwnerAPINotInstantiated);
+        };
+        let fee = slate.fee_fields.fee();
+        TxEstimate::new(
+            init_args.amount,
+            fee,
+            slate.amount,
+            TxEstimate::num_inputs_from_fee(fee, num_outputs, fee_base),
+        )
+    }
+
     pub async fn create_tx(
         wallet_interface: Arc<RwLock<WalletInterface<L, C>>>,
         init_args: InitTxArgs,
@@ -485,12 +1243,25 @@ where
             Ok(a) => Some(a),
             Err(_) => return Err(GrinWalletInterfaceError::InvalidSlatepackAddress),
         };
+        if let Some(r) = &w.remote_owner {
+            let slate = r.init_send_tx(init_args)?;
+            r.tx_lock_outputs(&slate)?;
+            return Ok((
+                slate.clone(),
+                r.encrypt_slatepack(&dest_slatepack_address, &slate)?,
+            ));
+        }
         if let Some(o) = &w.owner_api {
-            let slate = { o.init_send_tx(None, init_args)? };
-            o.tx_lock_outputs(None, &slate)?;
+            let slate = { o.init_send_tx(w.keychain_mask.as_ref(), init_args)? };
+            o.tx_lock_outputs(w.keychain_mask.as_ref(), &slate)?;
             return Ok((
                 slate.clone(),
-                WalletInterface::encrypt_slatepack(o, &dest_slatepack_address, &slate)?,
+                WalletInterface::encrypt_slatepack(
+                    o,
+                    w.keychain_mask.as_ref(),
+                    &dest_slatepack_address,
+                    &slate,
+                )?,
             ));
         } else {
             return Err(GrinWalletInterfaceError::OwnerAPINotInstantiated);
@@ -503,6 +1274,10 @@ where
         dest_slatepack_address: String,
     ) -> Result<(Slate, Option<String>), GrinWalletInterfaceError> {
         let w = wallet_interface.write().unwrap();
+        // The owner API has no receive call, that's up to the remote wallet's foreign listener
+        if w.remote_owner.is_some() {
+            return Err(GrinWalletInterfaceError::NotSupportedByRemoteOwnerAPI);
+        }
         let ret_slate;
         if let Some(f) = &w.foreign_api {
             ret_slate = f.receive_tx(&slate, None, None)?;
@@ -510,8 +1285,12 @@ where
             return Err(GrinWalletInterfaceError::ForeignAPINotInstantiated);
         }
         if let Some(o) = &w.owner_api {
-            let encrypted =
-                WalletInterface::encrypt_slatepack(o, &dest_slatepack_address, &ret_slate)?;
+            let encrypted = WalletInterface::encrypt_slatepack(
+                o,
+                w.keychain_mask.as_ref(),
+                &dest_slatepack_address,
+                &ret_slate,
+            )?;
             return Ok((ret_slate, Some(encrypted)));
         } else {
             return Err(GrinWalletInterfaceError::OwnerAPINotInstantiated);
@@ -524,22 +1303,136 @@ where
         send_to_chain: bool,
     ) -> Result<(Slate, Option<String>), GrinWalletInterfaceError> {
         let w = wallet_interface.write().unwrap();
+        if let Some(r) = &w.remote_owner {
+            let ret_slate = r.finalize_tx(&slate)?;
+            r.post_tx(&ret_slate, false)?;
+            return Ok((ret_slate, None));
+        }
+        if let Some(o) = &w.owner_api {
+            let ret_slate = o.finalize_tx(w.keychain_mask.as_ref(), &slate)?;
+            o.post_tx(w.keychain_mask.as_ref(), &ret_slate, false)?;
+            return Ok((ret_slate, None));
+        } else {
+            return Err(GrinWalletInterfaceError::OwnerAPINotInstantiated);
+        }
+    }
+
+    /// Request a payment of `args.amount`, returning the I1 slate and its slatepack
+    /// encrypted for the payer
+    pub async fn issue_invoice_tx(
+        wallet_interface: Arc<RwLock<WalletInterface<L, C>>>,
+        args: IssueInvoiceTxArgs,
+        dest_slatepack_address: String,
+    ) -> Result<(Slate, String), GrinWalletInterfaceError> {
+        let w = wallet_interface.write().unwrap();
+        if SlatepackAddress::try_from(dest_slatepack_address.as_str()).is_err() {
+            return Err(GrinWalletInterfaceError::InvalidSlatepackAddress);
+        }
+        if let Some(r) = &w.remote_owner {
+            let slate = r.issue_invoice_tx(args)?;
+            return Ok((
+                slate.clone(),
+                r.encrypt_slatepack(&dest_slatepack_address, &slate)?,
+            ));
+        }
+        if let Some(o) = &w.owner_api {
+            let slate = o.issue_invoice_tx(w.keychain_mask.as_ref(), args)?;
+            return Ok((
+                slate.clone(),
+                WalletInterface::encrypt_slatepack(
+                    o,
+                    w.keychain_mask.as_ref(),
+                    &dest_slatepack_address,
+                    &slate,
+                )?,
+            ));
+        } else {
+            return Err(GrinWalletInterfaceError::OwnerAPINotInstantiated);
+        }
+    }
+
+    /// Pay an I1 invoice, locking the inputs spent. The I2 slate goes back to the
+    /// invoicer to finalize
+    pub async fn pay_invoice_tx(
+        wallet_interface: Arc<RwLock<WalletInterface<L, C>>>,
+        slate: Slate,
+        init_args: InitTxArgs,
+        dest_slatepack_address: String,
+    ) -> Result<(Slate, Option<String>), GrinWalletInterfaceError> {
+        let w = wallet_interface.write().unwrap();
+        if let Some(r) = &w.remote_owner {
+            let ret_slate = r.process_invoice_tx(&slate, init_args)?;
+            r.tx_lock_outputs(&ret_slate)?;
+            let encrypted = r.encrypt_slatepack(&dest_slatepack_address, &ret_slate)?;
+            return Ok((ret_slate, Some(encrypted)));
+        }
+        if let Some(o) = &w.owner_api {
+            let ret_slate = o.process_invoice_tx(w.keychain_mask.as_ref(), &slate, init_args)?;
+            o.tx_lock_outputs(w.keychain_mask.as_ref(), &ret_slate)?;
+            let encrypted = WalletInterface::encrypt_slatepack(
+                o,
+                w.keychain_mask.as_ref(),
+                &dest_slatepack_address,
+                &ret_slate,
+            )?;
+            return Ok((ret_slate, Some(encrypted)));
+        } else {
+            return Err(GrinWalletInterfaceError::OwnerAPINotInstantiated);
+        }
+    }
+
+    /// Finalize a paid invoice and post it. Invoices are finalized through the foreign API,
+    /// which a remote owner API doesn't expose
+    pub async fn finalize_from_i2(
+        wallet_interface: Arc<RwLock<WalletInterface<L, C>>>,
+        slate: Slate,
+    ) -> Result<(Slate, Option<String>), GrinWalletInterfaceError> {
+        let w = wallet_interface.write().unwrap();
+        if w.remote_owner.is_some() {
+            return Err(GrinWalletInterfaceError::NotSupportedByRemoteOwnerAPI);
+        }
+        let ret_slate;
+        if let Some(f) = &w.foreign_api {
+            ret_slate = f.finalize_tx(&slate, false)?;
+        } else {
+            return Err(GrinWalletInterfaceError::ForeignAPINotInstantiated);
+        }
         if let Some(o) = &w.owner_api {
-            let ret_slate = o.finalize_tx(None, &slate)?;
-            o.post_tx(None, &ret_slate, false)?;
+            o.post_tx(w.keychain_mask.as_ref(), &ret_slate, false)?;
             return Ok((ret_slate, None));
         } else {
             return Err(GrinWalletInterfaceError::OwnerAPINotInstantiated);
         }
     }
 
+    /// Deliver a created send straight to the recipient's wallet over Tor, then finalize
+    /// and post what comes back. Only `RecipientUnreachable` means the slate never made it
+    /// and should be handed over manually instead
+    pub async fn send_tx_tor(
+        wallet_interface: Arc<RwLock<WalletInterface<L, C>>>,
+        slate: Slate,
+        dest_slatepack_address: String,
+        socks_proxy_addr: String,
+    ) -> Result<Slate, GrinWalletInterfaceError> {
+        // No wallet lock held while waiting on the network
+        let sender = TorSender::new(&dest_slatepack_address, &socks_proxy_addr)?;
+        let ret_slate = sender.send_tx(&slate)?;
+        let (ret_slate, _) =
+            WalletInterface::finalize_from_s2(wallet_interface, ret_slate, true).await?;
+        Ok(ret_slate)
+    }
+
     pub async fn cancel_tx(
         wallet_interface: Arc<RwLock<WalletInterface<L, C>>>,
         id: u32,
     ) -> Result<u32, GrinWalletInterfaceError> {
         let w = wallet_interface.write().unwrap();
+        if let Some(r) = &w.remote_owner {
+            r.cancel_tx(Some(id))?;
+            return Ok(id);
+        }
         if let Some(o) = &w.owner_api {
-            o.cancel_tx(None, Some(id), None)?;
+            o.cancel_tx(w.keychain_mask.as_ref(), Some(id), None)?;
             return Ok(id);
         } else {
             return Err(GrinWalletInterfaceError::OwnerAPINotInstantiated);
@@ -551,9 +1444,13 @@ where
         slate: Slate,
     ) -> Result<(Slate, Option<String>), GrinWalletInterfaceError> {
         let w = wallet_interface.write().unwrap();
+        if let Some(r) = &w.remote_owner {
+            r.post_tx(&slate, false)?;
+            return Ok((slate, None));
+        }
         if let Some(o) = &w.owner_api {
             let ret_slate = slate.clone();
-            o.post_tx(None, &ret_slate, false)?;
+            o.post_tx(w.keychain_mask.as_ref(), &ret_slate, false)?;
             return Ok((ret_slate, None));
         } else {
             return Err(GrinWalletInterfaceError::OwnerAPINotInstantiated);
@@ -566,11 +1463,23 @@ where
         dest_slatepack_address: String,
     ) -> Result<(Slate, String), GrinWalletInterfaceError> {
         let w = wallet_interface.write().unwrap();
+        if let Some(r) = &w.remote_owner {
+            let slate = r.contract_new(&args)?;
+            return Ok((
+                slate.clone(),
+                r.encrypt_slatepack(&dest_slatepack_address, &slate)?,
+            ));
+        }
         if let Some(o) = &w.owner_api {
-            let slate = o.contract_new(None, &args)?;
+            let slate = o.contract_new(w.keychain_mask.as_ref(), &args)?;
             return Ok((
                 slate.clone(),
-                WalletInterface::encrypt_slatepack(o, &dest_slatepack_address, &slate)?,
+                WalletInterface::encrypt_slatepack(
+                    o,
+                    w.keychain_mask.as_ref(),
+                    &dest_slatepack_address,
+                    &slate,
+                )?,
             ));
         } else {
             return Err(GrinWalletInterfaceError::OwnerAPINotInstantiated);
@@ -585,11 +1494,22 @@ where
         send_to_chain_if_ready: bool,
     ) -> Result<(Slate, Option<String>), GrinWalletInterfaceError> {
         let w = wallet_interface.write().unwrap();
+        if let Some(r) = &w.remote_owner {
+            let slate = r.contract_sign(&slate, &args)?;
+            if send_to_chain_if_ready {
+                if slate.state == SlateState::Standard3 || slate.state == SlateState::Invoice3 {
+                    r.post_tx(&slate, false)?;
+                    return Ok((slate, None));
+                }
+            }
+            let encrypted = r.encrypt_slatepack(&dest_slatepack_address, &slate)?;
+            return Ok((slate, Some(encrypted)));
+        }
         if let Some(o) = &w.owner_api {
-            let slate = o.contract_sign(None, &slate, &args)?;
+            let slate = o.contract_sign(w.keychain_mask.as_ref(), &slate, &args)?;
             if send_to_chain_if_ready {
                 if slate.state == SlateState::Standard3 || slate.state == SlateState::Invoice3 {
-                    o.post_tx(None, &slate, false)?;
+                    o.post_tx(w.keychain_mask.as_ref(), &slate, false)?;
                     return Ok((slate.clone(), None));
                 }
             }
@@ -597,6 +1517,7 @@ where
                 slate.clone(),
                 Some(WalletInterface::encrypt_slatepack(
                     o,
+                    w.keychain_mask.as_ref(),
                     &dest_slatepack_address,
                     &slate,
                 )?),
@@ -611,10 +1532,20 @@ where
         args: ContractNewArgsAPI,
     ) -> Result<(Slate, TxLogEntry), GrinWalletInterfaceError> {
         let w = wallet_interface.write().unwrap();
+        if let Some(r) = &w.remote_owner {
+            let slate = r.contract_new(&args)?;
+            let slate = r.contract_sign(&slate, &args.setup_args)?;
+            let (_, txs) = r.retrieve_txs(false, None, Some(slate.id.to_string()))?;
+            return match txs.into_iter().next() {
+                Some(e) => Ok((slate, e)),
+                None => Err(GrinWalletInterfaceError::InvalidTxLogState),
+            };
+        }
         if let Some(o) = &w.owner_api {
-            let slate = o.contract_new(None, &args)?;
-            let slate = o.contract_sign(None, &slate, &args.setup_args)?;
-            let tx_log_entry = o.retrieve_txs(None, false, None, Some(slate.id), None);
+            let slate = o.contract_new(w.keychain_mask.as_ref(), &args)?;
+            let slate = o.contract_sign(w.keychain_mask.as_ref(), &slate, &args.setup_args)?;
+            let tx_log_entry =
+                o.retrieve_txs(w.keychain_mask.as_ref(), false, None, Some(slate.id), None);
             if let Ok(e) = tx_log_entry {
                 if !e.1.is_empty() {
                     return Ok((slate.clone(), e.1[0].clone()));
@@ -631,8 +1562,11 @@ where
         args: &ContractRevokeArgsAPI,
     ) -> Result<Option<Slate>, GrinWalletInterfaceError> {
         let w = wallet_interface.write().unwrap();
+        if let Some(r) = &w.remote_owner {
+            return r.contract_revoke(args);
+        }
         if let Some(o) = &w.owner_api {
-            let slate = o.contract_revoke(None, args)?;
+            let slate = o.contract_revoke(w.keychain_mask.as_ref(), args)?;
             return Ok(slate);
         } else {
             return Err(GrinWalletInterfaceError::OwnerAPINotInstantiated);
@@ -645,7 +1579,7 @@ where
     ) -> Result<usize, GrinWalletInterfaceError> {
         let w = wallet_interface.write().unwrap();
         if let Some(o) = &w.owner_api {
-            let index = o.get_slate_index_matching_my_context(None, slate)?;
+            let index = o.get_slate_index_matching_my_context(w.keychain_mask.as_ref(), slate)?;
             return Ok(index);
         } else {
             return Err(GrinWalletInterfaceError::OwnerAPINotInstantiated);
@@ -658,7 +1592,8 @@ where
     ) -> Result<InvoiceProof, GrinWalletInterfaceError> {
         let w = wallet_interface.write().unwrap();
         if let Some(o) = &w.owner_api {
-            let res = o.retrieve_payment_proof_invoice(None, true, tx_id, None)?;
+            let res =
+                o.retrieve_payment_proof_invoice(w.keychain_mask.as_ref(), true, tx_id, None)?;
             return Ok(res);
         } else {
             return Err(GrinWalletInterfaceError::OwnerAPINotInstantiated);
@@ -668,17 +1603,160 @@ where
     pub async fn verify_payment_proof_invoice(
         wallet_interface: Arc<RwLock<WalletInterface<L, C>>>,
         recipient_address: &str,
-        proof: &InvoiceProof
+        proof: &InvoiceProof,
     ) -> Result<(), GrinWalletInterfaceError> {
         let w = wallet_interface.write().unwrap();
         if let Some(f) = &w.foreign_api {
             let addr = SlatepackAddress::try_from(recipient_address)?;
-            f.verify_payment_proof_invoice(&addr.pub_key, proof).map_err(|_| GrinWalletInterfaceError::InvalidInvoiceProof)
+            f.verify_payment_proof_invoice(&addr.pub_key, proof)
+                .map_err(|_| GrinWalletInterfaceError::InvalidInvoiceProof)
         } else {
             Err(GrinWalletInterfaceError::ForeignAPINotInstantiated)
         }
     }
 
+    /// Payment proof of a legacy send, available once the transaction is confirmed
+    pub async fn retrieve_payment_proof(
+        wallet_interface: Arc<RwLock<WalletInterface<L, C>>>,
+        tx_id: u32,
+    ) -> Result<PaymentProof, GrinWalletInterfaceError> {
+        let w = wallet_interface.write().unwrap();
+        if let Some(r) = &w.remote_owner {
+            return r.retrieve_payment_proof(true, Some(tx_id), None);
+        }
+        if let Some(o) = &w.owner_api {
+            let res =
+                o.retrieve_payment_proof(w.keychain_mask.as_ref(), true, Some(tx_id), None)?;
+            return Ok(res);
+        } else {
+            return Err(GrinWalletInterfaceError::OwnerAPINotInstantiated);
+        }
+    }
+
+    /// Verify a legacy payment proof, returning whether this wallet is its sender and
+    /// whether it is its recipient
+    pub async fn verify_payment_proof(
+        wallet_interface: Arc<RwLock<WalletInterface<L, C>>>,
+        proof: PaymentProof,
+    ) -> Result<(bool, bool), GrinWalletInterfaceError> {
+        let w = wallet_interface.write().unwrap();
+        if let Some(r) = &w.remote_owner {
+            return r.verify_payment_proof(&proof);
+        }
+        if let Some(o) = &w.owner_api {
+            let res = o.verify_payment_proof(w.keychain_mask.as_ref(), &proof)?;
+            return Ok(res);
+        } else {
+            return Err(GrinWalletInterfaceError::OwnerAPINotInstantiated);
+        }
+    }
+
+    /// Proof of a transaction, the legacy payment proof when it was sent with one and the
+    /// invoice proof otherwise
+    pub async fn retrieve_proof(
+        wallet_interface: Arc<RwLock<WalletInterface<L, C>>>,
+        tx: &TxLogEntry,
+    ) -> Result<ProofFile, GrinWalletInterfaceError> {
+        if tx.payment_proof.is_some() {
+            let proof = Self::retrieve_payment_proof(wallet_interface, tx.id).await?;
+            Ok(ProofFile::Payment(proof))
+        } else {
+            let proof = Self::retrieve_payment_proof_invoice(wallet_interface, Some(tx.id)).await?;
+            Ok(ProofFile::Invoice(proof))
+        }
+    }
+
+    /// Write a proof file to `dir` for each transaction confirmed between `from` and `to`.
+    /// Transactions without a proof are skipped. Returns the number of files written.
+    /// Progress goes to `job` when run on the wallet worker, which can also cancel it.
+    pub async fn export_proofs(
+        wallet_interface: Arc<RwLock<WalletInterface<L, C>>>,
+        from: Option<NaiveDate>,
+        to: Option<NaiveDate>,
+        dir: PathBuf,
+        job: Option<JobContext>,
+    ) -> Result<usize, GrinWalletInterfaceError> {
+        let (_, txs) = Self::get_txs(wallet_interface.clone(), None).await?;
+        std::fs::create_dir_all(&dir)?;
+
+        let txs: Vec<_> = txs
+            .iter()
+            .filter(|tx| confirmed_between(tx, from, to))
+            .collect();
+        let mut exported = 0;
+        for (i, tx) in txs.iter().enumerate() {
+            if let Some(job) = &job {
+                if job.is_cancelled() {
+                    return Err(GrinWalletInterfaceError::WalletJobCancelled);
+                }
+                job.report(
+                    Some((i * 100 / txs.len()) as u8),
+                    format!("Exporting proof {} of {}", i + 1, txs.len()),
+                );
+            }
+            let proof = match Self::retrieve_proof(wallet_interface.clone(), tx).await {
+                Ok(p) => p,
+                Err(e) => {
+                    log::debug!("No proof exported for tx {}: {}", tx.id, e);
+                    continue;
+                }
+            };
+            std::fs::write(dir.join(proof_file_name(tx)), proof.to_json()?)?;
+            exported += 1;
+        }
+        Ok(exported)
+    }
+
+    /// Check a proof someone else handed us: its signatures against `recipient_address`,
+    /// then whether its kernel is on chain. Invoice proofs don't carry their kernel, so
+    /// `kernel_excess` is looked up instead when given.
+    pub async fn verify_proof(
+        wallet_interface: Arc<RwLock<WalletInterface<L, C>>>,
+        proof: ProofFile,
+        recipient_address: String,
+        kernel_excess: Option<String>,
+    ) -> Result<ProofVerdict, GrinWalletInterfaceError> {
+        let recipient = SlatepackAddress::try_from(recipient_address.trim())
+            .map_err(|_| GrinWalletInterfaceError::InvalidSlatepackAddress)?;
+        let excess = match kernel_excess.as_deref().map(str::trim) {
+            Some(e) if !e.is_empty() => Some(parse_kernel_excess(e)?),
+            _ => proof.kernel_excess(),
+        };
+
+        let (signatures_valid, mut node_client) = {
+            let w = wallet_interface.read().unwrap();
+            let signatures_valid = match &proof {
+                ProofFile::Payment(p) => {
+                    p.recipient_address == recipient && verify_payment_proof_signatures(p)
+                }
+                ProofFile::Invoice(p) => match &w.foreign_api {
+                    Some(f) => f
+                        .verify_payment_proof_invoice(&recipient.pub_key, p)
+                        .is_ok(),
+                    None if w.remote_owner.is_some() => {
+                        return Err(GrinWalletInterfaceError::NotSupportedByRemoteOwnerAPI)
+                    }
+                    None => return Err(GrinWalletInterfaceError::ForeignAPINotInstantiated),
+                },
+            };
+            (signatures_valid, w.node_client.clone())
+        };
+
+        let kernel = match excess {
+            None => KernelStatus::NotChecked,
+            Some(e) => match node_client.get_kernel(&e, None, None) {
+                Ok(Some((_, height, _))) => KernelStatus::OnChain(height),
+                Ok(None) => KernelStatus::NotFound,
+                Err(e) => KernelStatus::LookupFailed(e.to_string()),
+            },
+        };
+
+        Ok(ProofVerdict {
+            signatures_valid,
+            kernel,
+        })
+    }
+
     /*pub async fn tx_lock_outputs(
         wallet_interface: Arc<RwLock<WalletInterface<L, C>>>,
         init_args: InitTxArgs,
@@ -686,7 +1764,7 @@ where
         let w = wallet_interface.write().unwrap();
         if let Some(o) = &w.owner_api {
             let slate = {
-                o.init_send_tx(None, init_args)?
+                o.init_send_tx(w.keychain_mask.as_ref(), init_args)?
             };
             return Ok(slate);
         } else {
@@ -694,8 +1772,56 @@ where
         }
     }*/
 
-    /*pub async fn get_recovery_phrase(wallet_interface: Arc<RwLock<WalletInterface<L, C>>>, password: String) -> String {
-        let mut w = wallet_interface.read().unwrap();
-        w.owner_api.get_mnemonic(name, password.into())
-    }*/
+    /// Recovery phrase of the open wallet, only given out for the right password
+    pub async fn get_recovery_phrase(
+        wallet_interface: Arc<RwLock<WalletInterface<L, C>>>,
+        password: SecretString,
+    ) -> Result<SecretString, GrinWalletInterfaceError> {
+        let w = wallet_interface.read().unwrap();
+        if let Some(r) = &w.remote_owner {
+            return r.get_mnemonic(&password);
+        }
+        if let Some(o) = &w.owner_api {
+            let phrase = o.get_mnemonic(None, ZeroingString::from(password.as_str()))?;
+            return Ok(Zeroizing::new(phrase.to_string()));
+        } else {
+            return Err(GrinWalletInterfaceError::OwnerAPINotInstantiated);
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::TxEstimate;
+    use crate::error::GrinWalletInterfaceError;
+
+    #[test]
+    fn test_tx_estimate_num_inputs_from_fee() {
+        let fee_base = 500_000;
+        for num_inputs in 1..10 {
+            let fee = TxEstimate::fee_for(num_inputs, 2, fee_base);
+            assert_eq!(
+                TxEstimate::num_inputs_from_fee(fee, 2, fee_base),
+                num_inputs
+            );
+        }
+    }
+
+    #[test]
+    fn test_tx_estimate_change() {
+        let estimate = TxEstimate::new(10, 2, 20, 1).unwrap();
+        assert_eq!(estimate.change, 8);
+        assert_eq!(TxEstimate::new(10, 2, 12, 1).unwrap().change, 0);
+        // Hand-picked inputs that don't cover amount and fee
+        assert!(matches!(
+            TxEstimate::new(10, 2, 11, 1),
+            Err(GrinWalletInterfaceError::WalletLibWallet(
+                grin_wallet_libwallet::Error::NotEnoughFunds {
+                    available: 11,
+                    needed: 12,
+                    ..
+                }
+            ))
+        ));
+    }
 }
@@ -0,0 +1,154 @@
+//! Node client the wallet is opened with, whose requests can be made to fail on demand.
+//! Libwallet's scan can't be told to stop, but it asks the node for every batch of outputs
+//! through a clone of the wallet's client, so failing those requests ends it early.
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+
+use grin_core::core::{Transaction, TxKernel};
+use grin_util::secp::pedersen::{Commitment, RangeProof};
+use grin_wallet_libwallet::{Error, HeaderInfo, NodeClient, NodeVersionInfo};
+
+const INTERRUPTED: &str = "Interrupted";
+
+/// Shared by a client and all its clones
+#[derive(Clone, Default)]
+pub struct NodeInterrupt(Arc<AtomicBool>);
+
+impl NodeInterrupt {
+    /// Fail requests to the node from now on
+    pub fn interrupt(&self) {
+        self.0.store(true, Ordering::SeqCst);
+    }
+
+    /// Let requests through again, returns whether they were being failed
+    pub fn resume(&self) -> bool {
+        self.0.swap(false, Ordering::SeqCst)
+    }
+
+    fn check(&self) -> Result<(), Error> {
+        if self.0.load(Ordering::SeqCst) {
+            return Err(Error::ClientCallback(INTERRUPTED.to_owned()));
+        }
+        Ok(())
+    }
+}
+
+#[derive(Clone)]
+pub struct InterruptibleNodeClient<C: NodeClient> {
+    inner: C,
+    interrupt: NodeInterrupt,
+}
+
+impl<C: NodeClient> InterruptibleNodeClient<C> {
+    pub fn new(inner: C) -> Self {
+        InterruptibleNodeClient {
+            inner,
+            interrupt: NodeInterrupt::default(),
+        }
+    }
+
+    pub fn interrupt_handle(&self) -> NodeInterrupt {
+        self.interrupt.clone()
+    }
+}
+
+// Only the chain queries a scan makes are interrupted, posting a transaction never is
+impl<C: NodeClient> NodeClient for InterruptibleNodeClient<C> {
+    fn node_url(&self) -> &str {
+        self.inner.node_url()
+    }
+
+    fn set_node_url(&mut self, node_url: &str) {
+        self.inner.set_node_url(node_url)
+    }
+
+    fn node_api_secret(&self) -> Option<String> {
+        self.inner.node_api_secret()
+    }
+
+    fn set_node_api_secret(&mut self, node_api_secret: Option<String>) {
+        self.inner.set_node_api_secret(node_api_secret)
+    }
+
+    fn post_tx(&self, tx: &Transaction, fluff: bool) -> Result<(), Error> {
+        self.inner.post_tx(tx, fluff)
+    }
+
+    fn get_version_info(&mut self) -> Option<NodeVersionInfo> {
+        self.inner.get_version_info()
+    }
+
+    fn get_chain_tip(&self) -> Result<(u64, String), Error> {
+        self.interrupt.check()?;
+        self.inner.get_chain_tip()
+    }
+
+    fn get_header_info(&self, height: u64) -> Result<HeaderInfo, Error> {
+        self.interrupt.check()?;
+        self.inner.get_header_info(height)
+    }
+
+    fn get_kernel(
+        &mut self,
+        excess: &Commitment,
+        min_height: Option<u64>,
+        max_height: Option<u64>,
+    ) -> Result<Option<(TxKernel, u64, u64)>, Error> {
+        self.interrupt.check()?;
+        self.inner.get_kernel(excess, min_height, max_height)
+    }
+
+    fn get_outputs_from_node(
+        &self,
+        wallet_outputs: Vec<Commitment>,
+    ) -> Result<HashMap<Commitment, (String, u64, u64)>, Error> {
+        self.interrupt.check()?;
+        self.inner.get_outputs_from_node(wallet_outputs)
+    }
+
+    fn get_outputs_by_pmmr_index(
+        &self,
+        start_index: u64,
+        end_index: Option<u64>,
+        max_outputs: u64,
+    ) -> Result<(u64, u64, Vec<(Commitment, RangeProof, bool, u64, u64)>), Error> {
+        self.interrupt.check()?;
+        self.inner
+            .get_outputs_by_pmmr_index(start_index, end_index, max_outputs)
+    }
+
+    fn height_range_to_pmmr_indices(
+        &self,
+        start_height: u64,
+        end_height: Option<u64>,
+    ) -> Result<(u64, u64), Error> {
+        self.interrupt.check()?;
+        self.inner
+            .height_range_to_pmmr_indices(start_height, end_height)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use grin_wallet_impls::HTTPNodeClient;
+
+    #[test]
+    fn test_interrupt_shared_by_clones() {
+        // Nothing listens here, interrupted requests fail before trying to connect
+        let client =
+            InterruptibleNodeClient::new(HTTPNodeClient::new("http://127.0.0.1:1", None).unwrap());
+        let clone = client.clone();
+        let interrupt = client.interrupt_handle();
+
+        interrupt.interrupt();
+        assert!(matches!(
+            clone.get_chain_tip(),
+            Err(Error::ClientCallback(m)) if m == INTERRUPTED
+        ));
+        assert!(interrupt.resume());
+        assert!(!interrupt.resume());
+        assert!(clone.interrupt.check().is_ok());
+    }
+}
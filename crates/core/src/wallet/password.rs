@@ -0,0 +1,52 @@
+/// Rough strength of a new wallet password, for feedback while it's typed
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum PasswordStrength {
+    Weak,
+    Fair,
+    Strong,
+}
+
+impl PasswordStrength {
+    /// Based on length and how many of lower case, upper case, digits and symbols are used
+    pub fn of(password: &str) -> Self {
+        let len = password.chars().count();
+        let classes = [
+            password.chars().any(|c| c.is_lowercase()),
+            password.chars().any(|c| c.is_uppercase()),
+            password.chars().any(|c| c.is_numeric()),
+            password.chars().any(|c| !c.is_alphanumeric()),
+        ]
+        .iter()
+        .filter(|c| **c)
+        .count();
+
+        if len >= 16 || (len >= 12 && classes >= 3) {
+            PasswordStrength::Strong
+        } else if len >= 8 && classes >= 2 {
+            PasswordStrength::Fair
+        } else {
+            PasswordStrength::Weak
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_password_strength() {
+        assert_eq!(PasswordStrength::of(""), PasswordStrength::Weak);
+        assert_eq!(PasswordStrength::of("password"), PasswordStrength::Weak);
+        assert_eq!(PasswordStrength::of("Ab1!"), PasswordStrength::Weak);
+        assert_eq!(PasswordStrength::of("password1"), PasswordStrength::Fair);
+        assert_eq!(
+            PasswordStrength::of("Password123!"),
+            PasswordStrength::Strong
+        );
+        assert_eq!(
+            PasswordStrength::of("correct horse battery staple"),
+            PasswordStrength::Strong
+        );
+    }
+}
@@ -0,0 +1,246 @@
+/// Checks on payment proofs handed to us by someone else, e.g. a customer showing they
+/// paid, and the files proofs are exchanged in. Works on both legacy payment proofs and
+/// contract invoice proofs.
+use chrono::NaiveDate;
+use ed25519_dalek::Verifier;
+use grin_util::secp::pedersen::Commitment;
+use grin_util::{from_hex, ToHex};
+
+use super::{InvoiceProof, PaymentProof, TxLogEntry};
+use crate::error::GrinWalletInterfaceError;
+
+/// A proof as loaded from a file or pasted in
+#[derive(Debug, Clone)]
+pub enum ProofFile {
+    /// Proof of a legacy send, created by the sender's wallet
+    Payment(PaymentProof),
+    /// Proof of a paid contract invoice
+    Invoice(InvoiceProof),
+}
+
+impl ProofFile {
+    pub fn parse(json: &str) -> Result<Self, GrinWalletInterfaceError> {
+        let json = json.trim();
+        if let Ok(p) = serde_json::from_str::<PaymentProof>(json) {
+            return Ok(ProofFile::Payment(p));
+        }
+        serde_json::from_str::<InvoiceProof>(json)
+            .map(ProofFile::Invoice)
+            .map_err(|_| GrinWalletInterfaceError::InvalidProofFile)
+    }
+
+    /// Contents of a proof file, the bare proof as grin-wallet's `export_proof` writes it
+    pub fn to_json(&self) -> Result<String, serde_json::Error> {
+        match self {
+            ProofFile::Payment(p) => serde_json::to_string_pretty(p),
+            ProofFile::Invoice(p) => serde_json::to_string_pretty(p),
+        }
+    }
+
+    pub fn amount(&self) -> u64 {
+        match self {
+            ProofFile::Payment(p) => p.amount,
+            ProofFile::Invoice(p) => p.amount,
+        }
+    }
+
+    /// Kernel excess the proof commits to, when it carries one
+    pub fn kernel_excess(&self) -> Option<Commitment> {
+        match self {
+            ProofFile::Payment(p) => Some(p.excess),
+            ProofFile::Invoice(_) => None,
+        }
+    }
+
+    pub fn kernel_excess_hex(&self) -> Option<String> {
+        self.kernel_excess().map(|e| e.0.to_hex())
+    }
+
+    /// Recipient named in the proof. Invoice proofs only name the sender.
+    pub fn recipient_address(&self) -> Option<String> {
+        match self {
+            ProofFile::Payment(p) => Some(p.recipient_address.to_string()),
+            ProofFile::Invoice(_) => None,
+        }
+    }
+}
+
+/// Whether the transaction a proof refers to made it on chain
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum KernelStatus {
+    /// Kernel found at the given block height
+    OnChain(u64),
+    NotFound,
+    /// No kernel excess to look up
+    NotChecked,
+    /// The node couldn't be asked
+    LookupFailed(String),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ProofVerdict {
+    pub signatures_valid: bool,
+    pub kernel: KernelStatus,
+}
+
+impl ProofVerdict {
+    pub fn is_valid(&self) -> bool {
+        self.signatures_valid && matches!(self.kernel, KernelStatus::OnChain(_))
+    }
+}
+
+/// Message both parties sign in a legacy payment proof
+fn payment_proof_message(proof: &PaymentProof) -> Vec<u8> {
+    let mut msg = proof.amount.to_be_bytes().to_vec();
+    msg.extend_from_slice(&proof.excess.0);
+    msg.extend_from_slice(proof.sender_address.pub_key.as_bytes());
+    msg
+}
+
+/// Check the recipient's and sender's signatures of a legacy payment proof
+pub fn verify_payment_proof_signatures(proof: &PaymentProof) -> bool {
+    let msg = payment_proof_message(proof);
+    proof
+        .recipient_address
+        .pub_key
+        .verify(&msg, &proof.recipient_sig)
+        .is_ok()
+        && proof
+            .sender_address
+            .pub_key
+            .verify(&msg, &proof.sender_sig)
+            .is_ok()
+}
+
+/// Name of the file a transaction's proof is exported to
+pub fn proof_file_name(tx: &TxLogEntry) -> String {
+    match tx.tx_slate_id {
+        Some(id) => format!("{}.proof.json", id),
+        None => format!("tx-{}.proof.json", tx.id),
+    }
+}
+
+/// Whether a transaction was confirmed within `from` to `to`, both inclusive and either
+/// open ended
+pub fn confirmed_between(tx: &TxLogEntry, from: Option<NaiveDate>, to: Option<NaiveDate>) -> bool {
+    let date = match tx.confirmation_ts {
+        Some(ts) if tx.confirmed => ts.date_naive(),
+        _ => return false,
+    };
+    from.map_or(true, |f| date >= f) && to.map_or(true, |t| date <= t)
+}
+
+/// Date as entered for a date range, `YYYY-MM-DD`
+pub fn parse_date(date: &str) -> Option<NaiveDate> {
+    NaiveDate::parse_from_str(date.trim(), "%Y-%m-%d").ok()
+}
+
+/// Kernel excess commitment from its hex form
+pub fn parse_kernel_excess(excess: &str) -> Result<Commitment, GrinWalletInterfaceError> {
+    match from_hex(excess.trim()) {
+        Ok(b) if b.len() == 33 => Ok(Commitment::from_vec(b)),
+        _ => Err(GrinWalletInterfaceError::InvalidKernelExcess),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::wallet::SlatepackAddress;
+    use crate::wallet::TxLogEntryType;
+    use chrono::{TimeZone, Utc};
+    use ed25519_dalek::{Keypair, PublicKey, SecretKey, Signer};
+    use grin_keychain::Identifier;
+
+    fn keypair(seed: u8) -> Keypair {
+        let secret = SecretKey::from_bytes(&[seed; 32]).unwrap();
+        let public = PublicKey::from(&secret);
+        Keypair { secret, public }
+    }
+
+    fn signed_proof() -> PaymentProof {
+        let sender = keypair(1);
+        let recipient = keypair(2);
+        let mut proof = PaymentProof {
+            amount: 1_500_000_000,
+            excess: Commitment::from_vec(vec![8; 33]),
+            recipient_address: SlatepackAddress::new(&recipient.public),
+            recipient_sig: recipient.sign(&[]),
+            sender_address: SlatepackAddress::new(&sender.public),
+            sender_sig: sender.sign(&[]),
+        };
+        let msg = payment_proof_message(&proof);
+        proof.recipient_sig = recipient.sign(&msg);
+        proof.sender_sig = sender.sign(&msg);
+        proof
+    }
+
+    #[test]
+    fn test_verify_payment_proof_signatures() {
+        let mut proof = signed_proof();
+        assert!(verify_payment_proof_signatures(&proof));
+
+        proof.amount += 1;
+        assert!(!verify_payment_proof_signatures(&proof));
+    }
+
+    #[test]
+    fn test_parse_payment_proof_file() {
+        let proof = signed_proof();
+        let json = serde_json::to_string_pretty(&proof).unwrap();
+        match ProofFile::parse(&json).unwrap() {
+            ProofFile::Payment(p) => {
+                assert_eq!(p.amount, proof.amount);
+                assert!(verify_payment_proof_signatures(&p));
+            }
+            ProofFile::Invoice(_) => panic!("expected a payment proof"),
+        }
+        assert!(matches!(
+            ProofFile::parse("{\"amount\": 1}"),
+            Err(GrinWalletInterfaceError::InvalidProofFile)
+        ));
+    }
+
+    #[test]
+    fn test_proof_file_round_trip() {
+        let proof = ProofFile::Payment(signed_proof());
+        let json = proof.to_json().unwrap();
+        match ProofFile::parse(&json).unwrap() {
+            ProofFile::Payment(p) => assert!(verify_payment_proof_signatures(&p)),
+            ProofFile::Invoice(_) => panic!("expected a payment proof"),
+        }
+    }
+
+    #[test]
+    fn test_confirmed_between() {
+        let mut tx = TxLogEntry::new(Identifier::zero(), TxLogEntryType::TxSent, 4);
+        tx.confirmation_ts = Some(Utc.with_ymd_and_hms(2023, 3, 15, 12, 0, 0).unwrap());
+        let date = |s| parse_date(s);
+
+        // Not confirmed yet
+        assert!(!confirmed_between(&tx, None, None));
+
+        tx.confirmed = true;
+        assert!(confirmed_between(&tx, None, None));
+        assert!(confirmed_between(
+            &tx,
+            date("2023-03-15"),
+            date("2023-03-15")
+        ));
+        assert!(confirmed_between(&tx, date("2023-03-01"), None));
+        assert!(!confirmed_between(&tx, None, date("2023-03-14")));
+        assert!(!confirmed_between(
+            &tx,
+            date("2023-03-16"),
+            date("2023-04-01")
+        ));
+        assert_eq!(proof_file_name(&tx), "tx-4.proof.json");
+    }
+
+    #[test]
+    fn test_parse_kernel_excess() {
+        let excess = Commitment::from_vec(vec![9; 33]);
+        assert_eq!(parse_kernel_excess(&excess.0.to_hex()).unwrap(), excess);
+        assert!(parse_kernel_excess("08ab").is_err());
+    }
+}
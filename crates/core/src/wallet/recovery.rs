@@ -0,0 +1,48 @@
+/// Backup check for a wallet's recovery phrase: the user re-enters a few of its words
+use rand::seq::index;
+
+/// Words asked for when checking a backup
+pub const QUIZ_WORD_COUNT: usize = 3;
+
+/// Positions of the words to ask for, in phrase order
+pub fn quiz_indices(phrase: &str) -> Vec<usize> {
+    let words = phrase.split_whitespace().count();
+    let mut indices =
+        index::sample(&mut rand::thread_rng(), words, QUIZ_WORD_COUNT.min(words)).into_vec();
+    indices.sort_unstable();
+    indices
+}
+
+/// Whether `answer` is the word at `index` of the phrase, ignoring case and spacing
+pub fn check_word(phrase: &str, index: usize, answer: &str) -> bool {
+    match phrase.split_whitespace().nth(index) {
+        Some(w) => w.eq_ignore_ascii_case(answer.trim()),
+        None => false,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const PHRASE: &str = "unit tooth frame absent giant ocean reopen muffin cool \
+                          pigeon blue ridge";
+
+    #[test]
+    fn test_quiz_indices() {
+        let indices = quiz_indices(PHRASE);
+        assert_eq!(indices.len(), QUIZ_WORD_COUNT);
+        assert!(indices.windows(2).all(|w| w[0] < w[1]));
+        assert!(indices.iter().all(|i| *i < 12));
+
+        assert_eq!(quiz_indices("two words").len(), 2);
+    }
+
+    #[test]
+    fn test_check_word() {
+        assert!(check_word(PHRASE, 0, "unit"));
+        assert!(check_word(PHRASE, 11, " Ridge "));
+        assert!(!check_word(PHRASE, 1, "unit"));
+        assert!(!check_word(PHRASE, 12, "ridge"));
+    }
+}
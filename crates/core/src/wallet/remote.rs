@@ -0,0 +1,743 @@
+/// Client for a grin-wallet instance driven over its V3 Owner API.
+/// All calls after `init_secure_api` are wrapped in `encrypted_request_v3`
+/// using the ECDH shared key, and wallet calls carry the `open_wallet` token.
+use grin_util::secp::key::{PublicKey, SecretKey};
+use grin_util::{from_hex, static_secp_instance, ToHex};
+use grin_wallet_api::{EncryptedRequest, EncryptedResponse, JsonId};
+use grin_wallet_libwallet::slate_versions::{SlateVersion, VersionedSlate};
+
+use isahc::auth::{Authentication, Credentials};
+use isahc::config::Configurable;
+use isahc::{ReadResponseExt, Request};
+use serde::de::DeserializeOwned;
+use serde_json::{json, Value};
+
+use std::convert::TryFrom;
+use std::sync::atomic::{AtomicU32, Ordering};
+use std::time::Duration;
+
+use super::{
+    AcctPathMapping, ContractNewArgsAPI, ContractRevokeArgsAPI, ContractSetupArgsAPI, InitTxArgs,
+    IssueInvoiceTxArgs, NodeHeightResult, OutputCommitMapping, PaymentProof, RetrieveTxQueryArgs,
+    RetrieveTxQuerySortOrder, SecretString, Slate, Slatepack, SlatepackAddress, StatusMessage,
+    TxLogEntry, TxLogEntryType, WalletInfo, Zeroizing,
+};
+use crate::error::GrinWalletInterfaceError;
+
+/// Basic auth user expected by grin-wallet's owner listener
+const OWNER_API_USER: &str = "grin";
+
+const REQUEST_TIMEOUT_SECS: u64 = 60;
+/// A full rescan only returns once it reaches the chain tip
+const SCAN_TIMEOUT_SECS: u64 = 6 * 60 * 60;
+
+pub struct RemoteOwner {
+    url: String,
+    api_secret: Option<String>,
+    // Derived during init_secure_api
+    shared_key: Option<SecretKey>,
+    // Returned by open_wallet, passed verbatim as `token` to wallet calls
+    token: Value,
+    next_id: AtomicU32,
+}
+
+impl RemoteOwner {
+    pub fn new(url: &str, api_secret: Option<String>) -> Self {
+        RemoteOwner {
+            url: url.to_owned(),
+            api_secret,
+            shared_key: None,
+            token: Value::Null,
+            next_id: AtomicU32::new(1),
+        }
+    }
+
+    pub fn url(&self) -> &str {
+        &self.url
+    }
+
+    fn next_id(&self) -> u32 {
+        self.next_id.fetch_add(1, Ordering::SeqCst)
+    }
+
+    fn post(&self, method: &str, body: &Value) -> Result<Value, GrinWalletInterfaceError> {
+        let mut builder = Request::post(&self.url)
+            .header("content-type", "application/json")
+            .timeout(request_timeout(method));
+        if let Some(s) = &self.api_secret {
+            builder = builder
+                .authentication(Authentication::basic())
+                .credentials(Credentials::new(OWNER_API_USER, s.as_str()));
+        }
+        let request = builder.body(serde_json::to_vec(body)?)?;
+        let mut response = isahc::send(request)?;
+        if !response.status().is_success() {
+            return Err(GrinWalletInterfaceError::OwnerAPIRequest {
+                method: method.to_owned(),
+                message: format!("HTTP status {}", response.status()),
+            });
+        }
+        Ok(response.json()?)
+    }
+
+    /// Pull the `Ok` value out of a JSON-RPC response, turning RPC and wallet errors into
+    /// `OwnerAPIRequest` errors
+    fn unwrap_result(method: &str, response: Value) -> Result<Value, GrinWalletInterfaceError> {
+        let err = |message: String| GrinWalletInterfaceError::OwnerAPIRequest {
+            method: method.to_owned(),
+            message,
+        };
+        if !response["error"].is_null() {
+            return Err(err(response["error"].to_string()));
+        }
+        let result = &response["result"];
+        if let Some(ok) = result.get("Ok") {
+            return Ok(ok.clone());
+        }
+        if let Some(e) = result.get("Err") {
+            return Err(err(e.to_string()));
+        }
+        Err(err(format!("Malformed response: {}", response)))
+    }
+
+    /// Exchange ECDH public keys with the remote wallet and derive the shared key
+    /// used to encrypt every subsequent request
+    pub fn init_secure_api(&mut self) -> Result<(), GrinWalletInterfaceError> {
+        let (sec_key, pub_key_hex) = {
+            let secp_inst = static_secp_instance();
+            let secp = secp_inst.lock();
+            let sec_key = SecretKey::from_slice(&secp, &rand::random::<[u8; 32]>())
+                .map_err(|e| GrinWalletInterfaceError::OwnerAPISecureChannel(e.to_string()))?;
+            let pub_key = PublicKey::from_secret_key(&secp, &sec_key)
+                .map_err(|e| GrinWalletInterfaceError::OwnerAPISecureChannel(e.to_string()))?;
+            (sec_key, pub_key.serialize_vec(&secp, true).to_hex())
+        };
+
+        let request = json!({
+            "jsonrpc": "2.0",
+            "method": "init_secure_api",
+            "id": self.next_id(),
+            "params": {
+                "ecdh_pubkey": pub_key_hex,
+            }
+        });
+        let response = self.post("init_secure_api", &request)?;
+        let server_key = RemoteOwner::unwrap_result("init_secure_api", response)?;
+        let server_key = server_key.as_str().ok_or_else(|| {
+            GrinWalletInterfaceError::OwnerAPISecureChannel(format!(
+                "Unexpected public key: {}",
+                server_key
+            ))
+        })?;
+
+        self.shared_key = Some(derive_shared_key(&sec_key, server_key)?);
+        Ok(())
+    }
+
+    /// Send an encrypted request and return the decrypted `Ok` value
+    fn call<T: DeserializeOwned>(
+        &self,
+        method: &str,
+        params: Value,
+    ) -> Result<T, GrinWalletInterfaceError> {
+        let shared_key = match &self.shared_key {
+            Some(k) => k,
+            None => return Err(GrinWalletInterfaceError::OwnerAPINotInstantiated),
+        };
+        let id = self.next_id();
+        let inner = json!({
+            "jsonrpc": "2.0",
+            "method": method,
+            "id": id,
+            "params": params,
+        });
+        let request = EncryptedRequest::from_json(&JsonId::IntId(id), &inner, shared_key)?;
+        let response = self.post(method, &serde_json::to_value(&request)?)?;
+        // Errors in the encryption layer itself come back unencrypted
+        if !response["error"].is_null() {
+            return Err(GrinWalletInterfaceError::OwnerAPIRequest {
+                method: method.to_owned(),
+                message: response["error"].to_string(),
+            });
+        }
+        let response: EncryptedResponse = serde_json::from_value(response)?;
+        let decrypted = response.decrypt(shared_key)?;
+        let ok = RemoteOwner::unwrap_result(method, decrypted)?;
+        Ok(serde_json::from_value(ok)?)
+    }
+
+    /// As `call`, adding the token returned by `open_wallet` to the parameters
+    fn call_with_token<T: DeserializeOwned>(
+        &self,
+        method: &str,
+        mut params: Value,
+    ) -> Result<T, GrinWalletInterfaceError> {
+        if let Some(p) = params.as_object_mut() {
+            p.insert("token".to_owned(), self.token.clone());
+        }
+        self.call(method, params)
+    }
+
+    pub fn open_wallet(&mut self, password: &str) -> Result<(), GrinWalletInterfaceError> {
+        self.token = self.call(
+            "open_wallet",
+            json!({
+                "name": null,
+                "password": password,
+            }),
+        )?;
+        Ok(())
+    }
+
+    pub fn close_wallet(&mut self) -> Result<(), GrinWalletInterfaceError> {
+        let _: Value = self.call("close_wallet", json!({ "name": null }))?;
+        self.token = Value::Null;
+        self.shared_key = None;
+        Ok(())
+    }
+
+    pub fn change_password(&self, old: &str, new: &str) -> Result<(), GrinWalletInterfaceError> {
+        let _: Value = self.call(
+            "change_password",
+            json!({
+                "name": null,
+                "old": old,
+                "new": new,
+            }),
+        )?;
+        Ok(())
+    }
+
+    pub fn get_mnemonic(&self, password: &str) -> Result<SecretString, GrinWalletInterfaceError> {
+        let phrase: String = self.call(
+            "get_mnemonic",
+            json!({
+                "name": null,
+                "password": password,
+            }),
+        )?;
+        Ok(Zeroizing::new(phrase))
+    }
+
+    pub fn start_updater(&self, frequency: Duration) -> Result<(), GrinWalletInterfaceError> {
+        let _: Value = self.call_with_token(
+            "start_updater",
+            json!({ "frequency": frequency.as_millis() as u32 }),
+        )?;
+        Ok(())
+    }
+
+    pub fn stop_updater(&self) -> Result<(), GrinWalletInterfaceError> {
+        let _: Value = self.call("stop_updater", json!({}))?;
+        Ok(())
+    }
+
+    pub fn get_updater_messages(
+        &self,
+        count: u32,
+    ) -> Result<Vec<StatusMessage>, GrinWalletInterfaceError> {
+        self.call("get_updater_messages", json!({ "count": count }))
+    }
+
+    pub fn scan(
+        &self,
+        start_height: Option<u64>,
+        delete_unconfirmed: bool,
+    ) -> Result<(), GrinWalletInterfaceError> {
+        let _: Value = self.call_with_token(
+            "scan",
+            json!({
+                "start_height": start_height,
+                "delete_unconfirmed": delete_unconfirmed,
+            }),
+        )?;
+        Ok(())
+    }
+
+    pub fn retrieve_summary_info(
+        &self,
+        refresh_from_node: bool,
+        minimum_confirmations: u64,
+    ) -> Result<(bool, WalletInfo), GrinWalletInterfaceError> {
+        self.call_with_token(
+            "retrieve_summary_info",
+            json!({
+                "refresh_from_node": refresh_from_node,
+                "minimum_confirmations": minimum_confirmations,
+            }),
+        )
+    }
+
+    pub fn retrieve_txs(
+        &self,
+        refresh_from_node: bool,
+        tx_id: Option<u32>,
+        tx_slate_id: Option<String>,
+    ) -> Result<(bool, Vec<TxLogEntry>), GrinWalletInterfaceError> {
+        self.call_with_token(
+            "retrieve_txs",
+            json!({
+                "refresh_from_node": refresh_from_node,
+                "tx_id": tx_id,
+                "tx_slate_id": tx_slate_id,
+            }),
+        )
+    }
+
+    pub fn retrieve_outputs(
+        &self,
+        include_spent: bool,
+        refresh_from_node: bool,
+        tx_id: Option<u32>,
+    ) -> Result<(bool, Vec<OutputCommitMapping>), GrinWalletInterfaceError> {
+        self.call_with_token(
+            "retrieve_outputs",
+            json!({
+                "include_spent": include_spent,
+                "refresh_from_node": refresh_from_node,
+                "tx_id": tx_id,
+            }),
+        )
+    }
+
+    pub fn node_height(&self) -> Result<NodeHeightResult, GrinWalletInterfaceError> {
+        self.call_with_token("node_height", json!({}))
+    }
+
+    pub fn accounts(&self) -> Result<Vec<AcctPathMapping>, GrinWalletInterfaceError> {
+        self.call_with_token("accounts", json!({}))
+    }
+
+    pub fn create_account_path(&self, label: &str) -> Result<(), GrinWalletInterfaceError> {
+        let _: Value = self.call_with_token("create_account_path", json!({ "label": label }))?;
+        Ok(())
+    }
+
+    pub fn set_active_account(&self, label: &str) -> Result<(), GrinWalletInterfaceError> {
+        let _: Value = self.call_with_token("set_active_account", json!({ "label": label }))?;
+        Ok(())
+    }
+
+    pub fn get_slatepack_address(
+        &self,
+        derivation_index: u32,
+    ) -> Result<SlatepackAddress, GrinWalletInterfaceError> {
+        self.call_with_token(
+            "get_slatepack_address",
+            json!({ "derivation_index": derivation_index }),
+        )
+    }
+
+    pub fn init_send_tx(&self, args: InitTxArgs) -> Result<Slate, GrinWalletInterfaceError> {
+        let slate: VersionedSlate =
+            self.call_with_token("init_send_tx", json!({ "args": args }))?;
+        Ok(Slate::from(slate))
+    }
+
+    pub fn issue_invoice_tx(
+        &self,
+        args: IssueInvoiceTxArgs,
+    ) -> Result<Slate, GrinWalletInterfaceError> {
+        let slate: VersionedSlate =
+            self.call_with_token("issue_invoice_tx", json!({ "args": args }))?;
+        Ok(Slate::from(slate))
+    }
+
+    pub fn process_invoice_tx(
+        &self,
+        slate: &Slate,
+        args: InitTxArgs,
+    ) -> Result<Slate, GrinWalletInterfaceError> {
+        let slate: VersionedSlate = self.call_with_token(
+            "process_invoice_tx",
+            json!({
+                "slate": slate_param(slate)?,
+                "args": args,
+            }),
+        )?;
+        Ok(Slate::from(slate))
+    }
+
+    pub fn tx_lock_outputs(&self, slate: &Slate) -> Result<(), GrinWalletInterfaceError> {
+        let _: Value =
+            self.call_with_token("tx_lock_outputs", json!({ "slate": slate_param(slate)? }))?;
+        Ok(())
+    }
+
+    pub fn finalize_tx(&self, slate: &Slate) -> Result<Slate, GrinWalletInterfaceError> {
+        let slate: VersionedSlate =
+            self.call_with_token("finalize_tx", json!({ "slate": slate_param(slate)? }))?;
+        Ok(Slate::from(slate))
+    }
+
+    pub fn post_tx(&self, slate: &Slate, fluff: bool) -> Result<(), GrinWalletInterfaceError> {
+        let _: Value = self.call_with_token(
+            "post_tx",
+            json!({
+                "slate": slate_param(slate)?,
+                "fluff": fluff,
+            }),
+        )?;
+        Ok(())
+    }
+
+    pub fn cancel_tx(&self, tx_id: Option<u32>) -> Result<(), GrinWalletInterfaceError> {
+        let _: Value = self.call_with_token(
+            "cancel_tx",
+            json!({
+                "tx_id": tx_id,
+                "tx_slate_id": null,
+            }),
+        )?;
+        Ok(())
+    }
+
+    pub fn retrieve_payment_proof(
+        &self,
+        refresh_from_node: bool,
+        tx_id: Option<u32>,
+        tx_slate_id: Option<String>,
+    ) -> Result<PaymentProof, GrinWalletInterfaceError> {
+        self.call_with_token(
+            "retrieve_payment_proof",
+            json!({
+                "refresh_from_node": refresh_from_node,
+                "tx_id": tx_id,
+                "tx_slate_id": tx_slate_id,
+            }),
+        )
+    }
+
+    pub fn verify_payment_proof(
+        &self,
+        proof: &PaymentProof,
+    ) -> Result<(bool, bool), GrinWalletInterfaceError> {
+        self.call_with_token("verify_payment_proof", json!({ "proof": proof }))
+    }
+
+    pub fn create_slatepack_message(
+        &self,
+        slate: &Slate,
+        sender_index: Option<u32>,
+        recipients: Vec<SlatepackAddress>,
+    ) -> Result<String, GrinWalletInterfaceError> {
+        self.call_with_token(
+            "create_slatepack_message",
+            json!({
+                "slate": slate_param(slate)?,
+                "sender_index": sender_index,
+                "recipients": recipients,
+            }),
+        )
+    }
+
+    pub fn slate_from_slatepack_message(
+        &self,
+        message: String,
+        secret_indices: Vec<u32>,
+    ) -> Result<Slate, GrinWalletInterfaceError> {
+        let slate: VersionedSlate = self.call_with_token(
+            "slate_from_slatepack_message",
+            json!({
+                "message": message,
+                "secret_indices": secret_indices,
+            }),
+        )?;
+        Ok(Slate::from(slate))
+    }
+
+    pub fn decode_slatepack_message(
+        &self,
+        message: String,
+        secret_indices: Vec<u32>,
+    ) -> Result<Slatepack, GrinWalletInterfaceError> {
+        self.call_with_token(
+            "decode_slatepack_message",
+            json!({
+                "message": message,
+                "secret_indices": secret_indices,
+            }),
+        )
+    }
+
+    pub fn contract_new(
+        &self,
+        args: &ContractNewArgsAPI,
+    ) -> Result<Slate, GrinWalletInterfaceError> {
+        let slate: VersionedSlate =
+            self.call_with_token("contract_new", json!({ "args": args }))?;
+        Ok(Slate::from(slate))
+    }
+
+    pub fn contract_sign(
+        &self,
+        slate: &Slate,
+        args: &ContractSetupArgsAPI,
+    ) -> Result<Slate, GrinWalletInterfaceError> {
+        let slate: VersionedSlate = self.call_with_token(
+            "contract_sign",
+            json!({
+                "slate": slate_param(slate)?,
+                "args": args,
+            }),
+        )?;
+        Ok(Slate::from(slate))
+    }
+
+    pub fn contract_revoke(
+        &self,
+        args: &ContractRevokeArgsAPI,
+    ) -> Result<Option<Slate>, GrinWalletInterfaceError> {
+        let slate: Option<VersionedSlate> =
+            self.call_with_token("contract_revoke", json!({ "args": args }))?;
+        Ok(slate.map(Slate::from))
+    }
+
+    /// Remote counterpart of `WalletInterface::encrypt_slatepack`
+    pub fn encrypt_slatepack(
+        &self,
+        dest: &str,
+        unenc_slate: &Slate,
+    ) -> Result<String, GrinWalletInterfaceError> {
+        let address = match SlatepackAddress::try_from(dest) {
+            Ok(a) => a,
+            Err(_) => return Err(GrinWalletInterfaceError::InvalidSlatepackAddress),
+        };
+        self.create_slatepack_message(unenc_slate, Some(0), vec![address])
+    }
+}
+
+fn request_timeout(method: &str) -> Duration {
+    match method {
+        "scan" => Duration::from_secs(SCAN_TIMEOUT_SECS),
+        _ => Duration::from_secs(REQUEST_TIMEOUT_SECS),
+    }
+}
+
+fn slate_param(slate: &Slate) -> Result<Value, GrinWalletInterfaceError> {
+    let versioned = VersionedSlate::into_version(slate.clone(), SlateVersion::V4)?;
+    Ok(serde_json::to_value(versioned)?)
+}
+
+/// Shared key is the x coordinate of our secret key multiplied into the other party's public key,
+/// matching grin-wallet's side of `init_secure_api`
+fn derive_shared_key(
+    sec_key: &SecretKey,
+    other_pub_key_hex: &str,
+) -> Result<SecretKey, GrinWalletInterfaceError> {
+    let secure_channel_err = |e: String| GrinWalletInterfaceError::OwnerAPISecureChannel(e);
+    let secp_inst = static_secp_instance();
+    let secp = secp_inst.lock();
+    let other_pub_key_bytes =
+        from_hex(other_pub_key_hex).map_err(|e| secure_channel_err(e.to_string()))?;
+    let mut shared_pub_key = PublicKey::from_slice(&secp, &other_pub_key_bytes)
+        .map_err(|e| secure_channel_err(e.to_string()))?;
+    shared_pub_key
+        .mul_assign(&secp, sec_key)
+        .map_err(|e| secure_channel_err(e.to_string()))?;
+    let x_coord = shared_pub_key.serialize_vec(&secp, true);
+    SecretKey::from_slice(&secp, &x_coord[1..]).map_err(|e| secure_channel_err(e.to_string()))
+}
+
+/// The V3 `retrieve_txs` call takes no query arguments, so apply the ones the tx list
+/// relies on to the returned entries
+pub fn apply_tx_query_args(
+    mut txs: Vec<TxLogEntry>,
+    query_args: &RetrieveTxQueryArgs,
+) -> Vec<TxLogEntry> {
+    if query_args.exclude_cancelled == Some(true) {
+        txs.retain(|t| {
+            t.tx_type != TxLogEntryType::TxSentCancelled
+                && t.tx_type != TxLogEntryType::TxReceivedCancelled
+        });
+    }
+    if query_args.include_outstanding_only == Some(true) {
+        txs.retain(|t| !t.confirmed);
+    }
+    match query_args.sort_order {
+        Some(RetrieveTxQuerySortOrder::Desc) => txs.sort_by(|a, b| b.id.cmp(&a.id)),
+        _ => txs.sort_by(|a, b| a.id.cmp(&b.id)),
+    }
+    txs
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::wallet::SlateState;
+    use grin_keychain::Identifier;
+    use std::io::{BufRead, BufReader, Read, Write};
+    use std::net::TcpListener;
+    use std::thread;
+
+    const TOKEN: &str = "d202964900000000d302964900000000d402964900000000d502964900000000";
+
+    /// Minimal stand-in for grin-wallet's owner listener. Answers `init_secure_api` in the
+    /// clear and a handful of methods inside `encrypted_request_v3`.
+    fn mock_owner_api() -> String {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}/v3/owner", listener.local_addr().unwrap());
+
+        thread::spawn(move || {
+            let mut shared_key: Option<SecretKey> = None;
+            for stream in listener.incoming() {
+                let mut stream = stream.unwrap();
+                let mut reader = BufReader::new(stream.try_clone().unwrap());
+
+                let mut content_length = 0;
+                let mut has_auth = false;
+                loop {
+                    let mut line = String::new();
+                    reader.read_line(&mut line).unwrap();
+                    let line = line.trim_end().to_lowercase();
+                    if line.is_empty() {
+                        break;
+                    }
+                    if let Some(l) = line.strip_prefix("content-length:") {
+                        content_length = l.trim().parse().unwrap();
+                    }
+                    if line.starts_with("authorization: basic") {
+                        has_auth = true;
+                    }
+                }
+                let mut body = vec![0u8; content_length];
+                reader.read_exact(&mut body).unwrap();
+                let request: Value = serde_json::from_slice(&body).unwrap();
+
+                let response = if !has_auth {
+                    json!({"jsonrpc": "2.0", "id": request["id"], "error": {"code": -32000, "message": "Unauthorized"}})
+                } else if request["method"] == "init_secure_api" {
+                    let sec_key = {
+                        let secp_inst = static_secp_instance();
+                        let secp = secp_inst.lock();
+                        SecretKey::from_slice(&secp, &[7u8; 32]).unwrap()
+                    };
+                    let client_key = request["params"]["ecdh_pubkey"].as_str().unwrap();
+                    shared_key = Some(derive_shared_key(&sec_key, client_key).unwrap());
+                    let pub_key_hex = {
+                        let secp_inst = static_secp_instance();
+                        let secp = secp_inst.lock();
+                        PublicKey::from_secret_key(&secp, &sec_key)
+                            .unwrap()
+                            .serialize_vec(&secp, true)
+                            .to_hex()
+                    };
+                    json!({"jsonrpc": "2.0", "id": request["id"], "result": {"Ok": pub_key_hex}})
+                } else {
+                    let key = shared_key.as_ref().unwrap();
+                    let enc_req: EncryptedRequest = serde_json::from_value(request).unwrap();
+                    let inner = enc_req.decrypt(key).unwrap();
+                    let result = match inner["method"].as_str().unwrap() {
+                        "open_wallet" => json!({"Ok": TOKEN}),
+                        "retrieve_txs" if inner["params"]["token"] == TOKEN => {
+                            json!({"Ok": [true, []]})
+                        }
+                        "issue_invoice_tx" if inner["params"]["token"] == TOKEN => {
+                            let args: IssueInvoiceTxArgs =
+                                serde_json::from_value(inner["params"]["args"].clone()).unwrap();
+                            let mut slate = Slate::blank(2, true);
+                            slate.amount = args.amount;
+                            let slate =
+                                VersionedSlate::into_version(slate, SlateVersion::V4).unwrap();
+                            json!({ "Ok": slate })
+                        }
+                        _ => json!({"Err": {"GenericError": "Unsupported"}}),
+                    };
+                    let inner_res = json!({"jsonrpc": "2.0", "id": inner["id"], "result": result});
+                    let enc_res =
+                        EncryptedResponse::from_json(&enc_req.id, &inner_res, key).unwrap();
+                    serde_json::to_value(enc_res).unwrap()
+                };
+
+                let body = response.to_string();
+                write!(
+                    stream,
+                    "HTTP/1.1 200 OK\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                    body.len(),
+                    body
+                )
+                .unwrap();
+            }
+        });
+
+        url
+    }
+
+    #[test]
+    fn test_remote_owner_open_and_retrieve_txs() {
+        let url = mock_owner_api();
+        let mut owner = RemoteOwner::new(&url, Some("secret".to_owned()));
+
+        // no shared key yet
+        assert!(owner.retrieve_txs(false, None, None).is_err());
+
+        owner.init_secure_api().unwrap();
+        owner.open_wallet("password").unwrap();
+        assert_eq!(owner.token, json!(TOKEN));
+
+        let (refreshed, txs) = owner.retrieve_txs(true, None, None).unwrap();
+        assert!(refreshed);
+        assert!(txs.is_empty());
+
+        // Wallet errors inside the encrypted response are surfaced
+        match owner.cancel_tx(Some(1)) {
+            Err(GrinWalletInterfaceError::OwnerAPIRequest { method, .. }) => {
+                assert_eq!(method, "cancel_tx")
+            }
+            r => panic!("unexpected result {:?}", r.map(|_| ())),
+        }
+    }
+
+    #[test]
+    fn test_remote_owner_issue_invoice_tx() {
+        let url = mock_owner_api();
+        let mut owner = RemoteOwner::new(&url, Some("secret".to_owned()));
+        owner.init_secure_api().unwrap();
+        owner.open_wallet("password").unwrap();
+
+        let args = IssueInvoiceTxArgs {
+            amount: 2_000_000_000,
+            ..Default::default()
+        };
+        let slate = owner.issue_invoice_tx(args).unwrap();
+        assert_eq!(slate.state, SlateState::Invoice1);
+        assert_eq!(slate.amount, 2_000_000_000);
+    }
+
+    #[test]
+    fn test_remote_owner_missing_secret() {
+        let url = mock_owner_api();
+        let mut owner = RemoteOwner::new(&url, None);
+        assert!(matches!(
+            owner.init_secure_api(),
+            Err(GrinWalletInterfaceError::OwnerAPIRequest { .. })
+        ));
+    }
+
+    #[test]
+    fn test_apply_tx_query_args() {
+        let mut cancelled = TxLogEntry::new(Identifier::zero(), TxLogEntryType::TxSentCancelled, 1);
+        cancelled.confirmed = false;
+        let mut confirmed = TxLogEntry::new(Identifier::zero(), TxLogEntryType::TxReceived, 2);
+        confirmed.confirmed = true;
+        let mut outstanding = TxLogEntry::new(Identifier::zero(), TxLogEntryType::TxSent, 3);
+        outstanding.confirmed = false;
+        let txs = vec![cancelled, confirmed, outstanding];
+
+        let mut query_args = RetrieveTxQueryArgs::default();
+        query_args.sort_order = Some(RetrieveTxQuerySortOrder::Desc);
+        let ids: Vec<u32> = apply_tx_query_args(txs.clone(), &query_args)
+            .iter()
+            .map(|t| t.id)
+            .collect();
+        assert_eq!(ids, vec![3, 2, 1]);
+
+        query_args.exclude_cancelled = Some(true);
+        query_args.include_outstanding_only = Some(true);
+        let ids: Vec<u32> = apply_tx_query_args(txs, &query_args)
+            .iter()
+            .map(|t| t.id)
+            .collect();
+        assert_eq!(ids, vec![3]);
+    }
+}
@@ -0,0 +1,296 @@
+/// Streams changes of the open wallet to the UI, instead of the UI polling for them.
+/// A watcher thread checks the wallet and passes on only what changed since last time.
+/// Balances, transactions and inbox slatepacks are read on the wallet worker, so they
+/// queue behind wallet operations and are cancelled along with them.
+use futures::executor::block_on;
+use futures::SinkExt;
+use iced_core::Hasher;
+use iced_futures::{
+    self,
+    futures::{channel::mpsc, stream::StreamExt},
+    subscription,
+};
+
+use chrono::{DateTime, Utc};
+use std::collections::HashMap;
+use std::hash::Hash;
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, RwLock};
+use std::thread::{self, JoinHandle};
+use std::time::Duration;
+
+use super::inbox::{InboxScanner, InboxSlatepack};
+use super::worker::WalletWorker;
+use super::{StatusMessage, TxLogEntry, TxLogEntryType, WalletInfo, WalletInterfaceHttpNodeClient};
+
+/// How often the updater status is checked. Polled on purpose: libwallet's updater only
+/// leaves its messages in a queue, and reading them is cheap and never waits on the
+/// worker, so scan progress still shows while a scan keeps the worker busy.
+const STATUS_INTERVAL: Duration = Duration::from_secs(1);
+/// Balances and transactions are checked every this many status checks
+const SUMMARY_EVERY: u32 = 10;
+
+#[derive(Clone, Debug)]
+pub enum WalletMessage {
+    None,
+    /// Latest message from the wallet's background updater
+    UpdaterStatus(StatusMessage),
+    /// Balances changed, and whether they were refreshed from the node
+    WalletInfo(bool, WalletInfo),
+    /// Transactions that are new or changed since the last check
+    TxsChanged(Vec<TxLogEntry>),
+    /// Slatepacks now waiting in the inbox folder, sent whenever that list changes
+    InboxChanged(Vec<InboxSlatepack>),
+}
+
+pub enum State {
+    Ready,
+    Listening {
+        receiver: mpsc::Receiver<WalletMessage>,
+    },
+}
+
+pub fn subscriber<I: 'static + Hash + Copy + Send>(
+    id: I,
+) -> iced::Subscription<(I, WalletMessage, Option<mpsc::Sender<WalletMessage>>)> {
+    iced::Subscription::from_recipe(WalletSubscriber { id })
+}
+
+pub struct WalletSubscriber<I> {
+    id: I,
+}
+
+impl<T> iced_futures::subscription::Recipe for WalletSubscriber<T>
+where
+    T: 'static + Hash + Copy + Send,
+{
+    type Output = (T, WalletMessage, Option<mpsc::Sender<WalletMessage>>);
+
+    fn hash(&self, state: &mut Hasher) {
+        struct Marker;
+        std::any::TypeId::of::<Marker>().hash(state);
+        self.id.hash(state);
+    }
+
+    fn stream(
+        self: Box<Self>,
+        _input: subscription::EventStream,
+    ) -> futures::stream::BoxStream<'static, Self::Output> {
+        let id = self.id;
+        Box::pin(futures::stream::unfold(
+            State::Ready,
+            move |state| async move {
+                match state {
+                    State::Ready => {
+                        let (sender, receiver) = mpsc::channel::<WalletMessage>(0);
+                        Some((
+                            (id, WalletMessage::None, Some(sender)),
+                            State::Listening { receiver },
+                        ))
+                    }
+                    State::Listening { mut receiver } => match receiver.next().await {
+                        Some(msg) => Some(((id, msg, None), State::Listening { receiver })),
+                        None => {
+                            // Watcher stopped, nothing more will come
+                            let _: () = iced::futures::future::pending().await;
+                            None
+                        }
+                    },
+                }
+            },
+        ))
+    }
+}
+
+/// What was last sent, so only changes go out
+#[derive(Default)]
+struct Seen {
+    // Compared by debug output, status messages can't be compared directly
+    status: Option<String>,
+    info: Option<WalletInfo>,
+    // Versions of the entries by tx id
+    txs: Option<HashMap<u32, TxVersion>>,
+    inbox: InboxScanner,
+}
+
+/// What changes on a transaction once it's created. The log has no update time, this
+/// stands in for one so entries don't have to be compared in full
+#[derive(PartialEq)]
+struct TxVersion {
+    tx_type: TxLogEntryType,
+    confirmed: bool,
+    confirmation_ts: Option<DateTime<Utc>>,
+    finalized: bool,
+}
+
+impl From<&TxLogEntry> for TxVersion {
+    fn from(tx: &TxLogEntry) -> Self {
+        TxVersion {
+            tx_type: tx.tx_type.clone(),
+            confirmed: tx.confirmed,
+            confirmation_ts: tx.confirmation_ts,
+            finalized: tx.kernel_excess.is_some(),
+        }
+    }
+}
+
+/// Thread watching the open wallet for the subscription
+pub struct WalletWatcher {
+    stop: Arc<AtomicBool>,
+    // Inbox folder of the open wallet, if any
+    inbox_dir: Arc<RwLock<Option<PathBuf>>>,
+    handle: Option<JoinHandle<()>>,
+}
+
+impl WalletWatcher {
+    pub fn start(
+        wallet_interface: Arc<RwLock<WalletInterfaceHttpNodeClient>>,
+        wallet_worker: Arc<WalletWorker>,
+        mut sender: mpsc::Sender<WalletMessage>,
+    ) -> Self {
+        let stop = Arc::new(AtomicBool::new(false));
+        let thread_stop = stop.clone();
+        let inbox_dir = Arc::new(RwLock::new(None));
+        let thread_inbox_dir = inbox_dir.clone();
+        let handle = thread::Builder::new()
+            .name("wallet-watcher".to_owned())
+            .spawn(move || {
+                let mut seen = Seen::default();
+                let mut checks = 0;
+                while !thread_stop.load(Ordering::SeqCst) {
+                    thread::sleep(STATUS_INTERVAL);
+                    if !wallet_interface.read().unwrap().wallet_is_open() {
+                        // Everything is news again for the next wallet opened
+                        seen = Seen::default();
+                        checks = 0;
+                        continue;
+                    }
+                    // Anything else would only queue up behind a scan or a long running
+                    // operation, a summary that's due waits for the worker to be idle
+                    let worker_idle = wallet_worker.is_idle();
+                    let summary_due = checks % SUMMARY_EVERY == 0;
+                    let with_summary = summary_due && worker_idle;
+                    if with_summary || !summary_due {
+                        checks += 1;
+                    }
+                    let inbox_dir = thread_inbox_dir
+                        .read()
+                        .unwrap()
+                        .clone()
+                        .filter(|_| worker_idle);
+                    for msg in changes(
+                        &wallet_interface,
+                        &wallet_worker,
+                        &mut seen,
+                        with_summary,
+                        inbox_dir,
+                    ) {
+                        if block_on(sender.send(msg)).is_err() {
+                            // Subscription is gone
+                            return;
+                        }
+                    }
+                }
+            })
+            .map_err(|e| log::error!("Unable to start wallet watcher: {}", e))
+            .ok();
+
+        WalletWatcher {
+            stop,
+            inbox_dir,
+            handle,
+        }
+    }
+
+    /// Set the inbox folder to watch, for the wallet that was just opened
+    pub fn set_inbox_dir(&self, dir: Option<PathBuf>) {
+        *self.inbox_dir.write().unwrap() = dir;
+    }
+
+    pub fn stop(&mut self) {
+        self.stop.store(true, Ordering::SeqCst);
+        self.handle.take();
+    }
+}
+
+fn changes(
+    wallet_interface: &Arc<RwLock<WalletInterfaceHttpNodeClient>>,
+    wallet_worker: &WalletWorker,
+    seen: &mut Seen,
+    with_summary: bool,
+    inbox_dir: Option<PathBuf>,
+) -> Vec<WalletMessage> {
+    let mut messages = vec![];
+
+    match WalletInterfaceHttpNodeClient::get_wallet_updater_status(wallet_interface.clone()) {
+        Ok(status) => {
+            if let Some(m) = status.into_iter().next() {
+                let debug = format!("{:?}", m);
+                if seen.status.as_ref() != Some(&debug) {
+                    seen.status = Some(debug);
+                    messages.push(WalletMessage::UpdaterStatus(m));
+                }
+            }
+        }
+        Err(e) => log::debug!("Wallet watcher couldn't get updater status: {}", e),
+    }
+
+    if let Some(dir) = inbox_dir {
+        let decode = |slatepack| {
+            let w = wallet_interface.clone();
+            let fut = async move { WalletInterfaceHttpNodeClient::decrypt_slatepack(w, slatepack) };
+            block_on(wallet_worker.run(fut))
+                .map(|(_, slate, tx)| (slate, tx))
+                .map_err(|e| e.to_string())
+        };
+        match seen.inbox.scan(&dir, decode) {
+            Ok(Some(inbox)) => messages.push(WalletMessage::InboxChanged(inbox)),
+            Ok(None) => {}
+            Err(e) => log::debug!("Wallet watcher couldn't read inbox {:?}: {}", dir, e),
+        }
+    }
+
+    if !with_summary {
+        return messages;
+    }
+
+    let fut = WalletInterfaceHttpNodeClient::get_wallet_info(wallet_interface.clone(), false);
+    match block_on(wallet_worker.run(fut)) {
+        Ok((refreshed, info)) => {
+            if seen.info.as_ref() != Some(&info) {
+                seen.info = Some(info.clone());
+                messages.push(WalletMessage::WalletInfo(refreshed, info));
+            }
+        }
+        Err(e) => log::debug!("Wallet watcher couldn't get wallet info: {}", e),
+    }
+
+    let w = wallet_interface.clone();
+    let fut = async move { WalletInterfaceHttpNodeClient::get_stored_txs(w) };
+    match block_on(wallet_worker.run(fut)) {
+        Ok(txs) => {
+            let previous = seen.txs.take();
+            let mut current = HashMap::new();
+            let mut changed = vec![];
+            for tx in txs {
+                let version = TxVersion::from(&tx);
+                let is_changed = previous
+                    .as_ref()
+                    .map_or(false, |p| p.get(&tx.id) != Some(&version));
+                current.insert(tx.id, version);
+                if is_changed {
+                    changed.push(tx);
+                }
+            }
+            // The first look only sets the baseline, the UI loads its list itself
+            if !changed.is_empty() {
+                messages.push(WalletMessage::TxsChanged(changed));
+            }
+            seen.txs = Some(current);
+        }
+        Err(e) => log::debug!("Wallet watcher couldn't get transactions: {}", e),
+    }
+
+    messages
+}
@@ -0,0 +1,248 @@
+/// Synchronous delivery of a slate to the recipient wallet's foreign listener, as
+/// grin-wallet does when sending to a slatepack address. The listener is reached at the
+/// onion address behind the slatepack address, through a Tor SOCKS proxy.
+use grin_wallet_libwallet::slate_versions::{SlateVersion, VersionedSlate};
+use grin_wallet_util::OnionV3Address;
+
+use isahc::config::Configurable;
+use isahc::error::ErrorKind;
+use isahc::{ReadResponseExt, Request};
+use serde_json::{json, Value};
+
+use std::convert::TryFrom;
+use std::time::Duration;
+
+use super::{Slate, SlatepackAddress};
+use crate::error::GrinWalletInterfaceError;
+
+/// Circuits to an onion service can take a while to build
+const REQUEST_TIMEOUT_SECS: u64 = 120;
+
+pub struct TorSender {
+    url: String,
+    socks_proxy_addr: Option<String>,
+}
+
+impl TorSender {
+    /// Sender for the wallet behind `dest`, a slatepack address
+    pub fn new(dest: &str, socks_proxy_addr: &str) -> Result<Self, GrinWalletInterfaceError> {
+        Ok(TorSender {
+            url: foreign_api_url(dest)?,
+            socks_proxy_addr: Some(socks_proxy_addr.to_owned()),
+        })
+    }
+
+    /// Sender talking to a foreign listener directly over HTTP, without Tor
+    pub fn with_url(url: &str) -> Self {
+        TorSender {
+            url: url.to_owned(),
+            socks_proxy_addr: None,
+        }
+    }
+
+    /// Call `method` on the recipient's listener. Only failing to connect means for sure
+    /// that nothing was delivered, anything else goes to `failed`
+    fn post(
+        &self,
+        method: &str,
+        params: Value,
+        failed: fn(String) -> GrinWalletInterfaceError,
+    ) -> Result<Value, GrinWalletInterfaceError> {
+        let unreachable = |e: String| GrinWalletInterfaceError::RecipientUnreachable(e);
+        let body = json!({
+            "jsonrpc": "2.0",
+            "method": method,
+            "id": 1,
+            "params": params,
+        });
+        let mut builder = Request::post(&self.url)
+            .header("content-type", "application/json")
+            .timeout(Duration::from_secs(REQUEST_TIMEOUT_SECS));
+        if let Some(p) = &self.socks_proxy_addr {
+            // socks5h so the proxy resolves the onion address
+            let proxy = format!("socks5h://{}", p)
+                .parse()
+                .map_err(|e: isahc::http::uri::InvalidUri| unreachable(e.to_string()))?;
+            builder = builder.proxy(Some(proxy));
+        }
+        let request = builder.body(serde_json::to_vec(&body)?)?;
+        let mut response = isahc::send(request).map_err(|e| match e.kind() {
+            // Covers the SOCKS proxy and the onion service behind it
+            ErrorKind::ConnectionFailed | ErrorKind::NameResolution => unreachable(e.to_string()),
+            _ => failed(e.to_string()),
+        })?;
+        if !response.status().is_success() {
+            return Err(failed(format!("HTTP status {}", response.status())));
+        }
+        let response: Value = response.json().map_err(|e| failed(e.to_string()))?;
+        if !response["error"].is_null() {
+            return Err(failed(response["error"].to_string()));
+        }
+        match response["result"].get("Ok") {
+            Some(ok) => Ok(ok.clone()),
+            None => Err(failed(response["result"].to_string())),
+        }
+    }
+
+    /// Send the S1 slate to the recipient, who signs it and hands back S2. Once the slate
+    /// has gone out, a failure other than not connecting is `RecipientResponse`, as the
+    /// recipient may have it already
+    pub fn send_tx(&self, slate: &Slate) -> Result<Slate, GrinWalletInterfaceError> {
+        // Nothing is handed over until the version check passes
+        let version = self.post(
+            "check_version",
+            json!([]),
+            GrinWalletInterfaceError::RecipientUnreachable,
+        )?;
+        let supports_v4 = version["supported_slate_versions"]
+            .as_array()
+            .map_or(false, |v| v.iter().any(|s| s == "V4"));
+        if !supports_v4 {
+            return Err(GrinWalletInterfaceError::RecipientUnreachable(
+                "Recipient wallet doesn't support V4 slates".to_owned(),
+            ));
+        }
+
+        let versioned = VersionedSlate::into_version(slate.clone(), SlateVersion::V4)?;
+        let ret = self.post(
+            "receive_tx",
+            json!([versioned, null, null]),
+            GrinWalletInterfaceError::RecipientResponse,
+        )?;
+        let ret: VersionedSlate = serde_json::from_value(ret)?;
+        Ok(Slate::from(ret))
+    }
+}
+
+/// Foreign API endpoint of the wallet's onion service
+pub fn foreign_api_url(dest: &str) -> Result<String, GrinWalletInterfaceError> {
+    let address = SlatepackAddress::try_from(dest.trim())
+        .map_err(|_| GrinWalletInterfaceError::InvalidSlatepackAddress)?;
+    let onion = OnionV3Address::try_from(&address)
+        .map_err(|_| GrinWalletInterfaceError::InvalidSlatepackAddress)?;
+    Ok(format!("http://{}.onion/v2/foreign", onion))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::io::{BufRead, BufReader, Read, Write};
+    use std::net::TcpListener;
+    use std::sync::mpsc;
+    use std::thread;
+
+    /// Minimal stand-in for the recipient's foreign listener. Echoes back whatever slate
+    /// it receives, unless told to refuse it, and reports each method called.
+    fn mock_foreign_api(slate_versions: Value, accept: bool) -> (String, mpsc::Receiver<String>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}/v2/foreign", listener.local_addr().unwrap());
+        let (tx, rx) = mpsc::channel();
+
+        thread::spawn(move || {
+            for stream in listener.incoming() {
+                let mut stream = stream.unwrap();
+                let mut reader = BufReader::new(stream.try_clone().unwrap());
+
+                let mut content_length = 0;
+                loop {
+                    let mut line = String::new();
+                    reader.read_line(&mut line).unwrap();
+                    let line = line.trim_end().to_lowercase();
+                    if line.is_empty() {
+                        break;
+                    }
+                    if let Some(l) = line.strip_prefix("content-length:") {
+                        content_length = l.trim().parse().unwrap();
+                    }
+                }
+                let mut body = vec![0u8; content_length];
+                reader.read_exact(&mut body).unwrap();
+                let request: Value = serde_json::from_slice(&body).unwrap();
+                let method = request["method"].as_str().unwrap().to_owned();
+
+                let result = match method.as_str() {
+                    "check_version" => json!({"Ok": {
+                        "foreign_api_version": 2,
+                        "supported_slate_versions": slate_versions,
+                    }}),
+                    "receive_tx" if accept => json!({"Ok": request["params"][0]}),
+                    _ => json!({"Err": {"GenericError": "Unsupported"}}),
+                };
+                let _ = tx.send(method);
+
+                let body =
+                    json!({"jsonrpc": "2.0", "id": request["id"], "result": result}).to_string();
+                write!(
+                    stream,
+                    "HTTP/1.1 200 OK\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                    body.len(),
+                    body
+                )
+                .unwrap();
+            }
+        });
+
+        (url, rx)
+    }
+
+    #[test]
+    fn test_tor_sender_round_trip() {
+        let (url, rx) = mock_foreign_api(json!(["V4"]), true);
+        let slate = Slate::blank(2, false);
+
+        let ret = TorSender::with_url(&url).send_tx(&slate).unwrap();
+        assert_eq!(ret.id, slate.id);
+        assert_eq!(rx.recv().unwrap(), "check_version");
+        assert_eq!(rx.recv().unwrap(), "receive_tx");
+    }
+
+    #[test]
+    fn test_tor_sender_unsupported_version() {
+        let (url, rx) = mock_foreign_api(json!(["V3"]), true);
+        let slate = Slate::blank(2, false);
+
+        match TorSender::with_url(&url).send_tx(&slate) {
+            Err(GrinWalletInterfaceError::RecipientUnreachable(_)) => {}
+            r => panic!("expected RecipientUnreachable, got {:?}", r.map(|s| s.id)),
+        }
+        // The slate is never handed over
+        assert_eq!(rx.recv().unwrap(), "check_version");
+        assert!(rx.try_recv().is_err());
+    }
+
+    #[test]
+    fn test_tor_sender_refused() {
+        let (url, rx) = mock_foreign_api(json!(["V4"]), false);
+
+        // The recipient got the slate, so no falling back to a slatepack
+        match TorSender::with_url(&url).send_tx(&Slate::blank(2, false)) {
+            Err(GrinWalletInterfaceError::RecipientResponse(_)) => {}
+            r => panic!("expected RecipientResponse, got {:?}", r.map(|s| s.id)),
+        }
+        assert_eq!(rx.recv().unwrap(), "check_version");
+        assert_eq!(rx.recv().unwrap(), "receive_tx");
+    }
+
+    #[test]
+    fn test_tor_sender_unreachable() {
+        // Nothing listens on a port that was just released
+        let addr = TcpListener::bind("127.0.0.1:0")
+            .unwrap()
+            .local_addr()
+            .unwrap();
+        let url = format!("http://{}/v2/foreign", addr);
+
+        match TorSender::with_url(&url).send_tx(&Slate::blank(2, false)) {
+            Err(GrinWalletInterfaceError::RecipientUnreachable(_)) => {}
+            r => panic!("expected RecipientUnreachable, got {:?}", r.map(|s| s.id)),
+        }
+    }
+
+    #[test]
+    fn test_foreign_api_url_invalid_address() {
+        assert!(matches!(
+            foreign_api_url("not a slatepack address"),
+            Err(GrinWalletInterfaceError::InvalidSlatepackAddress)
+        ));
+    }
+}
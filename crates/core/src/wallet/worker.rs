@@ -0,0 +1,411 @@
+/// Dedicated thread wallet operations run on, one at a time and in the order they were
+/// submitted. Libwallet and LMDB calls block, so running them on the UI executor froze
+/// the interface for as long as they took; here the UI only awaits a channel.
+use futures::channel::oneshot;
+use futures::executor::block_on;
+
+use std::future::Future;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::{mpsc, Arc, Mutex, RwLock};
+use std::thread::{self, JoinHandle};
+
+use crate::error::GrinWalletInterfaceError;
+
+pub type JobId = u64;
+
+/// Makes a blocking call a running job is in give up, set with `JobContext::interruptible`
+type Interrupt = Arc<Mutex<Option<Box<dyn Fn() + Send>>>>;
+
+/// Latest progress a running job reported
+#[derive(Debug, Clone, PartialEq)]
+pub struct JobProgress {
+    pub percent: Option<u8>,
+    pub message: String,
+}
+
+/// What the worker is up to, cheap to read from the UI
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct WorkerStatus {
+    pub running: Option<JobId>,
+    pub queued: usize,
+    pub progress: Option<JobProgress>,
+}
+
+/// Handed to jobs submitted with `WalletWorker::submit`, to report progress and to
+/// stop early once cancelled
+#[derive(Clone)]
+pub struct JobContext {
+    id: JobId,
+    cancelled: Arc<AtomicBool>,
+    interrupt: Interrupt,
+    status: Arc<RwLock<WorkerStatus>>,
+}
+
+impl JobContext {
+    pub fn is_cancelled(&self) -> bool {
+        self.cancelled.load(Ordering::SeqCst)
+    }
+
+    /// Await `fut`, calling `interrupt` if the job is cancelled meanwhile. For calls that
+    /// block without looking at `is_cancelled`, but can be made to stop early.
+    pub async fn interruptible<Fut: Future>(
+        &self,
+        interrupt: impl Fn() + Send + 'static,
+        fut: Fut,
+    ) -> Fut::Output {
+        *self.interrupt.lock().unwrap() = Some(Box::new(interrupt));
+        if self.is_cancelled() {
+            // Cancelled before the hook was in place
+            if let Some(i) = self.interrupt.lock().unwrap().as_ref() {
+                i();
+            }
+        }
+        let res = fut.await;
+        self.interrupt.lock().unwrap().take();
+        res
+    }
+
+    pub fn report(&self, percent: Option<u8>, message: String) {
+        let mut status = self.status.write().unwrap();
+        if status.running == Some(self.id) {
+            status.progress = Some(JobProgress { percent, message });
+        }
+    }
+
+    fn finish(&self) {
+        let mut status = self.status.write().unwrap();
+        status.running = None;
+        status.progress = None;
+    }
+}
+
+/// Cancels a submitted job. A queued job never runs, a running one only stops early if
+/// it checks `JobContext::is_cancelled` or is in an interruptible call, and its result is
+/// still delivered otherwise so the UI doesn't treat a job as done while it's holding the
+/// wallet.
+#[derive(Clone)]
+pub struct JobHandle {
+    id: JobId,
+    cancelled: Arc<AtomicBool>,
+    interrupt: Interrupt,
+}
+
+impl JobHandle {
+    pub fn id(&self) -> JobId {
+        self.id
+    }
+
+    pub fn cancel(&self) {
+        self.cancelled.store(true, Ordering::SeqCst);
+        if let Some(i) = self.interrupt.lock().unwrap().as_ref() {
+            i();
+        }
+    }
+}
+
+struct Job {
+    context: JobContext,
+    handle: JobHandle,
+    // Value of `WalletWorker::generation` when submitted, older jobs were cancelled
+    generation: u64,
+    run: Box<dyn FnOnce(JobContext) + Send>,
+}
+
+/// Shared between the UI and the wallet watcher, whose refreshes run here too
+pub struct WalletWorker {
+    jobs: Mutex<Option<mpsc::Sender<Job>>>,
+    status: Arc<RwLock<WorkerStatus>>,
+    next_id: AtomicU64,
+    // Bumped by `cancel_all`, so jobs queued before never run
+    generation: Arc<AtomicU64>,
+    running: Arc<Mutex<Option<JobHandle>>>,
+    handle: Mutex<Option<JoinHandle<()>>>,
+}
+
+impl WalletWorker {
+    pub fn new() -> Self {
+        let (jobs, rx) = mpsc::channel::<Job>();
+        let status = Arc::new(RwLock::new(WorkerStatus::default()));
+        let generation = Arc::new(AtomicU64::new(0));
+        let running = Arc::new(Mutex::new(None));
+
+        let thread_status = status.clone();
+        let thread_generation = generation.clone();
+        let thread_running = running.clone();
+        let handle = thread::Builder::new()
+            .name("wallet-worker".to_owned())
+            .spawn(move || {
+                for job in rx {
+                    {
+                        let mut status = thread_status.write().unwrap();
+                        status.queued = status.queued.saturating_sub(1);
+                        if job.context.is_cancelled()
+                            || job.generation < thread_generation.load(Ordering::SeqCst)
+                        {
+                            continue;
+                        }
+                        status.running = Some(job.context.id);
+                        status.progress = None;
+                    }
+                    *thread_running.lock().unwrap() = Some(job.handle);
+                    (job.run)(job.context);
+                    thread_running.lock().unwrap().take();
+                }
+            })
+            .map_err(|e| log::error!("Unable to start wallet worker: {}", e))
+            .ok();
+
+        WalletWorker {
+            jobs: Mutex::new(handle.as_ref().map(|_| jobs)),
+            status,
+            next_id: AtomicU64::new(1),
+            generation,
+            running,
+            handle: Mutex::new(handle),
+        }
+    }
+
+    /// Run `fut` on the worker once the jobs ahead of it are done. The returned future
+    /// only waits for the result, so it's fine to hand to the UI executor.
+    pub fn run<Fut, T>(
+        &self,
+        fut: Fut,
+    ) -> impl Future<Output = Result<T, GrinWalletInterfaceError>> + Send + 'static
+    where
+        Fut: Future<Output = Result<T, GrinWalletInterfaceError>> + Send + 'static,
+        T: Send + 'static,
+    {
+        self.submit(move |_| fut).1
+    }
+
+    /// Like `run`, for jobs that report progress or can be cancelled. The job is built
+    /// on the worker, so its future doesn't need to be `Send`.
+    pub fn submit<F, Fut, T>(
+        &self,
+        job: F,
+    ) -> (
+        JobHandle,
+        impl Future<Output = Result<T, GrinWalletInterfaceError>> + Send + 'static,
+    )
+    where
+        F: FnOnce(JobContext) -> Fut + Send + 'static,
+        Fut: Future<Output = Result<T, GrinWalletInterfaceError>>,
+        T: Send + 'static,
+    {
+        let context = JobContext {
+            id: self.next_id.fetch_add(1, Ordering::SeqCst),
+            cancelled: Arc::new(AtomicBool::new(false)),
+            interrupt: Arc::new(Mutex::new(None)),
+            status: self.status.clone(),
+        };
+        let handle = JobHandle {
+            id: context.id,
+            cancelled: context.cancelled.clone(),
+            interrupt: context.interrupt.clone(),
+        };
+
+        let (tx, rx) = oneshot::channel();
+        let run = Box::new(move |context: JobContext| {
+            let res = block_on(job(context.clone()));
+            // Idle again before anyone waiting on the result hears about it
+            context.finish();
+            // The receiver is gone if the UI stopped waiting, nothing to do about it
+            let _ = tx.send(res);
+        });
+
+        let queued = match self.jobs.lock().unwrap().as_ref() {
+            Some(jobs) => {
+                // Counted before sending, so the worker never sees a job it didn't count
+                self.status.write().unwrap().queued += 1;
+                let sent = jobs
+                    .send(Job {
+                        context,
+                        handle: handle.clone(),
+                        generation: self.generation.load(Ordering::SeqCst),
+                        run,
+                    })
+                    .is_ok();
+                if !sent {
+                    let mut status = self.status.write().unwrap();
+                    status.queued = status.queued.saturating_sub(1);
+                }
+                sent
+            }
+            None => false,
+        };
+
+        let result = async move {
+            if !queued {
+                return Err(GrinWalletInterfaceError::WalletWorkerStopped);
+            }
+            // Dropped without an answer when the job was cancelled before it ran
+            rx.await
+                .map_err(|_| GrinWalletInterfaceError::WalletJobCancelled)?
+        };
+        (handle, result)
+    }
+
+    pub fn status(&self) -> WorkerStatus {
+        self.status.read().unwrap().clone()
+    }
+
+    /// Whether nothing is running or waiting to run
+    pub fn is_idle(&self) -> bool {
+        let status = self.status.read().unwrap();
+        status.running.is_none() && status.queued == 0
+    }
+
+    /// Cancel the running job and everything queued, before the wallet is closed
+    pub fn cancel_all(&self) {
+        self.generation.fetch_add(1, Ordering::SeqCst);
+        if let Some(job) = self.running.lock().unwrap().as_ref() {
+            job.cancel();
+        }
+    }
+
+    /// Stop taking jobs, cancel what's left and wait for the thread to exit so the wallet
+    /// isn't torn down in the middle of a job
+    pub fn shutdown(&self) {
+        self.cancel_all();
+        self.jobs.lock().unwrap().take();
+        let handle = self.handle.lock().unwrap().take();
+        if let Some(handle) = handle {
+            if handle.join().is_err() {
+                log::error!("Wallet worker panicked");
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    use std::sync::mpsc::channel;
+    use std::time::Duration;
+
+    #[test]
+    fn test_jobs_run_in_order() {
+        let worker = WalletWorker::new();
+        let first = worker.run(async { Ok(1) });
+        let second = worker.run(async { Ok(2) });
+        assert_eq!(block_on(second).unwrap(), 2);
+        assert_eq!(block_on(first).unwrap(), 1);
+        assert!(worker.is_idle());
+    }
+
+    #[test]
+    fn test_cancel_queued_job() {
+        let worker = WalletWorker::new();
+        let (release_tx, release_rx) = channel::<()>();
+        // Keep the worker busy so the next job stays queued
+        let (_, blocking) = worker.submit(move |_| async move {
+            release_rx.recv().unwrap();
+            Ok(())
+        });
+        let (handle, queued) = worker.submit(|_| async { Ok(()) });
+        handle.cancel();
+        release_tx.send(()).unwrap();
+
+        block_on(blocking).unwrap();
+        assert!(matches!(
+            block_on(queued),
+            Err(GrinWalletInterfaceError::WalletJobCancelled)
+        ));
+    }
+
+    #[test]
+    fn test_cancel_running_job() {
+        let worker = WalletWorker::new();
+        let (started_tx, started_rx) = channel::<()>();
+        let (release_tx, release_rx) = channel::<()>();
+        // Doesn't look at the context, so it runs to the end regardless
+        let (handle, running) = worker.submit(move |_| async move {
+            started_tx.send(()).unwrap();
+            release_rx.recv().unwrap();
+            Ok(1)
+        });
+        started_rx.recv_timeout(Duration::from_secs(5)).unwrap();
+        handle.cancel();
+        release_tx.send(()).unwrap();
+        assert_eq!(block_on(running).unwrap(), 1);
+    }
+
+    #[test]
+    fn test_cancel_all() {
+        let worker = WalletWorker::new();
+        let (started_tx, started_rx) = channel::<()>();
+        let (release_tx, release_rx) = channel::<()>();
+        // Blocks until interrupted, like a scan waiting on the node
+        let (_, running) = worker.submit(move |context| async move {
+            context
+                .interruptible(move || release_tx.send(()).unwrap(), async move {
+                    started_tx.send(()).unwrap();
+                    release_rx.recv().unwrap();
+                    Ok(1)
+                })
+                .await
+        });
+        let queued = worker.run(async { Ok(2) });
+        started_rx.recv_timeout(Duration::from_secs(5)).unwrap();
+
+        worker.cancel_all();
+        assert_eq!(block_on(running).unwrap(), 1);
+        assert!(matches!(
+            block_on(queued),
+            Err(GrinWalletInterfaceError::WalletJobCancelled)
+        ));
+        // Jobs submitted afterwards run as usual
+        assert_eq!(block_on(worker.run(async { Ok(3) })).unwrap(), 3);
+    }
+
+    #[test]
+    fn test_shutdown_joins() {
+        let worker = WalletWorker::new();
+        let (started_tx, started_rx) = channel::<()>();
+        let (done_tx, done_rx) = channel::<()>();
+        let job = worker.run(async move {
+            started_tx.send(()).unwrap();
+            std::thread::sleep(Duration::from_millis(50));
+            done_tx.send(()).unwrap();
+            Ok(())
+        });
+        started_rx.recv_timeout(Duration::from_secs(5)).unwrap();
+        worker.shutdown();
+        // Not interruptible, so shutting down waited for it to finish
+        assert!(done_rx.try_recv().is_ok());
+        block_on(job).unwrap();
+        assert!(matches!(
+            block_on(worker.run(async { Ok(()) })),
+            Err(GrinWalletInterfaceError::WalletWorkerStopped)
+        ));
+    }
+
+    #[test]
+    fn test_progress() {
+        let worker = WalletWorker::new();
+        let (reported_tx, reported_rx) = channel::<()>();
+        let (release_tx, release_rx) = channel::<()>();
+        let (_, job) = worker.submit(move |context| async move {
+            context.report(Some(50), "Halfway".to_owned());
+            reported_tx.send(()).unwrap();
+            release_rx.recv().unwrap();
+            Ok(())
+        });
+
+        reported_rx.recv_timeout(Duration::from_secs(5)).unwrap();
+        let status = worker.status();
+        assert!(status.running.is_some());
+        assert_eq!(
+            status.progress,
+            Some(JobProgress {
+                percent: Some(50),
+                message: "Halfway".to_owned(),
+            })
+        );
+
+        release_tx.send(()).unwrap();
+        block_on(job).unwrap();
+        assert_eq!(worker.status(), WorkerStatus::default());
+    }
+}
@@ -5,5 +5,6 @@ pub mod style;
 pub mod widget;
 
 pub use widget::header;
+pub use widget::qr_code;
 pub use widget::table_row;
 
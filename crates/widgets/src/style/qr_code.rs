@@ -0,0 +1,25 @@
+use iced::Color;
+
+/// The appearance of a QR code.
+#[derive(Debug, Clone, Copy)]
+pub struct Appearance {
+    pub dark: Color,
+    pub light: Color,
+}
+
+impl Default for Appearance {
+    fn default() -> Self {
+        Appearance {
+            dark: Color::BLACK,
+            light: Color::WHITE,
+        }
+    }
+}
+
+/// A set of rules that dictate the style of a QR code.
+pub trait StyleSheet {
+    type Style: std::default::Default + Copy;
+
+    /// Produces the appearance of a QR code.
+    fn appearance(&self, style: &Self::Style) -> Appearance;
+}
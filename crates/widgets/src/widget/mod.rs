@@ -1,2 +1,3 @@
 pub mod header;
+pub mod qr_code;
 pub mod table_row;
@@ -0,0 +1,177 @@
+use crate::style::qr_code::StyleSheet;
+use iced_core::{
+    layout, mouse, renderer, widget::Tree, Color, Element, Layout, Length, Rectangle, Size, Widget,
+};
+use qrcode::types::QrError;
+use qrcode::EcLevel;
+
+/// Number of light modules surrounding the code, as required by scanners.
+const QUIET_ZONE: usize = 4;
+
+/// The encoded modules of a QR code, computed once and drawn by [`QrCode`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Data {
+    width: usize,
+    modules: Vec<bool>,
+}
+
+impl Data {
+    /// Encodes `data` into a QR code, failing if it does not fit in a single code.
+    pub fn new(data: impl AsRef<[u8]>) -> Result<Self, QrError> {
+        let code = qrcode::QrCode::with_error_correction_level(data, EcLevel::L)?;
+
+        Ok(Data {
+            width: code.width(),
+            modules: code
+                .to_colors()
+                .into_iter()
+                .map(|c| c == qrcode::Color::Dark)
+                .collect(),
+        })
+    }
+
+    /// Number of modules along one side of the code, without the quiet zone.
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    fn is_dark(&self, x: usize, y: usize) -> bool {
+        self.modules[y * self.width + x]
+    }
+}
+
+/// A widget drawing the modules of a [`Data`] as a square QR code.
+#[allow(missing_debug_implementations)]
+pub struct QrCode<'a, Renderer>
+where
+    Renderer: iced_core::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    data: &'a Data,
+    cell_size: f32,
+    style: <Renderer::Theme as StyleSheet>::Style,
+}
+
+impl<'a, Renderer> QrCode<'a, Renderer>
+where
+    Renderer: iced_core::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    /// Creates a [`QrCode`] drawing the given [`Data`].
+    pub fn new(data: &'a Data) -> Self {
+        QrCode {
+            data,
+            cell_size: 4.0,
+            style: Default::default(),
+        }
+    }
+
+    /// Sets the size in pixels of a single module of the [`QrCode`].
+    pub fn cell_size(mut self, cell_size: f32) -> Self {
+        self.cell_size = cell_size.max(1.0);
+        self
+    }
+
+    /// Sets the style of the [`QrCode`].
+    pub fn style(mut self, style: impl Into<<Renderer::Theme as StyleSheet>::Style>) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    fn side(&self) -> f32 {
+        (self.data.width + 2 * QUIET_ZONE) as f32 * self.cell_size
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer> for QrCode<'a, Renderer>
+where
+    Renderer: iced_core::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    fn width(&self) -> Length {
+        Length::Fixed(self.side())
+    }
+
+    fn height(&self) -> Length {
+        Length::Fixed(self.side())
+    }
+
+    fn layout(&self, _renderer: &Renderer, limits: &layout::Limits) -> layout::Node {
+        let side = self.side();
+        let size = limits
+            .width(Length::Fixed(side))
+            .height(Length::Fixed(side))
+            .resolve(Size::new(side, side));
+
+        layout::Node::new(size)
+    }
+
+    fn draw(
+        &self,
+        _tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Renderer::Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        _cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+    ) {
+        let bounds = layout.bounds();
+        let appearance = theme.appearance(&self.style);
+
+        fill(renderer, bounds, appearance.light);
+
+        let origin_x = bounds.x + QUIET_ZONE as f32 * self.cell_size;
+        let origin_y = bounds.y + QUIET_ZONE as f32 * self.cell_size;
+
+        // Draw runs of adjacent dark modules as a single quad to keep the
+        // number of primitives low for large codes.
+        for y in 0..self.data.width {
+            let mut x = 0;
+            while x < self.data.width {
+                if !self.data.is_dark(x, y) {
+                    x += 1;
+                    continue;
+                }
+
+                let start = x;
+                while x < self.data.width && self.data.is_dark(x, y) {
+                    x += 1;
+                }
+
+                fill(
+                    renderer,
+                    Rectangle {
+                        x: origin_x + start as f32 * self.cell_size,
+                        y: origin_y + y as f32 * self.cell_size,
+                        width: (x - start) as f32 * self.cell_size,
+                        height: self.cell_size,
+                    },
+                    appearance.dark,
+                );
+            }
+        }
+    }
+}
+
+fn fill<Renderer: iced_core::Renderer>(renderer: &mut Renderer, bounds: Rectangle, color: Color) {
+    renderer.fill_quad(
+        renderer::Quad {
+            bounds,
+            border_radius: 0.0.into(),
+            border_width: 0.0,
+            border_color: Color::TRANSPARENT,
+        },
+        color,
+    );
+}
+
+impl<'a, Message, Renderer> From<QrCode<'a, Renderer>> for Element<'a, Message, Renderer>
+where
+    Renderer: 'a + iced_core::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    fn from(qr_code: QrCode<'a, Renderer>) -> Element<'a, Message, Renderer> {
+        Element::new(qr_code)
+    }
+}
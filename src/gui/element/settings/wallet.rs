@@ -1,16 +1,26 @@
+use crate::log_error;
+
 use {
-	super::DEFAULT_FONT_SIZE,
+	super::{super::SMALLER_FONT_SIZE, DEFAULT_FONT_SIZE},
 	crate::gui::{GrinGui, Interaction, Message},
 	crate::localization::localized_string,
+	crate::Result,
+	anyhow::Context,
 	grin_gui_core::config::{Config, TxMethod},
+	grin_gui_core::error::GrinWalletInterfaceError,
 	grin_gui_core::fs::PersistentData,
 	grin_gui_core::theme::{
 		Button, Column, Container, Element, PickList, Row, Scrollable, Text, TextInput,
 	},
+	grin_gui_core::wallet::{
+		listener::ListenerInfo, mixnet, password::PasswordStrength, SecretString, WalletInterface,
+		Zeroizing,
+	},
 	iced::widget::{button, pick_list, scrollable, text_input, Checkbox, Space},
 	iced::Length,
-	iced::{alignment, Alignment},
+	iced::{alignment, Alignment, Command},
 	serde::{Deserialize, Serialize},
+	std::sync::{Arc, RwLock},
 };
 
 #[derive(Debug, Clone)]
@@ -19,6 +29,15 @@ pub struct StateContainer {
 	mw_mixnet_address_1: String,
 	mw_mixnet_address_2: String,
 	mw_mixnet_address_3: String,
+	// Where the foreign listener is reachable, None when it isn't running
+	pub listener: Option<ListenerInfo>,
+	listener_starting: bool,
+	old_password: SecretString,
+	new_password: SecretString,
+	repeat_password: SecretString,
+	password_changing: bool,
+	// Outcome of the last password change, localized, and whether it worked
+	password_status: Option<(String, bool)>,
 }
 
 impl Default for StateContainer {
@@ -27,16 +46,66 @@ impl Default for StateContainer {
 			mw_mixnet_address_1: "".to_string(),
 			mw_mixnet_address_2: "".to_string(),
 			mw_mixnet_address_3: "".to_string(),
+			listener: None,
+			listener_starting: false,
+			old_password: Default::default(),
+			new_password: Default::default(),
+			repeat_password: Default::default(),
+			password_changing: false,
+			password_status: None,
 		}
 	}
 }
 
-#[derive(Serialize, Deserialize, Debug, Clone)]
+#[derive(Debug, Clone)]
 pub enum LocalViewInteraction {
 	TxMethodSelected(TxMethod),
+	IdleLockSelected(IdleLock),
 	MwMixnetAddress1Changed(String),
 	MwMixnetAddress2Changed(String),
 	MwMixnetAddress3Changed(String),
+	MwMixnetServerUrlChanged(String),
+	TorDispatchToggled(bool),
+	TorSocksProxyChanged(String),
+	ListenerEnabledToggled(bool),
+	ListenerAddrChanged(String),
+	ListenerUseTorToggled(bool),
+	StartListener,
+	StopListener,
+
+	ListenerStarted(ListenerInfo),
+	ListenerStartError(Arc<RwLock<Option<anyhow::Error>>>),
+
+	OldPasswordChanged(String),
+	NewPasswordChanged(String),
+	RepeatPasswordChanged(String),
+	ChangePassword,
+	PasswordChanged,
+	PasswordChangeError(Arc<RwLock<Option<anyhow::Error>>>),
+}
+
+/// Choices for how long a wallet may sit idle before it's closed
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct IdleLock(Option<u32>);
+
+impl IdleLock {
+	const ALL: [IdleLock; 6] = [
+		IdleLock(None),
+		IdleLock(Some(1)),
+		IdleLock(Some(5)),
+		IdleLock(Some(15)),
+		IdleLock(Some(30)),
+		IdleLock(Some(60)),
+	];
+}
+
+impl std::fmt::Display for IdleLock {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		match self.0 {
+			None => write!(f, "{}", localized_string("idle-lock-never")),
+			Some(m) => write!(f, "{} {}", m, localized_string("idle-lock-minutes")),
+		}
+	}
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -46,7 +115,10 @@ pub enum Mode {
 	General,
 }
 
-pub fn handle_message(grin_gui: &mut GrinGui, message: LocalViewInteraction) {
+pub fn handle_message(
+	grin_gui: &mut GrinGui,
+	message: LocalViewInteraction,
+) -> Result<Command<Message>> {
 	let state = &mut grin_gui.wallet_settings_state;
 	let mut check_mixnet_config = || {
 		if grin_gui.config.mixnet_keys.is_none() {
@@ -78,6 +150,13 @@ pub fn handle_message(grin_gui: &mut GrinGui, message: LocalViewInteraction) {
 			grin_gui.config.tx_method = method;
 			let _ = grin_gui.config.save();
 		}
+		LocalViewInteraction::IdleLockSelected(lock) => {
+			log::debug!("Interaction::IdleLockSelectedSettings({:?})", lock);
+			if let Some(i) = grin_gui.config.current_wallet_index {
+				grin_gui.config.wallets[i].idle_lock_minutes = lock.0;
+				let _ = grin_gui.config.save();
+			}
+		}
 		LocalViewInteraction::MwMixnetAddress1Changed(value) => {
 			check_mixnet_config();
 			grin_gui.config.mixnet_keys.as_mut().unwrap()[0] = value.clone();
@@ -96,7 +175,159 @@ pub fn handle_message(grin_gui: &mut GrinGui, message: LocalViewInteraction) {
 			state.mw_mixnet_address_3 = value;
 			let _ = grin_gui.config.save();
 		}
+		LocalViewInteraction::MwMixnetServerUrlChanged(value) => {
+			grin_gui.config.mixnet.server_url = value;
+			let _ = grin_gui.config.save();
+		}
+		LocalViewInteraction::TorDispatchToggled(enabled) => {
+			grin_gui.config.tor_dispatch.enabled = enabled;
+			let _ = grin_gui.config.save();
+		}
+		LocalViewInteraction::TorSocksProxyChanged(value) => {
+			grin_gui.config.tor_dispatch.socks_proxy_addr = value;
+			let _ = grin_gui.config.save();
+		}
+		LocalViewInteraction::ListenerEnabledToggled(enabled) => {
+			grin_gui.config.foreign_listener.enabled = enabled;
+			let _ = grin_gui.config.save();
+		}
+		LocalViewInteraction::ListenerAddrChanged(value) => {
+			grin_gui.config.foreign_listener.listen_addr = value;
+			let _ = grin_gui.config.save();
+		}
+		LocalViewInteraction::ListenerUseTorToggled(use_tor) => {
+			grin_gui.config.foreign_listener.use_tor = use_tor;
+			let _ = grin_gui.config.save();
+		}
+		LocalViewInteraction::StartListener => {
+			state.listener_starting = true;
+
+			let w = grin_gui.wallet_interface.clone();
+			let addr = grin_gui.config.foreign_listener.listen_addr.clone();
+			let use_tor = grin_gui.config.foreign_listener.use_tor;
+			let fut = grin_gui
+				.wallet_worker
+				.run(WalletInterface::start_foreign_listener(w, addr, use_tor));
+
+			return Ok(Command::perform(fut, |r| {
+				match r.context("Failed to start foreign listener") {
+					Ok(info) => Message::Interaction(Interaction::WalletSettingsViewInteraction(
+						LocalViewInteraction::ListenerStarted(info),
+					)),
+					Err(e) => Message::Interaction(Interaction::WalletSettingsViewInteraction(
+						LocalViewInteraction::ListenerStartError(Arc::new(RwLock::new(Some(e)))),
+					)),
+				}
+			}));
+		}
+		LocalViewInteraction::StopListener => {
+			state.listener = None;
+
+			let w = grin_gui.wallet_interface.clone();
+			let fut = grin_gui.wallet_worker.run(async move {
+				WalletInterface::stop_foreign_listener(w);
+				Ok(())
+			});
+			return Ok(Command::perform(fut, |_| Message::None(())));
+		}
+		LocalViewInteraction::ListenerStarted(info) => {
+			state.listener_starting = false;
+			state.listener = Some(info);
+		}
+		LocalViewInteraction::ListenerStartError(err) => {
+			state.listener_starting = false;
+			state.listener = None;
+			grin_gui.error = err.write().unwrap().take();
+			if let Some(e) = grin_gui.error.as_ref() {
+				log_error(e);
+			}
+		}
+		LocalViewInteraction::OldPasswordChanged(value) => {
+			state.old_password = Zeroizing::new(value);
+			state.password_status = None;
+		}
+		LocalViewInteraction::NewPasswordChanged(value) => {
+			state.new_password = Zeroizing::new(value);
+			state.password_status = None;
+		}
+		LocalViewInteraction::RepeatPasswordChanged(value) => {
+			state.repeat_password = Zeroizing::new(value);
+			state.password_status = None;
+		}
+		LocalViewInteraction::ChangePassword => {
+			log::debug!("Interaction::WalletSettingsViewInteraction(ChangePassword)");
+			if !grin_gui.wallet_interface.read().unwrap().wallet_is_open {
+				state.password_status =
+					Some((localized_string("change-password-wallet-not-open"), false));
+				return Ok(Command::none());
+			}
+			state.password_changing = true;
+			state.password_status = None;
+
+			// Taken out of the inputs, they're typed again if the change fails
+			let w = grin_gui.wallet_interface.clone();
+			let old_password = std::mem::take(&mut state.old_password);
+			let new_password = std::mem::take(&mut state.new_password);
+			state.repeat_password = Default::default();
+			let fut = grin_gui.wallet_worker.run(WalletInterface::change_password(
+				w,
+				old_password,
+				new_password,
+			));
+
+			return Ok(Command::perform(fut, |r| {
+				match r.context("Failed to Change Password") {
+					Ok(()) => Message::Interaction(Interaction::WalletSettingsViewInteraction(
+						LocalViewInteraction::PasswordChanged,
+					)),
+					Err(e) => Message::Interaction(Interaction::WalletSettingsViewInteraction(
+						LocalViewInteraction::PasswordChangeError(Arc::new(RwLock::new(Some(e)))),
+					)),
+				}
+			}));
+		}
+		LocalViewInteraction::PasswordChanged => {
+			state.password_changing = false;
+			state.password_status = Some((localized_string("change-password-success"), true));
+		}
+		LocalViewInteraction::PasswordChangeError(err) => {
+			state.password_changing = false;
+			let e = match err.write().unwrap().take() {
+				Some(e) => e,
+				None => return Ok(Command::none()),
+			};
+			log_error(&e);
+			if let Some(GrinWalletInterfaceError::PasswordChangedReopenFailed(_)) =
+				e.downcast_ref::<GrinWalletInterfaceError>()
+			{
+				// The new password is in effect, the wallet just has to be opened with it
+				state.password_status =
+					Some((localized_string("change-password-reopen-failed"), true));
+				crate::gui::element::wallet::operation::home::clear_wallet_data(grin_gui);
+				grin_gui
+					.wallet_state
+					.operation_state
+					.open_state
+					.wallet_message = localized_string("change-password-reopen-failed");
+				grin_gui.menu_state.mode = crate::gui::element::menu::Mode::Wallet;
+			} else {
+				// Most likely a wrong current password, which the user is told about in place
+				state.password_status = Some((localized_string("change-password-error"), false));
+			}
+		}
 	}
+
+	Ok(Command::none())
+}
+
+/// Command starting the foreign listener, used when a wallet is opened
+pub fn start_listener_command() -> Command<Message> {
+	let fut = move || async {};
+	Command::perform(fut(), |_| {
+		Message::Interaction(Interaction::WalletSettingsViewInteraction(
+			LocalViewInteraction::StartListener,
+		))
+	})
 }
 
 pub fn data_container<'a>(state: &'a StateContainer, config: &Config) -> Container<'a, Message> {
@@ -133,6 +364,33 @@ pub fn data_container<'a>(state: &'a StateContainer, config: &Config) -> Contain
 			.push(tx_method_data_row)
 	};
 
+	// Set per wallet, so only offered once there's a current wallet
+	let idle_lock_column = config.current_wallet_index.map(|i| {
+		let idle_lock_container =
+			Container::new(Text::new(localized_string("idle-lock")).size(DEFAULT_FONT_SIZE))
+				.style(grin_gui_core::theme::ContainerStyle::NormalBackground);
+
+		let idle_lock = IdleLock(config.wallets[i].idle_lock_minutes);
+		let idle_lock_pick_list = PickList::new(&IdleLock::ALL[..], Some(idle_lock), |l| {
+			Message::Interaction(Interaction::WalletSettingsViewInteraction(
+				LocalViewInteraction::IdleLockSelected(l),
+			))
+		})
+		.text_size(DEFAULT_FONT_SIZE)
+		.width(Length::Fixed(120.0))
+		.style(grin_gui_core::theme::PickListStyle::Primary);
+
+		let idle_lock_data_row = Row::new()
+			.push(idle_lock_pick_list)
+			.align_items(Alignment::Center)
+			.height(Length::Fixed(26.0));
+
+		Column::new()
+			.push(idle_lock_container)
+			.push(Space::new(Length::Fixed(0.0), Length::Fixed(5.0)))
+			.push(idle_lock_data_row)
+	});
+
 	let mw_mixnet_address_column = {
 		let mw_mixnet_address_container = Container::new(
 			Text::new(localized_string("mw-mixnet-addresses")).size(DEFAULT_FONT_SIZE),
@@ -199,31 +457,373 @@ pub fn data_container<'a>(state: &'a StateContainer, config: &Config) -> Contain
 
 		let mw_mixnet_address_3_input: Element<Interaction> = mw_mixnet_address_3_input.into();
 
-		// Data row for theme picker list.
-		/*let tx_method_data_row = Row::new()
-		.push(tx_method_pick_list)
-		.align_items(Alignment::Center)
-		.height(Length::Fixed(26.0));*/
+		let mw_mixnet_server_url = Text::new(localized_string("mw-mixnet-server-url"))
+			.size(DEFAULT_FONT_SIZE)
+			.horizontal_alignment(alignment::Horizontal::Left);
 
-		Column::new()
+		let mw_mixnet_server_url_container = Container::new(mw_mixnet_server_url)
+			.style(grin_gui_core::theme::ContainerStyle::NormalBackground);
+
+		let mw_mixnet_server_url_input = TextInput::new("", &config.mixnet.server_url)
+			.on_input(|s| {
+				Interaction::WalletSettingsViewInteraction(
+					LocalViewInteraction::MwMixnetServerUrlChanged(s),
+				)
+			})
+			.size(DEFAULT_FONT_SIZE)
+			.padding(6)
+			.width(Length::Fixed(400.0))
+			.style(grin_gui_core::theme::TextInputStyle::AddonsQuery);
+
+		let mw_mixnet_server_url_input: Element<Interaction> = mw_mixnet_server_url_input.into();
+
+		let mut column = Column::new()
 			.push(mw_mixnet_address_container)
 			.push(Space::new(Length::Fixed(0.0), Length::Fixed(5.0)))
-			.push(mw_mixnet_address_1_container)
+			.push(mw_mixnet_server_url_container)
+			.push(Space::new(Length::Fixed(0.0), Length::Fixed(5.0)))
+			.push(mw_mixnet_server_url_input.map(Message::Interaction));
+
+		let key_rows = [
+			(
+				mw_mixnet_address_1_container,
+				mw_mixnet_address_1_input,
+				&config_addr_1,
+			),
+			(
+				mw_mixnet_address_2_container,
+				mw_mixnet_address_2_input,
+				&config_addr_2,
+			),
+			(
+				mw_mixnet_address_3_container,
+				mw_mixnet_address_3_input,
+				&config_addr_3,
+			),
+		];
+		for (label, input, key) in key_rows {
+			column = column
+				.push(Space::new(Length::Fixed(0.0), Length::Fixed(5.0)))
+				.push(label)
+				.push(Space::new(Length::Fixed(0.0), Length::Fixed(5.0)))
+				.push(input.map(Message::Interaction));
+
+			// Checked as typed, swaps refuse to start until all three are valid
+			if !key.trim().is_empty() && mixnet::parse_server_key(key).is_none() {
+				column = column
+					.push(Space::new(Length::Fixed(0.0), Length::Fixed(5.0)))
+					.push(
+						Container::new(
+							Text::new(localized_string("mw-mixnet-key-invalid"))
+								.size(SMALLER_FONT_SIZE),
+						)
+						.style(grin_gui_core::theme::ContainerStyle::ErrorForeground),
+					);
+			}
+		}
+
+		column
+	};
+
+	let tor_dispatch_column = {
+		let tor_dispatch_container =
+			Container::new(Text::new(localized_string("tor-dispatch")).size(DEFAULT_FONT_SIZE))
+				.style(grin_gui_core::theme::ContainerStyle::NormalBackground);
+
+		let enabled = Checkbox::new(
+			localized_string("tor-dispatch-enabled"),
+			config.tor_dispatch.enabled,
+			|b| {
+				Interaction::WalletSettingsViewInteraction(
+					LocalViewInteraction::TorDispatchToggled(b),
+				)
+			},
+		)
+		.style(grin_gui_core::theme::CheckboxStyle::Normal)
+		.text_size(DEFAULT_FONT_SIZE)
+		.spacing(10);
+		let enabled: Element<Interaction> = enabled.into();
+
+		let socks_proxy = Text::new(localized_string("tor-socks-proxy"))
+			.size(DEFAULT_FONT_SIZE)
+			.horizontal_alignment(alignment::Horizontal::Left);
+		let socks_proxy_container = Container::new(socks_proxy)
+			.style(grin_gui_core::theme::ContainerStyle::NormalBackground);
+
+		let socks_proxy_input =
+			TextInput::new("127.0.0.1:9050", &config.tor_dispatch.socks_proxy_addr)
+				.on_input(|s| {
+					Interaction::WalletSettingsViewInteraction(
+						LocalViewInteraction::TorSocksProxyChanged(s),
+					)
+				})
+				.size(DEFAULT_FONT_SIZE)
+				.padding(6)
+				.width(Length::Fixed(200.0))
+				.style(grin_gui_core::theme::TextInputStyle::AddonsQuery);
+		let socks_proxy_input: Element<Interaction> = socks_proxy_input.into();
+
+		Column::new()
+			.push(tor_dispatch_container)
+			.push(Space::new(Length::Fixed(0.0), Length::Fixed(5.0)))
+			.push(enabled.map(Message::Interaction))
 			.push(Space::new(Length::Fixed(0.0), Length::Fixed(5.0)))
-			.push(mw_mixnet_address_1_input.map(Message::Interaction))
+			.push(socks_proxy_container)
 			.push(Space::new(Length::Fixed(0.0), Length::Fixed(5.0)))
-			.push(mw_mixnet_address_2_container)
+			.push(socks_proxy_input.map(Message::Interaction))
+	};
+
+	let listener_column = {
+		let listener_container =
+			Container::new(Text::new(localized_string("foreign-listener")).size(DEFAULT_FONT_SIZE))
+				.style(grin_gui_core::theme::ContainerStyle::NormalBackground);
+
+		let status = match (&state.listener, state.listener_starting) {
+			(_, true) => localized_string("foreign-listener-starting"),
+			(Some(info), _) => match &info.onion_address {
+				Some(onion) => format!(
+					"{} {} ({})",
+					localized_string("foreign-listener-running"),
+					info.addr,
+					onion
+				),
+				None => format!(
+					"{} {}",
+					localized_string("foreign-listener-running"),
+					info.addr
+				),
+			},
+			(None, _) => localized_string("foreign-listener-stopped"),
+		};
+		let status_container = Container::new(Text::new(status).size(SMALLER_FONT_SIZE))
+			.style(grin_gui_core::theme::ContainerStyle::NormalBackground);
+
+		let enabled = Checkbox::new(
+			localized_string("foreign-listener-enabled"),
+			config.foreign_listener.enabled,
+			|b| {
+				Interaction::WalletSettingsViewInteraction(
+					LocalViewInteraction::ListenerEnabledToggled(b),
+				)
+			},
+		)
+		.style(grin_gui_core::theme::CheckboxStyle::Normal)
+		.text_size(DEFAULT_FONT_SIZE)
+		.spacing(10);
+		let enabled: Element<Interaction> = enabled.into();
+
+		let use_tor = Checkbox::new(
+			localized_string("foreign-listener-use-tor"),
+			config.foreign_listener.use_tor,
+			|b| {
+				Interaction::WalletSettingsViewInteraction(
+					LocalViewInteraction::ListenerUseTorToggled(b),
+				)
+			},
+		)
+		.style(grin_gui_core::theme::CheckboxStyle::Normal)
+		.text_size(DEFAULT_FONT_SIZE)
+		.spacing(10);
+		let use_tor: Element<Interaction> = use_tor.into();
+
+		let addr = Text::new(localized_string("foreign-listener-addr"))
+			.size(DEFAULT_FONT_SIZE)
+			.horizontal_alignment(alignment::Horizontal::Left);
+		let addr_container =
+			Container::new(addr).style(grin_gui_core::theme::ContainerStyle::NormalBackground);
+
+		let addr_input = TextInput::new("127.0.0.1:3415", &config.foreign_listener.listen_addr)
+			.on_input(|s| {
+				Interaction::WalletSettingsViewInteraction(
+					LocalViewInteraction::ListenerAddrChanged(s),
+				)
+			})
+			.size(DEFAULT_FONT_SIZE)
+			.padding(6)
+			.width(Length::Fixed(200.0))
+			.style(grin_gui_core::theme::TextInputStyle::AddonsQuery);
+		let addr_input: Element<Interaction> = addr_input.into();
+
+		let (button_label, button_interaction) = match state.listener {
+			Some(_) => (
+				localized_string("foreign-listener-stop"),
+				LocalViewInteraction::StopListener,
+			),
+			None => (
+				localized_string("foreign-listener-start"),
+				LocalViewInteraction::StartListener,
+			),
+		};
+		let button_label_container =
+			Container::new(Text::new(button_label).size(SMALLER_FONT_SIZE))
+				.height(Length::Fixed(14.0))
+				.center_y()
+				.center_x();
+		let mut button = Button::new(button_label_container)
+			.style(grin_gui_core::theme::ButtonStyle::Bordered)
+			.padding(2);
+		if !state.listener_starting {
+			button = button.on_press(Interaction::WalletSettingsViewInteraction(
+				button_interaction,
+			));
+		}
+		let button: Element<Interaction> = button.into();
+
+		let status_row = Row::new()
+			.push(button.map(Message::Interaction))
+			.push(Space::new(Length::Fixed(10.0), Length::Fixed(0.0)))
+			.push(status_container)
+			.align_items(Alignment::Center);
+
+		Column::new()
+			.push(listener_container)
+			.push(Space::new(Length::Fixed(0.0), Length::Fixed(5.0)))
+			.push(enabled.map(Message::Interaction))
+			.push(Space::new(Length::Fixed(0.0), Length::Fixed(5.0)))
+			.push(use_tor.map(Message::Interaction))
+			.push(Space::new(Length::Fixed(0.0), Length::Fixed(5.0)))
+			.push(addr_container)
+			.push(Space::new(Length::Fixed(0.0), Length::Fixed(5.0)))
+			.push(addr_input.map(Message::Interaction))
+			.push(Space::new(Length::Fixed(0.0), Length::Fixed(5.0)))
+			.push(status_row)
+	};
+
+	let change_password_column = {
+		let change_password_container =
+			Container::new(Text::new(localized_string("change-password")).size(DEFAULT_FONT_SIZE))
+				.style(grin_gui_core::theme::ContainerStyle::NormalBackground);
+
+		let password_input =
+			|placeholder: &str, value: &str, on_input: fn(String) -> LocalViewInteraction| {
+				let input: Element<Interaction> = TextInput::new(placeholder, value)
+					.on_input(move |s| Interaction::WalletSettingsViewInteraction(on_input(s)))
+					.size(DEFAULT_FONT_SIZE)
+					.padding(6)
+					.width(Length::Fixed(200.0))
+					.style(grin_gui_core::theme::TextInputStyle::AddonsQuery)
+					.password()
+					.into();
+				input.map(Message::Interaction)
+			};
+
+		let old_password_input = password_input(
+			&localized_string("change-password-old"),
+			state.old_password.as_str(),
+			LocalViewInteraction::OldPasswordChanged,
+		);
+		let new_password_input = password_input(
+			&localized_string("change-password-new"),
+			state.new_password.as_str(),
+			LocalViewInteraction::NewPasswordChanged,
+		);
+		let repeat_password_input = password_input(
+			&localized_string("password-repeat"),
+			state.repeat_password.as_str(),
+			LocalViewInteraction::RepeatPasswordChanged,
+		);
+
+		let mut column = Column::new()
+			.push(change_password_container)
 			.push(Space::new(Length::Fixed(0.0), Length::Fixed(5.0)))
-			.push(mw_mixnet_address_2_input.map(Message::Interaction))
+			.push(old_password_input)
 			.push(Space::new(Length::Fixed(0.0), Length::Fixed(5.0)))
-			.push(mw_mixnet_address_3_container)
+			.push(new_password_input)
 			.push(Space::new(Length::Fixed(0.0), Length::Fixed(5.0)))
-			.push(mw_mixnet_address_3_input.map(Message::Interaction))
+			.push(repeat_password_input);
+
+		if !state.new_password.is_empty() {
+			let strength = PasswordStrength::of(&state.new_password);
+			let strength_value = match strength {
+				PasswordStrength::Weak => localized_string("password-strength-weak"),
+				PasswordStrength::Fair => localized_string("password-strength-fair"),
+				PasswordStrength::Strong => localized_string("password-strength-strong"),
+			};
+			let mut strength_text = Text::new(format!(
+				"{}: {}",
+				localized_string("password-strength"),
+				strength_value
+			))
+			.size(SMALLER_FONT_SIZE);
+			if strength == PasswordStrength::Weak {
+				strength_text = strength_text.style(grin_gui_core::theme::text::TextStyle::Warning);
+			}
+			column = column
+				.push(Space::new(Length::Fixed(0.0), Length::Fixed(5.0)))
+				.push(
+					Container::new(strength_text)
+						.style(grin_gui_core::theme::ContainerStyle::NormalBackground),
+				);
+		}
+
+		let passwords_match = state.new_password == state.repeat_password;
+		if !passwords_match && !state.repeat_password.is_empty() {
+			column = column
+				.push(Space::new(Length::Fixed(0.0), Length::Fixed(5.0)))
+				.push(
+					Container::new(
+						Text::new(localized_string("setup-grin-passwords-dont-match"))
+							.size(SMALLER_FONT_SIZE),
+					)
+					.style(grin_gui_core::theme::ContainerStyle::ErrorForeground),
+				);
+		}
+
+		let button_label_container = Container::new(
+			Text::new(localized_string("change-password-submit")).size(SMALLER_FONT_SIZE),
+		)
+		.height(Length::Fixed(14.0))
+		.center_y()
+		.center_x();
+		let mut button = Button::new(button_label_container)
+			.style(grin_gui_core::theme::ButtonStyle::Bordered)
+			.padding(2);
+		if !state.password_changing
+			&& !state.old_password.is_empty()
+			&& !state.new_password.is_empty()
+			&& passwords_match
+		{
+			button = button.on_press(Interaction::WalletSettingsViewInteraction(
+				LocalViewInteraction::ChangePassword,
+			));
+		}
+		let button: Element<Interaction> = button.into();
+
+		let mut status_row = Row::new()
+			.push(button.map(Message::Interaction))
+			.align_items(Alignment::Center);
+		if let Some((ref status, ok)) = state.password_status {
+			let mut status_text = Text::new(status).size(SMALLER_FONT_SIZE);
+			if !ok {
+				status_text = status_text.style(grin_gui_core::theme::text::TextStyle::Warning);
+			}
+			status_row = status_row
+				.push(Space::new(Length::Fixed(10.0), Length::Fixed(0.0)))
+				.push(
+					Container::new(status_text)
+						.style(grin_gui_core::theme::ContainerStyle::NormalBackground),
+				);
+		}
+
+		column
+			.push(Space::new(Length::Fixed(0.0), Length::Fixed(5.0)))
+			.push(status_row)
 	};
 
 	let wrap = {
-		Column::new()
+		let mut wrap = Column::new()
 			.push(tx_method_column)
+			.push(Space::new(Length::Fixed(0.0), Length::Fixed(10.0)));
+		if let Some(idle_lock_column) = idle_lock_column {
+			wrap = wrap
+				.push(idle_lock_column)
+				.push(Space::new(Length::Fixed(0.0), Length::Fixed(10.0)));
+		}
+		wrap.push(change_password_column)
+			.push(Space::new(Length::Fixed(0.0), Length::Fixed(10.0)))
+			.push(listener_column)
+			.push(Space::new(Length::Fixed(0.0), Length::Fixed(10.0)))
+			.push(tor_dispatch_column)
 			.push(Space::new(Length::Fixed(0.0), Length::Fixed(10.0)))
 			.push(mw_mixnet_address_column)
 	};
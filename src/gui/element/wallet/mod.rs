@@ -22,6 +22,8 @@ pub struct StateContainer {
 	pub operation_state: operation::StateContainer,
 	// When changed to true, this should stay false until a config exists
 	has_config_check_failed_one_time: bool,
+	// Wallet whose config file was last looked for, so it's only checked on selection
+	pub config_checked_wallet: Option<usize>,
 }
 
 impl Default for StateContainer {
@@ -31,6 +33,7 @@ impl Default for StateContainer {
 			setup_state: Default::default(),
 			operation_state: Default::default(),
 			has_config_check_failed_one_time: false,
+			config_checked_wallet: None,
 		}
 	}
 }
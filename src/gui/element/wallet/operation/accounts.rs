@@ -0,0 +1,440 @@
+use crate::log_error;
+use grin_gui_core::{
+	config::Config,
+	wallet::{AcctPathMapping, DEFAULT_ACCOUNT_LABEL},
+};
+
+use {
+	super::super::super::{
+		BUTTON_HEIGHT, BUTTON_WIDTH, DEFAULT_FONT_SIZE, DEFAULT_HEADER_FONT_SIZE, DEFAULT_PADDING,
+		SMALLER_FONT_SIZE,
+	},
+	crate::gui::{GrinGui, Interaction, Message},
+	crate::localization::localized_string,
+	crate::Result,
+	anyhow::Context,
+	grin_gui_core::theme::{
+		Button, Column, Container, Element, PickList, Row, Scrollable, Text, TextInput,
+	},
+	grin_gui_core::wallet::{WalletInfo, WalletInterface},
+	grin_gui_core::{node::amount_to_hr_string, theme::ContainerStyle},
+	iced::widget::Space,
+	iced::{alignment, Alignment, Command, Length},
+	std::sync::{Arc, RwLock},
+};
+
+pub struct StateContainer {
+	// Label of the account the wallet is currently operating on
+	pub active_account: String,
+	// Accounts along with their balances, as of last refresh
+	pub accounts: Vec<(AcctPathMapping, WalletInfo)>,
+	pub new_account_value: String,
+	// whether the new account label is invalid
+	new_account_error: bool,
+	// Whether we're waiting on balances to come back
+	is_loading: bool,
+}
+
+impl Default for StateContainer {
+	fn default() -> Self {
+		Self {
+			active_account: DEFAULT_ACCOUNT_LABEL.to_owned(),
+			accounts: vec![],
+			new_account_value: Default::default(),
+			new_account_error: false,
+			is_loading: false,
+		}
+	}
+}
+
+#[derive(Debug, Clone)]
+pub enum LocalViewInteraction {
+	Back,
+	Refresh,
+	NewAccountLabel(String),
+	CreateAccount,
+	SelectAccount(String),
+
+	AccountsRefreshOk(Vec<(AcctPathMapping, WalletInfo)>),
+	AccountCreatedOk(String),
+	AccountSelectedOk(String),
+	AccountsError(Arc<RwLock<Option<anyhow::Error>>>),
+}
+
+pub fn handle_message<'a>(
+	grin_gui: &mut GrinGui,
+	message: LocalViewInteraction,
+) -> Result<Command<Message>> {
+	let state = &mut grin_gui.wallet_state.operation_state.accounts_state;
+
+	match message {
+		LocalViewInteraction::Back => {
+			log::debug!("Interaction::WalletOperationAccountsViewInteraction(Back)");
+			state.new_account_value = Default::default();
+			state.new_account_error = false;
+			grin_gui.wallet_state.operation_state.mode =
+				crate::gui::element::wallet::operation::Mode::Home;
+		}
+		LocalViewInteraction::Refresh => {
+			grin_gui.error.take();
+			state.is_loading = true;
+
+			let w = grin_gui.wallet_interface.clone();
+			let node_synched = grin_gui
+				.wallet_state
+				.operation_state
+				.home_state
+				.node_synched;
+			let fut = grin_gui
+				.wallet_worker
+				.run(WalletInterface::get_account_balances(w, node_synched));
+
+			return Ok(Command::perform(fut, |r| {
+				match r.context("Failed to retrieve accounts") {
+					Ok(accounts) => {
+						Message::Interaction(Interaction::WalletOperationAccountsViewInteraction(
+							LocalViewInteraction::AccountsRefreshOk(accounts),
+						))
+					}
+					Err(e) => {
+						Message::Interaction(Interaction::WalletOperationAccountsViewInteraction(
+							LocalViewInteraction::AccountsError(Arc::new(RwLock::new(Some(e)))),
+						))
+					}
+				}
+			}));
+		}
+		LocalViewInteraction::NewAccountLabel(s) => {
+			state.new_account_value = s;
+			state.new_account_error = false;
+		}
+		LocalViewInteraction::CreateAccount => {
+			grin_gui.error.take();
+
+			let label = state.new_account_value.trim().to_owned();
+			if label.is_empty() || state.accounts.iter().any(|(a, _)| a.label == label) {
+				state.new_account_error = true;
+				return Ok(Command::none());
+			}
+
+			let w = grin_gui.wallet_interface.clone();
+			let fut = grin_gui
+				.wallet_worker
+				.run(WalletInterface::create_account(w, label));
+
+			return Ok(Command::perform(fut, |r| {
+				match r.context("Failed to create account") {
+					Ok(label) => {
+						Message::Interaction(Interaction::WalletOperationAccountsViewInteraction(
+							LocalViewInteraction::AccountCreatedOk(label),
+						))
+					}
+					Err(e) => {
+						Message::Interaction(Interaction::WalletOperationAccountsViewInteraction(
+							LocalViewInteraction::AccountsError(Arc::new(RwLock::new(Some(e)))),
+						))
+					}
+				}
+			}));
+		}
+		LocalViewInteraction::AccountCreatedOk(label) => {
+			log::debug!("Created account {}", label);
+			state.new_account_value = Default::default();
+			return Ok(refresh_command());
+		}
+		LocalViewInteraction::SelectAccount(label) => {
+			grin_gui.error.take();
+
+			let w = grin_gui.wallet_interface.clone();
+			let fut = grin_gui
+				.wallet_worker
+				.run(WalletInterface::set_active_account(w, label));
+
+			return Ok(Command::perform(fut, |r| {
+				match r.context("Failed to switch account") {
+					Ok(label) => {
+						Message::Interaction(Interaction::WalletOperationAccountsViewInteraction(
+							LocalViewInteraction::AccountSelectedOk(label),
+						))
+					}
+					Err(e) => {
+						Message::Interaction(Interaction::WalletOperationAccountsViewInteraction(
+							LocalViewInteraction::AccountsError(Arc::new(RwLock::new(Some(e)))),
+						))
+					}
+				}
+			}));
+		}
+		LocalViewInteraction::AccountSelectedOk(label) => {
+			state.active_account = label;
+
+			// Balances, address and transactions all belong to the newly active account
+			grin_gui
+				.wallet_state
+				.operation_state
+				.home_state
+				.reset_account_data();
+			grin_gui.wallet_state.operation_state.mode =
+				crate::gui::element::wallet::operation::Mode::Home;
+
+			let fut = move || async {};
+			return Ok(Command::perform(fut(), |_| {
+				Message::Interaction(Interaction::WalletOperationHomeTxListDisplayInteraction(
+					super::tx_list_display::LocalViewInteraction::SelectMode(
+						super::tx_list_display::Mode::Recent,
+					),
+				))
+			}));
+		}
+		LocalViewInteraction::AccountsRefreshOk(accounts) => {
+			state.is_loading = false;
+			state.accounts = accounts;
+		}
+		LocalViewInteraction::AccountsError(err) => {
+			state.is_loading = false;
+			grin_gui.error = err.write().unwrap().take();
+			if let Some(e) = grin_gui.error.as_ref() {
+				log_error(e);
+			}
+		}
+	}
+
+	Ok(Command::none())
+}
+
+/// Command that reloads the account list, used when entering the screen
+pub fn refresh_command() -> Command<Message> {
+	let fut = move || async {};
+	Command::perform(fut(), |_| {
+		Message::Interaction(Interaction::WalletOperationAccountsViewInteraction(
+			LocalViewInteraction::Refresh,
+		))
+	})
+}
+
+pub fn data_container<'a>(config: &'a Config, state: &'a StateContainer) -> Container<'a, Message> {
+	// Title row
+	let title = Text::new(localized_string("accounts-title"))
+		.size(DEFAULT_HEADER_FONT_SIZE)
+		.horizontal_alignment(alignment::Horizontal::Center);
+
+	let title_container = Container::new(title)
+		.style(grin_gui_core::theme::ContainerStyle::BrightBackground)
+		.padding(iced::Padding::from([
+			2, // top
+			0, // right
+			2, // bottom
+			5, // left
+		]));
+
+	let header_row = Row::new().push(title_container);
+
+	let header_container = Container::new(header_row).padding(iced::Padding::from([
+		0,                      // top
+		0,                      // right
+		DEFAULT_PADDING as u16, // bottom
+		0,                      // left
+	]));
+
+	let unit_spacing = 15.0;
+	let row_spacing = 5.0;
+
+	let button_height = Length::Fixed(BUTTON_HEIGHT);
+	let button_width = Length::Fixed(BUTTON_WIDTH);
+
+	let mut column = Column::new();
+
+	if state.is_loading && state.accounts.is_empty() {
+		let loading = Text::new(localized_string("accounts-loading"))
+			.size(DEFAULT_FONT_SIZE)
+			.horizontal_alignment(alignment::Horizontal::Left);
+		column = column
+			.push(Container::new(loading).style(ContainerStyle::NormalBackground))
+			.push(Space::new(Length::Fixed(0.0), Length::Fixed(unit_spacing)));
+	}
+
+	for (acct, info) in state.accounts.iter() {
+		let is_active = acct.label == state.active_account;
+
+		let label = Text::new(acct.label.clone())
+			.size(DEFAULT_FONT_SIZE)
+			.horizontal_alignment(alignment::Horizontal::Left);
+		let label_container = Container::new(label)
+			.width(Length::Fixed(150.0))
+			.style(ContainerStyle::NormalBackground);
+
+		let spendable = Text::new(format!(
+			"{}: {}",
+			localized_string("info-amount-spendable"),
+			amount_to_hr_string(info.amount_currently_spendable, false)
+		))
+		.size(DEFAULT_FONT_SIZE)
+		.horizontal_alignment(alignment::Horizontal::Left);
+		let spendable_container = Container::new(spendable)
+			.width(Length::Fixed(250.0))
+			.style(ContainerStyle::NormalBackground);
+
+		let total = Text::new(format!(
+			"{}: {}",
+			localized_string("info-confirmed-total"),
+			amount_to_hr_string(info.total, false)
+		))
+		.size(DEFAULT_FONT_SIZE)
+		.horizontal_alignment(alignment::Horizontal::Left);
+		let total_container = Container::new(total)
+			.width(Length::Fixed(250.0))
+			.style(ContainerStyle::NormalBackground);
+
+		let select_label = if is_active {
+			localized_string("accounts-active")
+		} else {
+			localized_string("accounts-switch")
+		};
+		let select_button_label_container =
+			Container::new(Text::new(select_label).size(SMALLER_FONT_SIZE))
+				.width(button_width)
+				.center_x()
+				.align_x(alignment::Horizontal::Center);
+
+		let mut select_button = Button::new(select_button_label_container)
+			.style(grin_gui_core::theme::ButtonStyle::Bordered)
+			.padding(2);
+		if !is_active {
+			select_button =
+				select_button.on_press(Interaction::WalletOperationAccountsViewInteraction(
+					LocalViewInteraction::SelectAccount(acct.label.clone()),
+				));
+		}
+		let select_button: Element<Interaction> = select_button.into();
+
+		let account_row = Row::new()
+			.push(label_container)
+			.push(spendable_container)
+			.push(total_container)
+			.push(select_button.map(Message::Interaction))
+			.align_items(Alignment::Center);
+
+		column = column
+			.push(account_row)
+			.push(Space::new(Length::Fixed(0.0), Length::Fixed(row_spacing)));
+	}
+
+	// New account
+	let new_account_label = Text::new(localized_string("accounts-new"))
+		.size(DEFAULT_FONT_SIZE)
+		.horizontal_alignment(alignment::Horizontal::Left);
+	let new_account_label_container =
+		Container::new(new_account_label).style(ContainerStyle::NormalBackground);
+
+	let new_account_input = TextInput::new("", &state.new_account_value)
+		.on_input(|s| {
+			Interaction::WalletOperationAccountsViewInteraction(
+				LocalViewInteraction::NewAccountLabel(s),
+			)
+		})
+		.on_submit(Interaction::WalletOperationAccountsViewInteraction(
+			LocalViewInteraction::CreateAccount,
+		))
+		.size(DEFAULT_FONT_SIZE)
+		.padding(6)
+		.width(Length::Fixed(200.0))
+		.style(grin_gui_core::theme::TextInputStyle::AddonsQuery);
+	let new_account_input: Element<Interaction> = new_account_input.into();
+
+	let create_button_label_container =
+		Container::new(Text::new(localized_string("accounts-create")).size(DEFAULT_FONT_SIZE))
+			.width(button_width)
+			.height(button_height)
+			.center_x()
+			.center_y()
+			.align_x(alignment::Horizontal::Center);
+
+	let create_button: Element<Interaction> = Button::new(create_button_label_container)
+		.style(grin_gui_core::theme::ButtonStyle::Primary)
+		.on_press(Interaction::WalletOperationAccountsViewInteraction(
+			LocalViewInteraction::CreateAccount,
+		))
+		.into();
+
+	let create_container = Container::new(create_button.map(Message::Interaction)).padding(1);
+	let create_container = Container::new(create_container)
+		.style(grin_gui_core::theme::ContainerStyle::Segmented)
+		.padding(1);
+
+	let new_account_row = Row::new()
+		.push(new_account_input.map(Message::Interaction))
+		.push(Space::new(Length::Fixed(unit_spacing), Length::Fixed(0.0)))
+		.push(create_container)
+		.align_items(Alignment::Center);
+
+	column = column
+		.push(Space::new(Length::Fixed(0.0), Length::Fixed(unit_spacing)))
+		.push(new_account_label_container)
+		.push(Space::new(Length::Fixed(0.0), Length::Fixed(row_spacing)))
+		.push(new_account_row)
+		.push(Space::new(Length::Fixed(0.0), Length::Fixed(unit_spacing)));
+
+	if state.new_account_error {
+		let new_account_error = Text::new(localized_string("accounts-new-error"))
+			.size(DEFAULT_FONT_SIZE)
+			.horizontal_alignment(alignment::Horizontal::Left)
+			.style(grin_gui_core::theme::text::TextStyle::Warning);
+		column = column
+			.push(Container::new(new_account_error).style(ContainerStyle::NormalBackground))
+			.push(Space::new(Length::Fixed(0.0), Length::Fixed(unit_spacing)));
+	}
+
+	let back_button_label_container =
+		Container::new(Text::new(localized_string("back")).size(DEFAULT_FONT_SIZE))
+			.width(button_width)
+			.height(button_height)
+			.center_x()
+			.center_y()
+			.align_x(alignment::Horizontal::Center);
+
+	let back_button: Element<Interaction> = Button::new(back_button_label_container)
+		.style(grin_gui_core::theme::ButtonStyle::Primary)
+		.on_press(Interaction::WalletOperationAccountsViewInteraction(
+			LocalViewInteraction::Back,
+		))
+		.into();
+
+	let back_container = Container::new(back_button.map(Message::Interaction)).padding(1);
+	let back_container = Container::new(back_container)
+		.style(grin_gui_core::theme::ContainerStyle::Segmented)
+		.padding(1);
+
+	column = column.push(Row::new().push(back_container));
+
+	let form_container = Container::new(column)
+		.width(Length::Fill)
+		.padding(iced::Padding::from([
+			0, // top
+			0, // right
+			0, // bottom
+			5, // left
+		]));
+
+	// form container should be scrollable in tiny windows
+	let scrollable = Scrollable::new(form_container)
+		.height(Length::Fill)
+		.style(grin_gui_core::theme::ScrollableStyle::Primary);
+
+	let content = Container::new(scrollable)
+		.width(Length::Fill)
+		.height(Length::Shrink)
+		.style(grin_gui_core::theme::ContainerStyle::NormalBackground);
+
+	let wrapper_column = Column::new()
+		.height(Length::Fill)
+		.push(header_container)
+		.push(content);
+
+	// Returns the final container.
+	Container::new(wrapper_column).padding(iced::Padding::from([
+		DEFAULT_PADDING, // top
+		DEFAULT_PADDING, // right
+		DEFAULT_PADDING, // bottom
+		DEFAULT_PADDING, // left
+	]))
+}
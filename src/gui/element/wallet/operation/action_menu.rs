@@ -2,7 +2,7 @@ use super::tx_list::{self, ExpandType};
 use crate::log_error;
 use async_std::prelude::FutureExt;
 use grin_gui_core::{
-	config::Config,
+	config::{Config, TxMethod},
 	wallet::{TxLogEntry, TxLogEntryType},
 };
 //use grin_gui_widgets::{header};
@@ -49,6 +49,7 @@ impl Default for StateContainer {
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum Action {
 	CreateTx,
+	CreateInvoice,
 	ApplyTx,
 }
 
@@ -74,9 +75,19 @@ pub fn handle_message<'a>(
 			);
 			match action {
 				Action::CreateTx => {
+					// Start from the user's saved defaults
+					grin_gui
+						.wallet_state
+						.operation_state
+						.create_tx_state
+						.advanced_options_state = (&grin_gui.config.tx_defaults).into();
 					grin_gui.wallet_state.operation_state.mode =
 						crate::gui::element::wallet::operation::Mode::CreateTx
 				}
+				Action::CreateInvoice => {
+					grin_gui.wallet_state.operation_state.mode =
+						crate::gui::element::wallet::operation::Mode::CreateInvoice
+				}
 				Action::ApplyTx => {
 					grin_gui.wallet_state.operation_state.mode =
 						crate::gui::element::wallet::operation::Mode::ApplyTx
@@ -116,7 +127,7 @@ pub fn data_container<'a>(
 		.width(button_width)
 		.style(grin_gui_core::theme::ButtonStyle::Primary);
 
-	if home_state.node_synched {
+	if home_state.node_synched && !home_state.scan_in_progress {
 		create_tx_button =
 			create_tx_button.on_press(Interaction::WalletOperationHomeActionMenuViewInteraction(
 				LocalViewInteraction::SelectAction(Action::CreateTx),
@@ -135,7 +146,7 @@ pub fn data_container<'a>(
 		.width(button_width)
 		.style(grin_gui_core::theme::ButtonStyle::Primary);
 
-	if home_state.node_synched {
+	if home_state.node_synched && !home_state.scan_in_progress {
 		apply_tx_button =
 			apply_tx_button.on_press(Interaction::WalletOperationHomeActionMenuViewInteraction(
 				LocalViewInteraction::SelectAction(Action::ApplyTx),
@@ -144,6 +155,27 @@ pub fn data_container<'a>(
 
 	let apply_tx_button: Element<Interaction> = apply_tx_button.into();
 
+	let create_invoice_container = Container::new(
+		Text::new(localized_string("wallet-create-invoice")).size(DEFAULT_FONT_SIZE),
+	)
+	.width(button_width)
+	.align_y(alignment::Vertical::Center)
+	.align_x(alignment::Horizontal::Center);
+
+	let mut create_invoice_button = Button::new(create_invoice_container)
+		.width(button_width)
+		.style(grin_gui_core::theme::ButtonStyle::Primary);
+
+	if home_state.node_synched && !home_state.scan_in_progress {
+		create_invoice_button = create_invoice_button.on_press(
+			Interaction::WalletOperationHomeActionMenuViewInteraction(
+				LocalViewInteraction::SelectAction(Action::CreateInvoice),
+			),
+		)
+	}
+
+	let create_invoice_button: Element<Interaction> = create_invoice_button.into();
+
 	// TODO refactor since many of the buttons around the UI repeat this theme
 	let create_container = Container::new(create_tx_button.map(Message::Interaction)).padding(1);
 	let create_container = Container::new(create_container)
@@ -155,10 +187,25 @@ pub fn data_container<'a>(
 		.style(grin_gui_core::theme::ContainerStyle::Segmented)
 		.padding(1);
 
-	let menu_column = Row::new()
+	let invoice_container =
+		Container::new(create_invoice_button.map(Message::Interaction)).padding(1);
+	let invoice_container = Container::new(invoice_container)
+		.style(grin_gui_core::theme::ContainerStyle::Segmented)
+		.padding(1);
+
+	let mut menu_column = Row::new()
 		.push(description_container)
 		.push(Space::with_width(Length::Fixed(DEFAULT_PADDING)))
-		.push(create_container)
+		.push(create_container);
+
+	// Contracts cover receiving through the create screen already
+	if config.tx_method == TxMethod::Legacy {
+		menu_column = menu_column
+			.push(Space::with_width(Length::Fixed(DEFAULT_PADDING)))
+			.push(invoice_container);
+	}
+
+	menu_column = menu_column
 		.push(Space::with_width(Length::Fixed(DEFAULT_PADDING)))
 		.push(apply_container);
 
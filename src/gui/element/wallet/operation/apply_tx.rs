@@ -3,11 +3,14 @@ use crate::log_error;
 use async_std::prelude::FutureExt;
 use grin_gui_core::{
 	config::Config,
+	error::{GrinWalletInterfaceError, QrError},
+	qr,
 	wallet::{Slate, Slatepack, TxLogEntry, TxLogEntryType},
 };
 use grin_gui_widgets::widget::header;
 use iced_aw::Card;
 use iced_core::Widget;
+use native_dialog::FileDialog;
 use std::path::PathBuf;
 
 use super::tx_list::{HeaderState, TxList};
@@ -67,6 +70,15 @@ impl StateContainer {
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum Action {}
 
+/// Where a slatepack being read came from
+#[derive(Debug, Clone)]
+pub enum SlatepackSource {
+	Clipboard,
+	File(PathBuf),
+	/// Archived once the slatepack has been applied
+	Inbox(PathBuf),
+}
+
 #[derive(Debug, Clone)]
 pub enum LocalViewInteraction {
 	Back,
@@ -76,6 +88,16 @@ pub enum LocalViewInteraction {
 	ApplyTransaction(String),
 	ReadFromClipboardSuccess(String),
 	ReadFromClipboardFailure,
+	LoadSlatepackFile,
+	LoadSlatepackFromPath(PathBuf),
+	LoadInboxSlatepack(PathBuf),
+	SlatepackReadSuccess(
+		SlatepackSource,
+		String,
+		(Slatepack, Slate, Option<TxLogEntry>),
+	),
+	SlatepackReadFailure(SlatepackSource, Arc<RwLock<Option<anyhow::Error>>>),
+	LoadQrImage,
 	ShowSlate,
 }
 
@@ -101,9 +123,11 @@ pub fn handle_message<'a>(
 					None => None,
 				};
 				let w = grin_gui.wallet_interface.clone();
-				let fut = move || WalletInterface::cancel_tx(w, tx_id.unwrap());
+				let fut = grin_gui
+					.wallet_worker
+					.run(WalletInterface::cancel_tx(w, tx_id.unwrap()));
 
-				return Ok(Command::perform(fut(), |_| {
+				return Ok(Command::perform(fut, |_| {
 					return Message::Interaction(
                             Interaction::WalletOperationApplyTxViewInteraction(
                                 crate::gui::element::wallet::operation::apply_tx::LocalViewInteraction::BackCleanup,
@@ -128,33 +152,78 @@ pub fn handle_message<'a>(
 			state.confirm_state.slatepack_parsed = None;
 			state.slatepack_read_data_full = Default::default();
 			state.confirm_state.is_self_send = false;
+			state.confirm_state.inbox_file = None;
 			state.can_continue = false;
 		}
 		LocalViewInteraction::ReadFromClipboardSuccess(value) => {
 			debug!("Read from clipboard: {}", value);
-			let w = grin_gui.wallet_interface.clone();
-			let decode_res = WalletInterface::decrypt_slatepack(w, value.clone());
-			match decode_res {
-				Err(e) => {
+			return Ok(read_slatepack(grin_gui, SlatepackSource::Clipboard, value));
+		}
+		LocalViewInteraction::LoadSlatepackFile => {
+			log::debug!("Interaction::WalletOperationApplyTxViewInteraction(LoadSlatepackFile)");
+			let mut dialog = FileDialog::new().add_filter("Slatepack", &["slatepack", "txt"]);
+			let slatepack_dir = grin_gui.config.get_wallet_slatepack_dir();
+			if let Some(dir) = slatepack_dir.as_ref() {
+				dialog = dialog.set_location(dir);
+			}
+			let path = match dialog.show_open_single_file() {
+				Ok(Some(p)) => p,
+				_ => return Ok(Command::none()),
+			};
+			return handle_message(grin_gui, LocalViewInteraction::LoadSlatepackFromPath(path));
+		}
+		LocalViewInteraction::LoadSlatepackFromPath(path) => {
+			debug!("Load slatepack from file: {:?}", path);
+			return load_slatepack_file(grin_gui, SlatepackSource::File(path));
+		}
+		LocalViewInteraction::LoadInboxSlatepack(path) => {
+			debug!("Load slatepack from inbox: {:?}", path);
+			return load_slatepack_file(grin_gui, SlatepackSource::Inbox(path));
+		}
+		LocalViewInteraction::SlatepackReadSuccess(source, value, s) => {
+			// Nothing to stage if the screen was left while decrypting
+			if grin_gui.wallet_state.operation_state.mode
+				!= crate::gui::element::wallet::operation::Mode::ApplyTx
+			{
+				return Ok(Command::none());
+			}
+			debug!("{}", s.1);
+			// Truncate a bit for compact display purposes
+			let mut s1 = value.clone();
+			s1.truncate(27);
+			let s2 = value
+				.clone()
+				.split_off(usize::saturating_sub(value.len(), 23));
+			let short_display = format!("{}...{}", s1, s2);
+
+			state.slatepack_read_data_full = value;
+			state.slatepack_read_data = short_display;
+			state.confirm_state.slatepack_parsed = Some(s);
+			state.confirm_state.inbox_file = match source {
+				SlatepackSource::Inbox(path) => Some(path),
+				_ => None,
+			};
+			state.can_continue = true;
+		}
+		LocalViewInteraction::SlatepackReadFailure(source, err) => {
+			let e = match err.write().unwrap().take() {
+				Some(e) => e,
+				None => return Ok(Command::none()),
+			};
+			match source {
+				SlatepackSource::Clipboard => {
+					log_error(&e);
 					state.slatepack_read_data = localized_string("tx-slatepack-read-failure");
-					state.confirm_state.slatepack_parsed = None;
-					state.slatepack_read_data_full = Default::default();
-					state.can_continue = false;
 				}
-				Ok(s) => {
-					debug!("{}", s.1);
-					// Truncate a bit for compact display purposes
-					let mut s1 = value.clone();
-					s1.truncate(27);
-					let s2 = value
-						.clone()
-						.split_off(usize::saturating_sub(value.len(), 23));
-					let short_display = format!("{}...{}", s1, s2);
-
-					state.slatepack_read_data_full = value.clone();
-					state.slatepack_read_data = short_display;
-					state.confirm_state.slatepack_parsed = Some(s);
-					state.can_continue = true;
+				SlatepackSource::File(path) | SlatepackSource::Inbox(path) => {
+					state.slatepack_read_data =
+						localized_string("tx-slatepack-read-result-default");
+					let file = path.display().to_string();
+					grin_gui.error =
+						Some(e.context(GrinWalletInterfaceError::InvalidSlatepackFile { file }));
+					if let Some(e) = grin_gui.error.as_ref() {
+						log_error(e);
+					}
 				}
 			}
 		}
@@ -173,6 +242,40 @@ pub fn handle_message<'a>(
 		LocalViewInteraction::ReadFromClipboardFailure => {
 			error!("Failed to read from clipboard");
 		}
+		LocalViewInteraction::LoadQrImage => {
+			log::debug!("Interaction::WalletOperationApplyTxViewInteraction(LoadQrImage)");
+			// A slatepack split over several QR frames may be saved as one image per frame
+			let paths = match FileDialog::new()
+				.add_filter("Image", &["png", "jpg", "jpeg"])
+				.show_open_multiple_file()
+			{
+				Ok(p) if !p.is_empty() => p,
+				_ => return Ok(Command::none()),
+			};
+
+			let decoded = paths
+				.iter()
+				.map(|p| qr::decode_image(p))
+				.collect::<std::result::Result<Vec<_>, QrError>>()
+				.and_then(|frames| qr::join_frames(frames.into_iter().flatten().collect()));
+
+			match decoded {
+				Ok(value) => {
+					return handle_message(
+						grin_gui,
+						LocalViewInteraction::ReadFromClipboardSuccess(value),
+					);
+				}
+				Err(e) => {
+					log::error!("Failed to read slatepack from QR image: {}", e);
+					state.slatepack_read_data =
+						format!("{} {}", localized_string("tx-qr-read-failure"), e);
+					state.confirm_state.slatepack_parsed = None;
+					state.slatepack_read_data_full = Default::default();
+					state.can_continue = false;
+				}
+			}
+		}
 		LocalViewInteraction::ShowSlate => {
 			// ensure back button on showing slate screen comes back here
 			grin_gui
@@ -184,7 +287,7 @@ pub fn handle_message<'a>(
 				.wallet_state
 				.operation_state
 				.show_slatepack_state
-				.encrypted_slate = Some(state.slatepack_read_data_full.clone());
+				.set_encrypted_slate(Some(state.slatepack_read_data_full.clone()));
 
 			grin_gui.wallet_state.operation_state.mode =
 				crate::gui::element::wallet::operation::Mode::ShowSlatepack;
@@ -195,6 +298,54 @@ pub fn handle_message<'a>(
 	Ok(Command::none())
 }
 
+fn load_slatepack_file(
+	grin_gui: &mut GrinGui,
+	source: SlatepackSource,
+) -> Result<Command<Message>> {
+	let path = match &source {
+		SlatepackSource::File(path) | SlatepackSource::Inbox(path) => path.clone(),
+		SlatepackSource::Clipboard => return Ok(Command::none()),
+	};
+	match std::fs::read_to_string(&path) {
+		Ok(value) => Ok(read_slatepack(grin_gui, source, value.trim().to_owned())),
+		Err(e) => handle_message(
+			grin_gui,
+			LocalViewInteraction::SlatepackReadFailure(
+				source,
+				Arc::new(RwLock::new(Some(e.into()))),
+			),
+		),
+	}
+}
+
+/// Decrypt a slatepack on the wallet worker, whatever was staged before is cleared until it's done
+fn read_slatepack(
+	grin_gui: &mut GrinGui,
+	source: SlatepackSource,
+	value: String,
+) -> Command<Message> {
+	let state = &mut grin_gui.wallet_state.operation_state.apply_tx_state;
+	state.confirm_state.inbox_file = None;
+	state.confirm_state.slatepack_parsed = None;
+	state.slatepack_read_data_full = Default::default();
+	state.can_continue = false;
+
+	let w = grin_gui.wallet_interface.clone();
+	let slatepack = value.clone();
+	let fut = grin_gui
+		.wallet_worker
+		.run(async move { WalletInterface::decrypt_slatepack(w, slatepack) });
+
+	Command::perform(fut, move |r| match r.context("Failed to read slatepack") {
+		Ok(s) => Message::Interaction(Interaction::WalletOperationApplyTxViewInteraction(
+			LocalViewInteraction::SlatepackReadSuccess(source, value, s),
+		)),
+		Err(e) => Message::Interaction(Interaction::WalletOperationApplyTxViewInteraction(
+			LocalViewInteraction::SlatepackReadFailure(source, Arc::new(RwLock::new(Some(e)))),
+		)),
+	})
+}
+
 pub fn data_container<'a>(config: &'a Config, state: &'a StateContainer) -> Container<'a, Message> {
 	let unit_spacing = 15.0;
 	let mut title_key = localized_string("apply-tx");
@@ -363,6 +514,36 @@ pub fn data_container<'a>(config: &'a Config, state: &'a StateContainer) -> Cont
 
 	let submit_button: Element<Interaction> = submit_button.into();
 
+	let load_file_button_label_container =
+		Container::new(Text::new(localized_string("tx-load-file")).size(DEFAULT_FONT_SIZE))
+			.width(button_width)
+			.height(button_height)
+			.center_x()
+			.center_y()
+			.align_x(alignment::Horizontal::Center);
+
+	let load_file_button: Element<Interaction> = Button::new(load_file_button_label_container)
+		.style(grin_gui_core::theme::ButtonStyle::Primary)
+		.on_press(Interaction::WalletOperationApplyTxViewInteraction(
+			LocalViewInteraction::LoadSlatepackFile,
+		))
+		.into();
+
+	let load_qr_button_label_container =
+		Container::new(Text::new(localized_string("tx-load-qr")).size(DEFAULT_FONT_SIZE))
+			.width(button_width)
+			.height(button_height)
+			.center_x()
+			.center_y()
+			.align_x(alignment::Horizontal::Center);
+
+	let load_qr_button: Element<Interaction> = Button::new(load_qr_button_label_container)
+		.style(grin_gui_core::theme::ButtonStyle::Primary)
+		.on_press(Interaction::WalletOperationApplyTxViewInteraction(
+			LocalViewInteraction::LoadQrImage,
+		))
+		.into();
+
 	let continue_button_label_container =
 		Container::new(Text::new(localized_string("tx-continue")).size(DEFAULT_FONT_SIZE))
 			.width(button_width)
@@ -398,6 +579,16 @@ pub fn data_container<'a>(config: &'a Config, state: &'a StateContainer) -> Cont
 		.style(grin_gui_core::theme::ContainerStyle::Segmented)
 		.padding(1);
 
+	let load_file_container = Container::new(load_file_button.map(Message::Interaction)).padding(1);
+	let load_file_container = Container::new(load_file_container)
+		.style(grin_gui_core::theme::ContainerStyle::Segmented)
+		.padding(1);
+
+	let load_qr_container = Container::new(load_qr_button.map(Message::Interaction)).padding(1);
+	let load_qr_container = Container::new(load_qr_container)
+		.style(grin_gui_core::theme::ContainerStyle::Segmented)
+		.padding(1);
+
 	let continue_container = Container::new(continue_button.map(Message::Interaction)).padding(1);
 	let continue_container = Container::new(continue_container)
 		.style(grin_gui_core::theme::ContainerStyle::Segmented)
@@ -412,6 +603,10 @@ pub fn data_container<'a>(config: &'a Config, state: &'a StateContainer) -> Cont
 	if !state.can_continue {
 		button_row = button_row
 			.push(submit_container)
+			.push(Space::new(Length::Fixed(unit_spacing), Length::Fixed(0.0)))
+			.push(load_file_container)
+			.push(Space::new(Length::Fixed(unit_spacing), Length::Fixed(0.0)))
+			.push(load_qr_container)
 			.push(Space::new(Length::Fixed(unit_spacing), Length::Fixed(0.0)));
 	} else {
 		button_row = button_row
@@ -4,8 +4,8 @@ use async_std::prelude::FutureExt;
 use grin_gui_core::{
 	config::{Config, TxMethod},
 	wallet::{
-		ContractNewArgsAPI, ContractSetupArgsAPI, ProofArgs, Slate, SlateState, Slatepack,
-		TxLogEntry, TxLogEntryType,
+		ContractNewArgsAPI, ContractSetupArgsAPI, InitTxArgs, ProofArgs, Slate, SlateState,
+		Slatepack, TxLogEntry, TxLogEntryType,
 	},
 };
 use grin_gui_widgets::widget::header;
@@ -30,7 +30,9 @@ use {
 		Button, Column, Container, Element, Header, PickList, Row, Scrollable, TableRow, Text,
 		TextInput,
 	},
-	grin_gui_core::wallet::{parse_abs_tx_amount_fee, StatusMessage, WalletInfo, WalletInterface},
+	grin_gui_core::wallet::{
+		inbox, parse_abs_tx_amount_fee, StatusMessage, WalletInfo, WalletInterface,
+	},
 	grin_gui_core::{node::amount_to_hr_string, theme::ColorPalette},
 	iced::widget::{button, pick_list, scrollable, text_input, Checkbox, Space},
 	iced::{alignment, Alignment, Command, Length},
@@ -51,6 +53,8 @@ pub struct StateContainer {
 	pub is_signing: bool,
 	// Is a self send
 	pub is_self_send: bool,
+	// Inbox file the slatepack was read from, archived once it's applied
+	pub inbox_file: Option<PathBuf>,
 }
 
 impl Default for StateContainer {
@@ -64,6 +68,7 @@ impl Default for StateContainer {
 			slatepack_parsed: None,
 			is_signing: false,
 			is_self_send: false,
+			inbox_file: None,
 		}
 	}
 }
@@ -117,11 +122,15 @@ pub fn handle_message<'a>(
 				match slate.state {
 					SlateState::Standard1 => {
 						state.is_signing = true;
-						let fut = move || {
-							WalletInterface::receive_tx_from_s1(w, out_slate, sp_sending_address)
-						};
-
-						return Ok(Command::perform(fut(), |r| {
+						let fut = grin_gui
+							.wallet_worker
+							.run(WalletInterface::receive_tx_from_s1(
+								w,
+								out_slate,
+								sp_sending_address,
+							));
+
+						return Ok(Command::perform(fut, |r| {
 							match r.context("Failed to Progress Transaction") {
 								Ok((slate, enc_slate)) => Message::Interaction(
 									Interaction::WalletOperationApplyTxConfirmViewInteraction(
@@ -142,9 +151,84 @@ pub fn handle_message<'a>(
 					}
 					SlateState::Standard2 => {
 						state.is_signing = true;
-						let fut = move || WalletInterface::finalize_from_s2(w, out_slate, true);
+						let fut = grin_gui
+							.wallet_worker
+							.run(WalletInterface::finalize_from_s2(w, out_slate, true));
+
+						return Ok(Command::perform(fut, |r| {
+							match r.context("Failed to Progress Transaction") {
+								Ok((slate, enc_slate)) => Message::Interaction(
+									Interaction::WalletOperationApplyTxConfirmViewInteraction(
+										LocalViewInteraction::TxAcceptSuccess(
+											slate, enc_slate, true,
+										),
+									),
+								),
+								Err(e) => Message::Interaction(
+									Interaction::WalletOperationApplyTxConfirmViewInteraction(
+										LocalViewInteraction::TxAcceptFailure(Arc::new(
+											RwLock::new(Some(e)),
+										)),
+									),
+								),
+							}
+						}));
+					}
+					SlateState::Invoice1 => {
+						state.is_signing = true;
+						// Inputs are chosen the same way as for a send
+						let tx_defaults = &grin_gui.config.tx_defaults;
+						let args = InitTxArgs {
+							src_acct_name: Some(
+								grin_gui
+									.wallet_state
+									.operation_state
+									.accounts_state
+									.active_account
+									.clone(),
+							),
+							amount: slate.amount,
+							minimum_confirmations: tx_defaults.minimum_confirmations,
+							max_outputs: 500,
+							num_change_outputs: tx_defaults.num_change_outputs,
+							selection_strategy_is_use_all: tx_defaults
+								.selection_strategy_is_use_all,
+							ttl_blocks: tx_defaults.ttl_blocks,
+							..Default::default()
+						};
+						let fut = grin_gui.wallet_worker.run(WalletInterface::pay_invoice_tx(
+							w,
+							out_slate,
+							args,
+							sp_sending_address,
+						));
+
+						return Ok(Command::perform(fut, |r| {
+							match r.context("Failed to Pay Invoice") {
+								Ok((slate, enc_slate)) => Message::Interaction(
+									Interaction::WalletOperationApplyTxConfirmViewInteraction(
+										LocalViewInteraction::TxAcceptSuccess(
+											slate, enc_slate, false,
+										),
+									),
+								),
+								Err(e) => Message::Interaction(
+									Interaction::WalletOperationApplyTxConfirmViewInteraction(
+										LocalViewInteraction::TxAcceptFailure(Arc::new(
+											RwLock::new(Some(e)),
+										)),
+									),
+								),
+							}
+						}));
+					}
+					SlateState::Invoice2 => {
+						state.is_signing = true;
+						let fut = grin_gui
+							.wallet_worker
+							.run(WalletInterface::finalize_from_i2(w, out_slate));
 
-						return Ok(Command::perform(fut(), |r| {
+						return Ok(Command::perform(fut, |r| {
 							match r.context("Failed to Progress Transaction") {
 								Ok((slate, enc_slate)) => Message::Interaction(
 									Interaction::WalletOperationApplyTxConfirmViewInteraction(
@@ -211,8 +295,10 @@ pub fn handle_message<'a>(
 
 				if state.is_self_send {
 					debug!("SLATE STATE SELF_SEND: {}", slate.state);
-					let fut = move || WalletInterface::post_tx(w, out_slate);
-					return Ok(Command::perform(fut(), |r| {
+					let fut = grin_gui
+						.wallet_worker
+						.run(WalletInterface::post_tx(w, out_slate));
+					return Ok(Command::perform(fut, |r| {
 						match r.context("Failed to Progress Transaction") {
 							Ok((slate, enc_slate)) => {
 								let finished = slate.state == SlateState::Standard3
@@ -235,12 +321,16 @@ pub fn handle_message<'a>(
 						}
 					}));
 				} else {
-					let fut = move || {
-						debug!("SIGN ARGS: {:?}", args);
-						WalletInterface::contract_sign(w, out_slate, args, sp_sending_address, true)
-					};
-
-					return Ok(Command::perform(fut(), |r| {
+					debug!("SIGN ARGS: {:?}", args);
+					let fut = grin_gui.wallet_worker.run(WalletInterface::contract_sign(
+						w,
+						out_slate,
+						args,
+						sp_sending_address,
+						true,
+					));
+
+					return Ok(Command::perform(fut, |r| {
 						match r.context("Failed to Progress Transaction") {
 							Ok((slate, enc_slate)) => {
 								debug!("SLATE STATE: {}", slate.state);
@@ -285,6 +375,18 @@ pub fn handle_message<'a>(
 
 			state.is_signing = false;
 
+			if let Some(path) = state.inbox_file.take() {
+				grin_gui
+					.wallet_state
+					.operation_state
+					.home_state
+					.inbox
+					.retain(|s| s.path != path);
+				if let Err(e) = inbox::archive_file(&path) {
+					log::error!("Failed to archive inbox slatepack {:?}: {}", path, e);
+				}
+			}
+
 			if finished {
 				grin_gui.wallet_state.operation_state.mode =
 					crate::gui::element::wallet::operation::Mode::TxDone;
@@ -293,7 +395,7 @@ pub fn handle_message<'a>(
 					.wallet_state
 					.operation_state
 					.show_slatepack_state
-					.encrypted_slate = encrypted_slate;
+					.set_encrypted_slate(encrypted_slate);
 
 				grin_gui
 					.wallet_state
@@ -372,6 +474,22 @@ pub fn data_container<'a>(config: &'a Config, state: &'a StateContainer) -> Cont
 			amt_stmt
 		}
 		SlateState::Standard3 => "This transaction is finalised - Standard workflow".to_owned(),
+		SlateState::Invoice1 => {
+			other_wallet_label = localized_string("tx-invoicer-name");
+			reception_instruction_1 = localized_string("tx-invoice-pay-instruction");
+			reception_instruction_2 = localized_string("tx-invoice-pay-instruction-2");
+			parse_info_strings(&localized_string("tx-invoice-pay"), &amount)
+		}
+		SlateState::Invoice2 => {
+			let mut fee = String::default();
+			other_wallet_label = localized_string("tx-sender-name");
+			if let Some(tx) = tx_log_entry {
+				(amount, fee) = parse_abs_tx_amount_fee(tx, false);
+			}
+			reception_instruction_1 = "".to_owned();
+			reception_instruction_2 = localized_string("tx-s1-finalization-3");
+			parse_info_strings(&localized_string("tx-i1-finalization-1"), &amount)
+		}
 		_ => "Support still in development".to_owned(),
 	};
 
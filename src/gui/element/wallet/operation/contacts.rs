@@ -0,0 +1,514 @@
+use super::outputs;
+use crate::log_error;
+use chrono::Utc;
+use grin_gui_core::{
+	config::Config,
+	wallet::contacts::{AddressBook, Contact},
+};
+
+use {
+	super::super::super::{
+		BUTTON_HEIGHT, BUTTON_WIDTH, DEFAULT_FONT_SIZE, DEFAULT_HEADER_FONT_SIZE, DEFAULT_PADDING,
+		SMALLER_FONT_SIZE,
+	},
+	crate::gui::{GrinGui, Interaction, Message},
+	crate::localization::localized_string,
+	crate::Result,
+	anyhow::Context,
+	grin_gui_core::theme::ContainerStyle,
+	grin_gui_core::theme::{
+		Button, Column, Container, Element, PickList, Row, Scrollable, Text, TextInput,
+	},
+	iced::widget::Space,
+	iced::{alignment, Alignment, Command, Length},
+};
+
+pub struct StateContainer {
+	pub name_value: String,
+	pub address_value: String,
+	pub notes_value: String,
+	// Address of the contact being edited, the form adds a new one if None
+	editing: Option<String>,
+}
+
+impl Default for StateContainer {
+	fn default() -> Self {
+		Self {
+			name_value: Default::default(),
+			address_value: Default::default(),
+			notes_value: Default::default(),
+			editing: None,
+		}
+	}
+}
+
+impl StateContainer {
+	pub fn clear_form(&mut self) {
+		self.name_value = Default::default();
+		self.address_value = Default::default();
+		self.notes_value = Default::default();
+		self.editing = None;
+	}
+}
+
+#[derive(Debug, Clone)]
+pub enum LocalViewInteraction {
+	Back,
+	Name(String),
+	Address(String),
+	Notes(String),
+	Edit(String),
+	CancelEdit,
+	Save,
+	Delete(String),
+}
+
+pub fn handle_message<'a>(
+	grin_gui: &mut GrinGui,
+	message: LocalViewInteraction,
+) -> Result<Command<Message>> {
+	let state = &mut grin_gui.wallet_state.operation_state.contacts_state;
+
+	match message {
+		LocalViewInteraction::Back => {
+			log::debug!("Interaction::WalletOperationContactsViewInteraction(Back)");
+			state.clear_form();
+			grin_gui.wallet_state.operation_state.mode =
+				crate::gui::element::wallet::operation::Mode::Home;
+		}
+		LocalViewInteraction::Name(s) => state.name_value = s,
+		LocalViewInteraction::Address(s) => state.address_value = s,
+		LocalViewInteraction::Notes(s) => state.notes_value = s,
+		LocalViewInteraction::Edit(address) => {
+			let operation_state = &mut grin_gui.wallet_state.operation_state;
+			if let Some(contact) = operation_state.address_book.find(&address) {
+				let state = &mut operation_state.contacts_state;
+				state.name_value = contact.name.clone();
+				state.address_value = contact.address.clone();
+				state.notes_value = contact.notes.clone();
+				state.editing = Some(contact.address.clone());
+			}
+		}
+		LocalViewInteraction::CancelEdit => state.clear_form(),
+		LocalViewInteraction::Save => {
+			grin_gui.error.take();
+			let editing = state.editing.clone();
+			let name = state.name_value.clone();
+			let address = state.address_value.clone();
+			let notes = state.notes_value.clone();
+
+			match save_contact(grin_gui, editing.as_deref(), &name, &address, &notes) {
+				Ok(()) => grin_gui
+					.wallet_state
+					.operation_state
+					.contacts_state
+					.clear_form(),
+				Err(e) => {
+					log_error(&e);
+					grin_gui.error = Some(e);
+				}
+			}
+		}
+		LocalViewInteraction::Delete(address) => {
+			grin_gui.error.take();
+			let operation_state = &mut grin_gui.wallet_state.operation_state;
+			if operation_state.address_book.remove(&address) {
+				if operation_state.contacts_state.editing.as_deref() == Some(address.as_str()) {
+					operation_state.contacts_state.clear_form();
+				}
+				if let Err(e) = save_address_book(grin_gui) {
+					log_error(&e);
+					grin_gui.error = Some(e);
+				}
+			}
+		}
+	}
+
+	Ok(Command::none())
+}
+
+/// Address book of the current wallet, empty if none was saved or it can't be read
+pub fn load_address_book(config: &Config) -> AddressBook {
+	match outputs::wallet_dir(config) {
+		Some(dir) => AddressBook::load(&dir).unwrap_or_else(|e| {
+			log::error!("Unable to load contacts: {}", e);
+			AddressBook::default()
+		}),
+		None => AddressBook::default(),
+	}
+}
+
+fn save_address_book(grin_gui: &GrinGui) -> Result<()> {
+	if let Some(dir) = outputs::wallet_dir(&grin_gui.config) {
+		grin_gui
+			.wallet_state
+			.operation_state
+			.address_book
+			.save(&dir)
+			.context("Failed to save contacts")?;
+	}
+	Ok(())
+}
+
+/// Add a contact, or update the one saved under `previous_address`, and write the address book
+pub fn save_contact(
+	grin_gui: &mut GrinGui,
+	previous_address: Option<&str>,
+	name: &str,
+	address: &str,
+	notes: &str,
+) -> Result<()> {
+	grin_gui
+		.wallet_state
+		.operation_state
+		.address_book
+		.upsert(previous_address, name, address, notes)
+		.context("Invalid contact")?;
+	save_address_book(grin_gui)
+}
+
+/// Note a transaction created for `address`, offering to save the address if it's not a
+/// contact yet
+pub fn record_recipient(grin_gui: &mut GrinGui, address: &str) {
+	let address = address.trim();
+	let operation_state = &mut grin_gui.wallet_state.operation_state;
+	if address.is_empty() || operation_state.home_state.address_value.as_deref() == Some(address) {
+		return;
+	}
+
+	if operation_state.address_book.mark_used(address, Utc::now()) {
+		if let Err(e) = save_address_book(grin_gui) {
+			log_error(&e);
+		}
+	} else {
+		operation_state
+			.show_slatepack_state
+			.offer_contact(address.to_owned());
+	}
+}
+
+/// Picker filling in a recipient address from the address book, if it has any contacts
+pub fn contact_picker<'a, F>(
+	address_book: &AddressBook,
+	address_value: &str,
+	on_selected: F,
+) -> Option<Element<'a, Interaction>>
+where
+	F: Fn(String) -> Interaction + 'a,
+{
+	let contacts = address_book.contacts();
+	if contacts.is_empty() {
+		return None;
+	}
+	let selected = address_book.find(address_value).cloned();
+
+	let picker = PickList::new(contacts, selected, move |c: Contact| on_selected(c.address))
+		.placeholder(localized_string("contacts-pick"))
+		.text_size(DEFAULT_FONT_SIZE)
+		.width(Length::Fixed(150.0))
+		.style(grin_gui_core::theme::PickListStyle::Primary);
+
+	Some(picker.into())
+}
+
+/// Start and end of an address, enough to tell contacts apart in a list
+pub fn short_address(address: &str) -> String {
+	if address.len() <= 20 || !address.is_ascii() {
+		return address.to_owned();
+	}
+	format!("{}...{}", &address[..10], &address[address.len() - 6..])
+}
+
+pub fn data_container<'a>(
+	_config: &'a Config,
+	state: &'a StateContainer,
+	address_book: &'a AddressBook,
+) -> Container<'a, Message> {
+	// Title row
+	let title = Text::new(localized_string("contacts-title"))
+		.size(DEFAULT_HEADER_FONT_SIZE)
+		.horizontal_alignment(alignment::Horizontal::Center);
+
+	let title_container = Container::new(title)
+		.style(grin_gui_core::theme::ContainerStyle::BrightBackground)
+		.padding(iced::Padding::from([
+			2, // top
+			0, // right
+			2, // bottom
+			5, // left
+		]));
+
+	let header_row = Row::new().push(title_container);
+
+	let header_container = Container::new(header_row).padding(iced::Padding::from([
+		0,                      // top
+		0,                      // right
+		DEFAULT_PADDING as u16, // bottom
+		0,                      // left
+	]));
+
+	let unit_spacing = 15.0;
+	let row_spacing = 5.0;
+
+	let button_height = Length::Fixed(BUTTON_HEIGHT);
+	let button_width = Length::Fixed(BUTTON_WIDTH);
+
+	let mut column = Column::new();
+
+	let contacts = address_book.contacts();
+	if contacts.is_empty() {
+		let empty = Text::new(localized_string("contacts-empty"))
+			.size(DEFAULT_FONT_SIZE)
+			.horizontal_alignment(alignment::Horizontal::Left);
+		column = column
+			.push(Container::new(empty).style(ContainerStyle::NormalBackground))
+			.push(Space::new(Length::Fixed(0.0), Length::Fixed(unit_spacing)));
+	}
+
+	for contact in contacts {
+		let name = Text::new(contact.name.clone())
+			.size(DEFAULT_FONT_SIZE)
+			.horizontal_alignment(alignment::Horizontal::Left);
+		let name_container = Container::new(name)
+			.width(Length::Fixed(150.0))
+			.style(ContainerStyle::NormalBackground);
+
+		let address = Text::new(short_address(&contact.address))
+			.size(DEFAULT_FONT_SIZE)
+			.horizontal_alignment(alignment::Horizontal::Left);
+		let address_container = Container::new(address)
+			.width(Length::Fixed(200.0))
+			.style(ContainerStyle::NormalBackground);
+
+		let notes = Text::new(contact.notes.clone())
+			.size(DEFAULT_FONT_SIZE)
+			.horizontal_alignment(alignment::Horizontal::Left);
+		let notes_container = Container::new(notes)
+			.width(Length::Fixed(200.0))
+			.style(ContainerStyle::NormalBackground);
+
+		let last_used = match contact.last_used {
+			Some(t) => t.format("%Y-%m-%d").to_string(),
+			None => localized_string("contacts-never-used"),
+		};
+		let last_used = Text::new(format!(
+			"{}: {}",
+			localized_string("contacts-last-used"),
+			last_used
+		))
+		.size(DEFAULT_FONT_SIZE)
+		.horizontal_alignment(alignment::Horizontal::Left);
+		let last_used_container = Container::new(last_used)
+			.width(Length::Fixed(180.0))
+			.style(ContainerStyle::NormalBackground);
+
+		let edit_button: Element<Interaction> =
+			Button::new(Text::new(localized_string("contacts-edit")).size(SMALLER_FONT_SIZE))
+				.style(grin_gui_core::theme::ButtonStyle::Bordered)
+				.on_press(Interaction::WalletOperationContactsViewInteraction(
+					LocalViewInteraction::Edit(contact.address.clone()),
+				))
+				.padding(2)
+				.into();
+
+		let delete_button: Element<Interaction> =
+			Button::new(Text::new(localized_string("contacts-delete")).size(SMALLER_FONT_SIZE))
+				.style(grin_gui_core::theme::ButtonStyle::Bordered)
+				.on_press(Interaction::WalletOperationContactsViewInteraction(
+					LocalViewInteraction::Delete(contact.address.clone()),
+				))
+				.padding(2)
+				.into();
+
+		let contact_row = Row::new()
+			.push(name_container)
+			.push(address_container)
+			.push(notes_container)
+			.push(last_used_container)
+			.push(edit_button.map(Message::Interaction))
+			.push(Space::with_width(Length::Fixed(2.0)))
+			.push(delete_button.map(Message::Interaction))
+			.align_items(Alignment::Center);
+
+		column = column
+			.push(contact_row)
+			.push(Space::new(Length::Fixed(0.0), Length::Fixed(row_spacing)));
+	}
+
+	// Add or edit form
+	let form_title = if state.editing.is_some() {
+		localized_string("contacts-edit-title")
+	} else {
+		localized_string("contacts-new")
+	};
+	let form_title = Text::new(form_title)
+		.size(DEFAULT_FONT_SIZE)
+		.horizontal_alignment(alignment::Horizontal::Left);
+	let form_title_container = Container::new(form_title).style(ContainerStyle::NormalBackground);
+
+	column = column
+		.push(Space::new(Length::Fixed(0.0), Length::Fixed(unit_spacing)))
+		.push(form_title_container)
+		.push(Space::new(Length::Fixed(0.0), Length::Fixed(row_spacing)));
+
+	let fields: [(&str, &String, fn(String) -> LocalViewInteraction, f32); 3] = [
+		(
+			"contacts-name",
+			&state.name_value,
+			LocalViewInteraction::Name,
+			200.0,
+		),
+		(
+			"contacts-address",
+			&state.address_value,
+			LocalViewInteraction::Address,
+			400.0,
+		),
+		(
+			"contacts-notes",
+			&state.notes_value,
+			LocalViewInteraction::Notes,
+			400.0,
+		),
+	];
+
+	for (label, value, on_input, width) in fields {
+		let label = Text::new(localized_string(label))
+			.size(DEFAULT_FONT_SIZE)
+			.horizontal_alignment(alignment::Horizontal::Left);
+		let label_container = Container::new(label)
+			.width(Length::Fixed(150.0))
+			.style(ContainerStyle::NormalBackground);
+
+		let input = TextInput::new("", value)
+			.on_input(move |s| Interaction::WalletOperationContactsViewInteraction(on_input(s)))
+			.on_submit(Interaction::WalletOperationContactsViewInteraction(
+				LocalViewInteraction::Save,
+			))
+			.size(DEFAULT_FONT_SIZE)
+			.padding(6)
+			.width(Length::Fixed(width))
+			.style(grin_gui_core::theme::TextInputStyle::AddonsQuery);
+		let input: Element<Interaction> = input.into();
+
+		column = column
+			.push(
+				Row::new()
+					.push(label_container)
+					.push(input.map(Message::Interaction))
+					.align_items(Alignment::Center),
+			)
+			.push(Space::new(Length::Fixed(0.0), Length::Fixed(row_spacing)));
+	}
+
+	let save_label = if state.editing.is_some() {
+		localized_string("contacts-update")
+	} else {
+		localized_string("contacts-add")
+	};
+	let save_button_label_container = Container::new(Text::new(save_label).size(DEFAULT_FONT_SIZE))
+		.width(button_width)
+		.height(button_height)
+		.center_x()
+		.center_y()
+		.align_x(alignment::Horizontal::Center);
+
+	let save_button: Element<Interaction> = Button::new(save_button_label_container)
+		.style(grin_gui_core::theme::ButtonStyle::Primary)
+		.on_press(Interaction::WalletOperationContactsViewInteraction(
+			LocalViewInteraction::Save,
+		))
+		.into();
+
+	let save_container = Container::new(save_button.map(Message::Interaction)).padding(1);
+	let save_container = Container::new(save_container)
+		.style(grin_gui_core::theme::ContainerStyle::Segmented)
+		.padding(1);
+
+	let mut form_button_row = Row::new().push(save_container);
+
+	if state.editing.is_some() {
+		let cancel_button_label_container =
+			Container::new(Text::new(localized_string("cancel")).size(DEFAULT_FONT_SIZE))
+				.width(button_width)
+				.height(button_height)
+				.center_x()
+				.center_y()
+				.align_x(alignment::Horizontal::Center);
+
+		let cancel_button: Element<Interaction> = Button::new(cancel_button_label_container)
+			.style(grin_gui_core::theme::ButtonStyle::Primary)
+			.on_press(Interaction::WalletOperationContactsViewInteraction(
+				LocalViewInteraction::CancelEdit,
+			))
+			.into();
+
+		let cancel_container = Container::new(cancel_button.map(Message::Interaction)).padding(1);
+		let cancel_container = Container::new(cancel_container)
+			.style(grin_gui_core::theme::ContainerStyle::Segmented)
+			.padding(1);
+
+		form_button_row = form_button_row
+			.push(Space::new(Length::Fixed(unit_spacing), Length::Fixed(0.0)))
+			.push(cancel_container);
+	}
+
+	column = column
+		.push(Space::new(Length::Fixed(0.0), Length::Fixed(row_spacing)))
+		.push(form_button_row)
+		.push(Space::new(Length::Fixed(0.0), Length::Fixed(unit_spacing)));
+
+	let back_button_label_container =
+		Container::new(Text::new(localized_string("back")).size(DEFAULT_FONT_SIZE))
+			.width(button_width)
+			.height(button_height)
+			.center_x()
+			.center_y()
+			.align_x(alignment::Horizontal::Center);
+
+	let back_button: Element<Interaction> = Button::new(back_button_label_container)
+		.style(grin_gui_core::theme::ButtonStyle::Primary)
+		.on_press(Interaction::WalletOperationContactsViewInteraction(
+			LocalViewInteraction::Back,
+		))
+		.into();
+
+	let back_container = Container::new(back_button.map(Message::Interaction)).padding(1);
+	let back_container = Container::new(back_container)
+		.style(grin_gui_core::theme::ContainerStyle::Segmented)
+		.padding(1);
+
+	column = column.push(Row::new().push(back_container));
+
+	let form_container = Container::new(column)
+		.width(Length::Fill)
+		.padding(iced::Padding::from([
+			0, // top
+			0, // right
+			0, // bottom
+			5, // left
+		]));
+
+	// form container should be scrollable in tiny windows
+	let scrollable = Scrollable::new(form_container)
+		.height(Length::Fill)
+		.style(grin_gui_core::theme::ScrollableStyle::Primary);
+
+	let content = Container::new(scrollable)
+		.width(Length::Fill)
+		.height(Length::Shrink)
+		.style(grin_gui_core::theme::ContainerStyle::NormalBackground);
+
+	let wrapper_column = Column::new()
+		.height(Length::Fill)
+		.push(header_container)
+		.push(content);
+
+	// Returns the final container.
+	Container::new(wrapper_column).padding(iced::Padding::from([
+		DEFAULT_PADDING, // top
+		DEFAULT_PADDING, // right
+		DEFAULT_PADDING, // bottom
+		DEFAULT_PADDING, // left
+	]))
+}
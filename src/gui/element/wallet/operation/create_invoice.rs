@@ -0,0 +1,376 @@
+use crate::log_error;
+use grin_gui_core::{
+	config::Config,
+	error::GrinWalletInterfaceError,
+	wallet::{IssueInvoiceTxArgs, Slate},
+};
+use std::fs::File;
+use std::io::Write;
+
+use {
+	super::super::super::{
+		BUTTON_HEIGHT, BUTTON_WIDTH, DEFAULT_FONT_SIZE, DEFAULT_HEADER_FONT_SIZE, DEFAULT_PADDING,
+		SMALLER_FONT_SIZE,
+	},
+	crate::gui::{GrinGui, Interaction, Message},
+	crate::localization::localized_string,
+	crate::Result,
+	grin_gui_core::node::amount_from_hr_string,
+	grin_gui_core::theme::{Button, Column, Container, Element, Row, Scrollable, Text, TextInput},
+	grin_gui_core::wallet::WalletInterface,
+	iced::widget::Space,
+	iced::{alignment, Command, Length},
+	std::sync::{Arc, RwLock},
+};
+
+pub struct StateContainer {
+	pub payer_address_value: String,
+	pub amount_value: String,
+	// whether amount has errored
+	amount_error: bool,
+	// slatepack address error
+	slatepack_address_error: bool,
+}
+
+impl Default for StateContainer {
+	fn default() -> Self {
+		Self {
+			payer_address_value: Default::default(),
+			amount_value: Default::default(),
+			amount_error: false,
+			slatepack_address_error: false,
+		}
+	}
+}
+
+#[derive(Debug, Clone)]
+pub enum LocalViewInteraction {
+	Back,
+	PayerAddress(String),
+	Amount(String),
+	CreateInvoice,
+
+	InvoiceCreatedOk(Slate, String),
+	InvoiceCreateError(Arc<RwLock<Option<anyhow::Error>>>),
+	SlatepackAddressError,
+}
+
+pub fn handle_message<'a>(
+	grin_gui: &mut GrinGui,
+	message: LocalViewInteraction,
+) -> Result<Command<Message>> {
+	let state = &mut grin_gui.wallet_state.operation_state.create_invoice_state;
+
+	match message {
+		LocalViewInteraction::Back => {
+			log::debug!("Interaction::WalletOperationCreateInvoiceViewInteraction(Back)");
+			grin_gui.wallet_state.operation_state.mode =
+				crate::gui::element::wallet::operation::Mode::Home;
+		}
+		LocalViewInteraction::PayerAddress(s) => {
+			state.payer_address_value = s;
+		}
+		LocalViewInteraction::Amount(s) => {
+			state.amount_value = s;
+		}
+		LocalViewInteraction::CreateInvoice => {
+			grin_gui.error.take();
+			log::debug!("Interaction::WalletOperationCreateInvoiceViewInteraction");
+
+			state.amount_error = false;
+			state.slatepack_address_error = false;
+
+			let amount = match amount_from_hr_string(&state.amount_value) {
+				Ok(0) | Err(_) => {
+					state.amount_error = true;
+					return Ok(Command::none());
+				}
+				Ok(a) => a,
+			};
+
+			let account = grin_gui
+				.wallet_state
+				.operation_state
+				.accounts_state
+				.active_account
+				.clone();
+			let args = IssueInvoiceTxArgs {
+				dest_acct_name: Some(account),
+				amount,
+				..Default::default()
+			};
+
+			let w = grin_gui.wallet_interface.clone();
+			let fut = grin_gui
+				.wallet_worker
+				.run(WalletInterface::issue_invoice_tx(
+					w,
+					args,
+					state.payer_address_value.clone(),
+				));
+
+			return Ok(Command::perform(fut, |r| match r {
+				Ok((unenc_slate, enc_slate)) => {
+					Message::Interaction(Interaction::WalletOperationCreateInvoiceViewInteraction(
+						LocalViewInteraction::InvoiceCreatedOk(unenc_slate, enc_slate),
+					))
+				}
+				Err(e) => match e {
+					GrinWalletInterfaceError::InvalidSlatepackAddress => Message::Interaction(
+						Interaction::WalletOperationCreateInvoiceViewInteraction(
+							LocalViewInteraction::SlatepackAddressError,
+						),
+					),
+					_ => Message::Interaction(
+						Interaction::WalletOperationCreateInvoiceViewInteraction(
+							LocalViewInteraction::InvoiceCreateError(Arc::new(RwLock::new(Some(
+								anyhow::Error::from(e),
+							)))),
+						),
+					),
+				},
+			}));
+		}
+		LocalViewInteraction::InvoiceCreatedOk(unencrypted_slate, encrypted_slate) => {
+			grin_gui
+				.wallet_state
+				.operation_state
+				.show_slatepack_state
+				.set_encrypted_slate(Some(encrypted_slate.to_string()));
+
+			grin_gui
+				.wallet_state
+				.operation_state
+				.show_slatepack_state
+				.title_label = localized_string("tx-invoice-create-success-title");
+
+			grin_gui
+				.wallet_state
+				.operation_state
+				.show_slatepack_state
+				.desc = localized_string("tx-invoice-create-success-desc");
+
+			if let Some(dir) = grin_gui.config.get_wallet_slatepack_dir() {
+				let out_file_name = format!("{}/{}.slatepack", dir, unencrypted_slate.id);
+				let mut output = File::create(out_file_name.clone())?;
+				output.write_all(&encrypted_slate.as_bytes())?;
+				output.sync_all()?;
+			}
+
+			grin_gui.wallet_state.operation_state.mode =
+				crate::gui::element::wallet::operation::Mode::ShowSlatepack;
+		}
+		LocalViewInteraction::InvoiceCreateError(err) => {
+			grin_gui.error = err.write().unwrap().take();
+			if let Some(e) = grin_gui.error.as_ref() {
+				log_error(e);
+			}
+		}
+		LocalViewInteraction::SlatepackAddressError => state.slatepack_address_error = true,
+	}
+
+	Ok(Command::none())
+}
+
+pub fn data_container<'a>(config: &'a Config, state: &'a StateContainer) -> Container<'a, Message> {
+	// Title row
+	let title = Text::new(localized_string("create-invoice"))
+		.size(DEFAULT_HEADER_FONT_SIZE)
+		.horizontal_alignment(alignment::Horizontal::Center);
+
+	let title_container = Container::new(title)
+		.style(grin_gui_core::theme::ContainerStyle::BrightBackground)
+		.padding(iced::Padding::from([
+			2, // top
+			0, // right
+			2, // bottom
+			5, // left
+		]));
+
+	let header_row = Row::new().push(title_container);
+
+	let header_container = Container::new(header_row).padding(iced::Padding::from([
+		0,                      // top
+		0,                      // right
+		DEFAULT_PADDING as u16, // bottom
+		0,                      // left
+	]));
+
+	let payer_address = Text::new(localized_string("payer-address"))
+		.size(DEFAULT_FONT_SIZE)
+		.horizontal_alignment(alignment::Horizontal::Left);
+
+	let payer_address_container =
+		Container::new(payer_address).style(grin_gui_core::theme::ContainerStyle::NormalBackground);
+
+	let payer_address_instruction = Text::new(localized_string("payer-address-instruction"))
+		.size(SMALLER_FONT_SIZE)
+		.horizontal_alignment(alignment::Horizontal::Left);
+
+	let payer_address_instruction_container = Container::new(payer_address_instruction)
+		.style(grin_gui_core::theme::ContainerStyle::NormalBackground);
+
+	let payer_address_input = TextInput::new("", &state.payer_address_value)
+		.on_input(|s| {
+			Interaction::WalletOperationCreateInvoiceViewInteraction(
+				LocalViewInteraction::PayerAddress(s),
+			)
+		})
+		.size(DEFAULT_FONT_SIZE)
+		.padding(6)
+		.width(Length::Fixed(400.0))
+		.style(grin_gui_core::theme::TextInputStyle::AddonsQuery);
+
+	let payer_address_input: Element<Interaction> = payer_address_input.into();
+
+	let address_error = Text::new(localized_string("create-tx-address-error"))
+		.size(DEFAULT_FONT_SIZE)
+		.horizontal_alignment(alignment::Horizontal::Left)
+		.style(grin_gui_core::theme::text::TextStyle::Warning);
+
+	let address_error_container =
+		Container::new(address_error).style(grin_gui_core::theme::ContainerStyle::NormalBackground);
+
+	let amount = Text::new(localized_string("create-tx-amount"))
+		.size(DEFAULT_FONT_SIZE)
+		.horizontal_alignment(alignment::Horizontal::Left);
+
+	let amount_container =
+		Container::new(amount).style(grin_gui_core::theme::ContainerStyle::NormalBackground);
+
+	let amount_input = TextInput::new("", &state.amount_value)
+		.on_input(|s| {
+			Interaction::WalletOperationCreateInvoiceViewInteraction(LocalViewInteraction::Amount(
+				s,
+			))
+		})
+		.size(DEFAULT_FONT_SIZE)
+		.padding(6)
+		.width(Length::Fixed(100.0))
+		.style(grin_gui_core::theme::TextInputStyle::AddonsQuery);
+
+	let amount_input: Element<Interaction> = amount_input.into();
+
+	let amount_error = Text::new(localized_string("create-tx-amount-error"))
+		.size(DEFAULT_FONT_SIZE)
+		.horizontal_alignment(alignment::Horizontal::Left)
+		.style(grin_gui_core::theme::text::TextStyle::Warning);
+
+	let amount_error_container =
+		Container::new(amount_error).style(grin_gui_core::theme::ContainerStyle::NormalBackground);
+
+	let button_height = Length::Fixed(BUTTON_HEIGHT);
+	let button_width = Length::Fixed(BUTTON_WIDTH);
+
+	let submit_button_label_container =
+		Container::new(Text::new(localized_string("tx-invoice-submit")).size(DEFAULT_FONT_SIZE))
+			.width(button_width)
+			.height(button_height)
+			.center_x()
+			.center_y()
+			.align_x(alignment::Horizontal::Center);
+
+	let submit_button: Element<Interaction> = Button::new(submit_button_label_container)
+		.style(grin_gui_core::theme::ButtonStyle::Primary)
+		.on_press(Interaction::WalletOperationCreateInvoiceViewInteraction(
+			LocalViewInteraction::CreateInvoice,
+		))
+		.into();
+
+	let cancel_button_label_container =
+		Container::new(Text::new(localized_string("cancel")).size(DEFAULT_FONT_SIZE))
+			.width(button_width)
+			.height(button_height)
+			.center_x()
+			.center_y()
+			.align_x(alignment::Horizontal::Center);
+
+	let cancel_button: Element<Interaction> = Button::new(cancel_button_label_container)
+		.style(grin_gui_core::theme::ButtonStyle::Primary)
+		.on_press(Interaction::WalletOperationCreateInvoiceViewInteraction(
+			LocalViewInteraction::Back,
+		))
+		.into();
+
+	let submit_container = Container::new(submit_button.map(Message::Interaction)).padding(1);
+	let submit_container = Container::new(submit_container)
+		.style(grin_gui_core::theme::ContainerStyle::Segmented)
+		.padding(1);
+
+	let cancel_container = Container::new(cancel_button.map(Message::Interaction)).padding(1);
+	let cancel_container = Container::new(cancel_container)
+		.style(grin_gui_core::theme::ContainerStyle::Segmented)
+		.padding(1);
+
+	let unit_spacing = 15.0;
+	let button_row = Row::new()
+		.push(submit_container)
+		.push(Space::new(Length::Fixed(unit_spacing), Length::Fixed(0.0)))
+		.push(cancel_container);
+
+	let mut column = Column::new()
+		.push(payer_address_container)
+		.push(Space::new(Length::Fixed(0.0), Length::Fixed(unit_spacing)))
+		.push(payer_address_instruction_container)
+		.push(Space::new(Length::Fixed(0.0), Length::Fixed(unit_spacing)))
+		.push(payer_address_input.map(Message::Interaction))
+		.push(Space::new(Length::Fixed(0.0), Length::Fixed(unit_spacing)));
+
+	if state.slatepack_address_error {
+		column = column
+			.push(address_error_container)
+			.push(Space::new(Length::Fixed(0.0), Length::Fixed(unit_spacing)));
+	}
+
+	column = column
+		.push(amount_container)
+		.push(Space::new(Length::Fixed(0.0), Length::Fixed(unit_spacing)))
+		.push(amount_input.map(Message::Interaction))
+		.push(Space::new(Length::Fixed(0.0), Length::Fixed(unit_spacing)));
+
+	if state.amount_error {
+		column = column
+			.push(amount_error_container)
+			.push(Space::new(Length::Fixed(0.0), Length::Fixed(unit_spacing)));
+	}
+
+	column = column
+		.push(button_row)
+		.push(Space::new(Length::Fixed(0.0), Length::Fixed(unit_spacing)))
+		.push(Space::new(
+			Length::Fixed(0.0),
+			Length::Fixed(unit_spacing + 10.0),
+		));
+
+	let form_container = Container::new(column)
+		.width(Length::Fill)
+		.padding(iced::Padding::from([
+			0, // top
+			0, // right
+			0, // bottom
+			5, // left
+		]));
+
+	// form container should be scrollable in tiny windows
+	let scrollable = Scrollable::new(form_container)
+		.height(Length::Fill)
+		.style(grin_gui_core::theme::ScrollableStyle::Primary);
+
+	let content = Container::new(scrollable)
+		.width(Length::Fill)
+		.height(Length::Shrink)
+		.style(grin_gui_core::theme::ContainerStyle::NormalBackground);
+
+	let wrapper_column = Column::new()
+		.height(Length::Fill)
+		.push(header_container)
+		.push(content);
+
+	// Returns the final container.
+	Container::new(wrapper_column).padding(iced::Padding::from([
+		DEFAULT_PADDING, // top
+		DEFAULT_PADDING, // right
+		DEFAULT_PADDING, // bottom
+		DEFAULT_PADDING, // left
+	]))
+}
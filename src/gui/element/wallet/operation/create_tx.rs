@@ -2,9 +2,9 @@ use super::tx_list::{self, ExpandType};
 use crate::log_error;
 use async_std::prelude::FutureExt;
 use grin_gui_core::{
-	config::Config,
+	config::{Config, TxDefaults},
 	error::GrinWalletInterfaceError,
-	wallet::{TxLogEntry, TxLogEntryType},
+	wallet::{contacts::AddressBook, SlatepackAddress, TxEstimate, TxLogEntry, TxLogEntryType},
 };
 use grin_gui_widgets::widget::header;
 use iced_aw::Card;
@@ -47,6 +47,10 @@ pub struct StateContainer {
 	amount_error: bool,
 	// slatepack address error
 	slatepack_address_error: bool,
+	pub show_advanced_options: bool,
+	pub advanced_options_state: AdvancedOptionsState,
+	// Dry run of the send awaiting confirmation, nothing is locked until confirmed
+	estimate: Option<TxEstimate>,
 }
 
 impl Default for StateContainer {
@@ -56,10 +60,77 @@ impl Default for StateContainer {
 			amount_value: Default::default(),
 			amount_error: false,
 			slatepack_address_error: false,
+			show_advanced_options: false,
+			advanced_options_state: Default::default(),
+			estimate: None,
 		}
 	}
 }
 
+pub struct AdvancedOptionsState {
+	pub minimum_confirmations_value: String,
+	pub num_change_outputs_value: String,
+	pub selection_strategy_is_use_all: bool,
+	pub late_lock: bool,
+	pub ttl_blocks_value: String,
+	// Locale key of the validation error to show, if any
+	error: Option<&'static str>,
+	// whether current values were just saved as defaults
+	saved: bool,
+}
+
+impl Default for AdvancedOptionsState {
+	fn default() -> Self {
+		Self::from(&TxDefaults::default())
+	}
+}
+
+impl From<&TxDefaults> for AdvancedOptionsState {
+	fn from(defaults: &TxDefaults) -> Self {
+		Self {
+			minimum_confirmations_value: defaults.minimum_confirmations.to_string(),
+			num_change_outputs_value: defaults.num_change_outputs.to_string(),
+			selection_strategy_is_use_all: defaults.selection_strategy_is_use_all,
+			late_lock: defaults.late_lock,
+			ttl_blocks_value: defaults
+				.ttl_blocks
+				.map(|t| t.to_string())
+				.unwrap_or_default(),
+			error: None,
+			saved: false,
+		}
+	}
+}
+
+impl AdvancedOptionsState {
+	/// Validate the entered values, returning the locale key of the first problem found
+	pub fn parse(&self) -> std::result::Result<TxDefaults, &'static str> {
+		let minimum_confirmations = match self.minimum_confirmations_value.trim().parse::<u64>() {
+			Ok(c) if TxDefaults::minimum_confirmations_valid(c) => c,
+			_ => return Err("tx-min-confirmations-error"),
+		};
+		let num_change_outputs = match self.num_change_outputs_value.trim().parse::<usize>() {
+			Ok(c) if TxDefaults::num_change_outputs_valid(c) => c,
+			_ => return Err("tx-change-outputs-error"),
+		};
+		// Blank means the transaction never expires
+		let ttl_blocks = match self.ttl_blocks_value.trim() {
+			"" => None,
+			t => match t.parse::<u64>() {
+				Ok(t) if TxDefaults::ttl_blocks_valid(t) => Some(t),
+				_ => return Err("tx-ttl-blocks-error"),
+			},
+		};
+		Ok(TxDefaults {
+			minimum_confirmations,
+			num_change_outputs,
+			selection_strategy_is_use_all: self.selection_strategy_is_use_all,
+			late_lock: self.late_lock,
+			ttl_blocks,
+		})
+	}
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum Action {}
 
@@ -69,7 +140,17 @@ pub enum LocalViewInteraction {
 	RecipientAddress(String),
 	Amount(String),
 	CreateTransaction(),
-
+	ConfirmTransaction,
+	EditTransaction,
+	ToggleAdvancedOptions(bool),
+	MinimumConfirmations(String),
+	NumChangeOutputs(String),
+	ToggleUseAll(bool),
+	ToggleLateLock(bool),
+	TtlBlocks(String),
+	SaveTxDefaults,
+
+	TxEstimateOk(TxEstimate),
 	TxCreatedOk(Slate, String),
 	TxCreateError(Arc<RwLock<Option<anyhow::Error>>>),
 	SlatepackAddressError,
@@ -81,6 +162,21 @@ pub fn handle_message<'a>(
 ) -> Result<Command<Message>> {
 	let state = &mut grin_gui.wallet_state.operation_state.create_tx_state;
 
+	// Any change to the form makes a shown estimate stale
+	if matches!(
+		message,
+		LocalViewInteraction::Back
+			| LocalViewInteraction::RecipientAddress(_)
+			| LocalViewInteraction::Amount(_)
+			| LocalViewInteraction::MinimumConfirmations(_)
+			| LocalViewInteraction::NumChangeOutputs(_)
+			| LocalViewInteraction::ToggleUseAll(_)
+			| LocalViewInteraction::ToggleLateLock(_)
+			| LocalViewInteraction::TtlBlocks(_)
+	) {
+		state.estimate = None;
+	}
+
 	match message {
 		LocalViewInteraction::Back => {
 			log::debug!("Interaction::WalletOperationCreateTxViewInteraction(Back)");
@@ -93,38 +189,111 @@ pub fn handle_message<'a>(
 		LocalViewInteraction::Amount(s) => {
 			state.amount_value = s;
 		}
+		LocalViewInteraction::ToggleAdvancedOptions(_) => {
+			state.show_advanced_options = !state.show_advanced_options
+		}
+		LocalViewInteraction::MinimumConfirmations(s) => {
+			state.advanced_options_state.minimum_confirmations_value = s;
+			state.advanced_options_state.error = None;
+			state.advanced_options_state.saved = false;
+		}
+		LocalViewInteraction::NumChangeOutputs(s) => {
+			state.advanced_options_state.num_change_outputs_value = s;
+			state.advanced_options_state.error = None;
+			state.advanced_options_state.saved = false;
+		}
+		LocalViewInteraction::ToggleUseAll(b) => {
+			state.advanced_options_state.selection_strategy_is_use_all = b;
+			state.advanced_options_state.saved = false;
+		}
+		LocalViewInteraction::ToggleLateLock(b) => {
+			state.advanced_options_state.late_lock = b;
+			state.advanced_options_state.error = None;
+			state.advanced_options_state.saved = false;
+		}
+		LocalViewInteraction::TtlBlocks(s) => {
+			state.advanced_options_state.ttl_blocks_value = s;
+			state.advanced_options_state.error = None;
+			state.advanced_options_state.saved = false;
+		}
+		LocalViewInteraction::SaveTxDefaults => match state.advanced_options_state.parse() {
+			Ok(defaults) => {
+				grin_gui.config.tx_defaults = defaults;
+				let _ = grin_gui.config.save();
+				state.advanced_options_state.saved = true;
+			}
+			Err(e) => state.advanced_options_state.error = Some(e),
+		},
 		LocalViewInteraction::CreateTransaction() => {
 			grin_gui.error.take();
-			state.amount_error = false;
-			state.slatepack_address_error = false;
-
 			log::debug!("Interaction::WalletOperationCreateTxViewInteraction");
 
+			let account = grin_gui
+				.wallet_state
+				.operation_state
+				.accounts_state
+				.active_account
+				.clone();
+			let args = match init_tx_args(state, account) {
+				Some(a) => a,
+				None => return Ok(Command::none()),
+			};
+
+			// Catch a bad address before showing the estimate rather than after confirming
+			if SlatepackAddress::try_from(state.recipient_address_value.as_str()).is_err() {
+				state.slatepack_address_error = true;
+				return Ok(Command::none());
+			}
+
 			let w = grin_gui.wallet_interface.clone();
+			let fut = grin_gui
+				.wallet_worker
+				.run(WalletInterface::estimate_tx(w, args, None));
 
-			let amount = match amount_from_hr_string(&state.amount_value) {
-				Ok(0) | Err(_) => {
-					state.amount_error = true;
-					return Ok(Command::none());
+			return Ok(Command::perform(fut, |r| {
+				match r.context("Failed to estimate transaction") {
+					Ok(estimate) => {
+						Message::Interaction(Interaction::WalletOperationCreateTxViewInteraction(
+							LocalViewInteraction::TxEstimateOk(estimate),
+						))
+					}
+					Err(e) => {
+						Message::Interaction(Interaction::WalletOperationCreateTxViewInteraction(
+							LocalViewInteraction::TxCreateError(Arc::new(RwLock::new(Some(e)))),
+						))
+					}
 				}
-				Ok(a) => a,
-			};
+			}));
+		}
+		LocalViewInteraction::TxEstimateOk(estimate) => {
+			state.estimate = Some(estimate);
+		}
+		LocalViewInteraction::EditTransaction => {
+			state.estimate = None;
+		}
+		LocalViewInteraction::ConfirmTransaction => {
+			grin_gui.error.take();
+			state.estimate = None;
 
-			// Todo: Amount parsing + validation, just testing the flow for now
-			let args = InitTxArgs {
-				src_acct_name: None,
-				amount,
-				minimum_confirmations: 2,
-				max_outputs: 500,
-				num_change_outputs: 1,
-				selection_strategy_is_use_all: false,
-				late_lock: Some(false),
-				..Default::default()
+			let account = grin_gui
+				.wallet_state
+				.operation_state
+				.accounts_state
+				.active_account
+				.clone();
+			let args = match init_tx_args(state, account) {
+				Some(a) => a,
+				None => return Ok(Command::none()),
 			};
-			let fut =
-				move || WalletInterface::create_tx(w, args, state.recipient_address_value.clone());
 
-			return Ok(Command::perform(fut(), |r| match r {
+			let w = grin_gui.wallet_interface.clone();
+			let fut = grin_gui.wallet_worker.run(WalletInterface::create_tx(
+				w,
+				args,
+				state.recipient_address_value.clone(),
+			));
+
+			return Ok(Command::perform(fut, |r| match r {
 				Ok((enc_slate, unenc_slate)) => {
 					Message::Interaction(Interaction::WalletOperationCreateTxViewInteraction(
 						LocalViewInteraction::TxCreatedOk(enc_slate, unenc_slate),
@@ -150,7 +319,7 @@ pub fn handle_message<'a>(
 				.wallet_state
 				.operation_state
 				.show_slatepack_state
-				.encrypted_slate = Some(encrypted_slate.to_string());
+				.set_encrypted_slate(Some(encrypted_slate.to_string()));
 
 			grin_gui
 				.wallet_state
@@ -172,8 +341,20 @@ pub fn handle_message<'a>(
 				output.sync_all()?;
 			}
 
+			let dest = state.recipient_address_value.clone();
+			super::contacts::record_recipient(grin_gui, &dest);
+
 			grin_gui.wallet_state.operation_state.mode =
 				crate::gui::element::wallet::operation::Mode::ShowSlatepack;
+
+			// The slatepack file above stays as a fallback if the recipient can't be reached
+			if grin_gui.config.tor_dispatch.enabled {
+				return Ok(super::show_slatepack::tor_dispatch_command(
+					grin_gui,
+					unencrypted_slate,
+					dest,
+				));
+			}
 		}
 		LocalViewInteraction::TxCreateError(err) => {
 			grin_gui.error = err.write().unwrap().take();
@@ -187,7 +368,51 @@ pub fn handle_message<'a>(
 	Ok(Command::none())
 }
 
-pub fn data_container<'a>(config: &'a Config, state: &'a StateContainer) -> Container<'a, Message> {
+/// Validate the form and build the send arguments, flagging the offending field on error
+fn init_tx_args(state: &mut StateContainer, src_acct_name: String) -> Option<InitTxArgs> {
+	state.amount_error = false;
+	state.slatepack_address_error = false;
+
+	let amount = match amount_from_hr_string(&state.amount_value) {
+		Ok(0) | Err(_) => {
+			state.amount_error = true;
+			return None;
+		}
+		Ok(a) => a,
+	};
+
+	let tx_options = match state.advanced_options_state.parse() {
+		Ok(o) => o,
+		Err(e) => {
+			state.advanced_options_state.error = Some(e);
+			state.show_advanced_options = true;
+			return None;
+		}
+	};
+
+	Some(InitTxArgs {
+		src_acct_name: Some(src_acct_name),
+		amount,
+		minimum_confirmations: tx_options.minimum_confirmations,
+		max_outputs: 500,
+		num_change_outputs: tx_options.num_change_outputs,
+		selection_strategy_is_use_all: tx_options.selection_strategy_is_use_all,
+		late_lock: Some(tx_options.late_lock),
+		ttl_blocks: tx_options.ttl_blocks,
+		// Always ask a slatepack recipient for a payment proof
+		payment_proof_recipient_address: SlatepackAddress::try_from(
+			state.recipient_address_value.trim(),
+		)
+		.ok(),
+		..Default::default()
+	})
+}
+
+pub fn data_container<'a>(
+	config: &'a Config,
+	state: &'a StateContainer,
+	address_book: &'a AddressBook,
+) -> Container<'a, Message> {
 	// Title row
 	let title = Text::new(localized_string("create-tx"))
 		.size(DEFAULT_HEADER_FONT_SIZE)
@@ -232,6 +457,21 @@ pub fn data_container<'a>(config: &'a Config, state: &'a StateContainer) -> Cont
 
 	let recipient_address_input: Element<Interaction> = recipient_address_input.into();
 
+	let mut recipient_address_row = Row::new()
+		.push(recipient_address_input.map(Message::Interaction))
+		.align_items(Alignment::Center);
+
+	if let Some(picker) =
+		super::contacts::contact_picker(address_book, &state.recipient_address_value, |a| {
+			Interaction::WalletOperationCreateTxViewInteraction(
+				LocalViewInteraction::RecipientAddress(a),
+			)
+		}) {
+		recipient_address_row = recipient_address_row
+			.push(Space::new(Length::Fixed(15.0), Length::Fixed(0.0)))
+			.push(picker.map(Message::Interaction));
+	}
+
 	let address_error = Text::new(localized_string("create-tx-address-error"))
 		.size(DEFAULT_FONT_SIZE)
 		.horizontal_alignment(alignment::Horizontal::Left)
@@ -278,27 +518,104 @@ pub fn data_container<'a>(config: &'a Config, state: &'a StateContainer) -> Cont
 	let address_instruction_container =
 		Container::new(address_instruction_container).style(ContainerStyle::NormalBackground);
 
+	// `InitTxArgs` can't name the inputs to spend, only contract sends can use hand-picked ones
+	let coin_control_summary =
+		Text::new(localized_string("outputs-automatic-selection")).size(DEFAULT_FONT_SIZE);
+	let coin_control_summary_container = Container::new(coin_control_summary)
+		.style(ContainerStyle::NormalBackground)
+		.padding(iced::Padding::from([
+			3, // top
+			0, // right
+			0, // bottom
+			0, // left
+		]));
+
+	let choose_outputs_label_container =
+		Container::new(Text::new(localized_string("outputs-choose")).size(SMALLER_FONT_SIZE))
+			.height(Length::Fixed(14.0))
+			.center_y()
+			.center_x();
+
+	let choose_outputs_button: Element<Interaction> = Button::new(choose_outputs_label_container)
+		.style(grin_gui_core::theme::ButtonStyle::Bordered)
+		.padding(2)
+		.into();
+
+	let coin_control_hint = Text::new(localized_string("outputs-legacy-unsupported"))
+		.size(SMALLER_FONT_SIZE)
+		.horizontal_alignment(alignment::Horizontal::Left);
+	let coin_control_hint_container = Container::new(coin_control_hint)
+		.style(ContainerStyle::NormalBackground)
+		.padding(iced::Padding::from([
+			3, // top
+			0, // right
+			0, // bottom
+			0, // left
+		]));
+
+	let coin_control_row = Row::new()
+		.push(coin_control_summary_container)
+		.push(Space::with_width(Length::Fixed(DEFAULT_PADDING)))
+		.push(choose_outputs_button.map(Message::Interaction))
+		.push(Space::with_width(Length::Fixed(DEFAULT_PADDING)))
+		.push(coin_control_hint_container);
+
+	let show_advanced_options = Checkbox::new(
+		localized_string("tx-advanced-options"),
+		state.show_advanced_options,
+		|b| {
+			Interaction::WalletOperationCreateTxViewInteraction(
+				LocalViewInteraction::ToggleAdvancedOptions(b),
+			)
+		},
+	)
+	.style(grin_gui_core::theme::CheckboxStyle::Normal)
+	.text_size(DEFAULT_FONT_SIZE)
+	.spacing(10);
+	let show_advanced_options: Element<Interaction> = show_advanced_options.into();
+	let show_advanced_options_container =
+		Container::new(show_advanced_options.map(Message::Interaction))
+			.style(grin_gui_core::theme::ContainerStyle::NormalBackground);
+
+	let advanced_options_column = advanced_options_column(&state.advanced_options_state);
+
 	let button_height = Length::Fixed(BUTTON_HEIGHT);
 	let button_width = Length::Fixed(BUTTON_WIDTH);
 
+	// Once estimated, the buttons confirm the send or go back to editing it
+	let (submit_label, submit_interaction, cancel_label, cancel_interaction) = match state.estimate
+	{
+		Some(_) => (
+			localized_string("tx-confirm-send"),
+			LocalViewInteraction::ConfirmTransaction,
+			localized_string("tx-edit"),
+			LocalViewInteraction::EditTransaction,
+		),
+		None => (
+			localized_string("tx-create-submit"),
+			LocalViewInteraction::CreateTransaction(),
+			localized_string("cancel"),
+			LocalViewInteraction::Back,
+		),
+	};
+
 	let submit_button_label_container =
-		Container::new(Text::new(localized_string("tx-create-submit")).size(DEFAULT_FONT_SIZE))
+		Container::new(Text::new(submit_label).size(DEFAULT_FONT_SIZE))
 			.width(button_width)
 			.height(button_height)
 			.center_x()
 			.center_y()
 			.align_x(alignment::Horizontal::Center);
 
-	let mut submit_button = Button::new(submit_button_label_container)
-		.style(grin_gui_core::theme::ButtonStyle::Primary);
-	let submit_button =
-		submit_button.on_press(Interaction::WalletOperationCreateTxViewInteraction(
-			LocalViewInteraction::CreateTransaction(),
-		));
-	let submit_button: Element<Interaction> = submit_button.into();
+	let submit_button: Element<Interaction> = Button::new(submit_button_label_container)
+		.style(grin_gui_core::theme::ButtonStyle::Primary)
+		.on_press(Interaction::WalletOperationCreateTxViewInteraction(
+			submit_interaction,
+		))
+		.into();
 
 	let cancel_button_label_container =
-		Container::new(Text::new(localized_string("cancel")).size(DEFAULT_FONT_SIZE))
+		Container::new(Text::new(cancel_label).size(DEFAULT_FONT_SIZE))
 			.width(button_width)
 			.height(button_height)
 			.center_x()
@@ -308,7 +625,7 @@ pub fn data_container<'a>(config: &'a Config, state: &'a StateContainer) -> Cont
 	let cancel_button: Element<Interaction> = Button::new(cancel_button_label_container)
 		.style(grin_gui_core::theme::ButtonStyle::Primary)
 		.on_press(Interaction::WalletOperationCreateTxViewInteraction(
-			LocalViewInteraction::Back,
+			cancel_interaction,
 		))
 		.into();
 
@@ -333,7 +650,7 @@ pub fn data_container<'a>(config: &'a Config, state: &'a StateContainer) -> Cont
 		.push(Space::new(Length::Fixed(0.0), Length::Fixed(unit_spacing)))
 		.push(address_instruction_container)
 		.push(Space::new(Length::Fixed(0.0), Length::Fixed(unit_spacing)))
-		.push(recipient_address_input.map(Message::Interaction))
+		.push(recipient_address_row)
 		.push(Space::new(Length::Fixed(0.0), Length::Fixed(unit_spacing)));
 
 	if state.slatepack_address_error {
@@ -354,6 +671,24 @@ pub fn data_container<'a>(config: &'a Config, state: &'a StateContainer) -> Cont
 			.push(Space::new(Length::Fixed(0.0), Length::Fixed(unit_spacing)));
 	}
 
+	column = column
+		.push(coin_control_row)
+		.push(Space::new(Length::Fixed(0.0), Length::Fixed(unit_spacing)))
+		.push(show_advanced_options_container)
+		.push(Space::new(Length::Fixed(0.0), Length::Fixed(unit_spacing)));
+
+	if state.show_advanced_options {
+		column = column
+			.push(advanced_options_column)
+			.push(Space::new(Length::Fixed(0.0), Length::Fixed(unit_spacing)));
+	}
+
+	if let Some(estimate) = &state.estimate {
+		column = column
+			.push(estimate_column(estimate))
+			.push(Space::new(Length::Fixed(0.0), Length::Fixed(unit_spacing)));
+	}
+
 	column = column
 		.push(button_row)
 		.push(Space::new(Length::Fixed(0.0), Length::Fixed(unit_spacing)))
@@ -394,3 +729,159 @@ pub fn data_container<'a>(config: &'a Config, state: &'a StateContainer) -> Cont
 		DEFAULT_PADDING, // left
 	]))
 }
+
+fn advanced_options_column<'a>(state: &'a AdvancedOptionsState) -> Column<'a, Message> {
+	let unit_spacing = 15.0;
+
+	// Each numeric option is a label followed by a small input
+	let input_row =
+		|label: String, value: &'a str, on_input: fn(String) -> LocalViewInteraction| {
+			let label = Container::new(Text::new(label).size(DEFAULT_FONT_SIZE))
+				.width(Length::Fixed(200.0))
+				.center_y()
+				.style(grin_gui_core::theme::ContainerStyle::NormalBackground);
+
+			let input = TextInput::new("", value)
+				.on_input(move |s| Interaction::WalletOperationCreateTxViewInteraction(on_input(s)))
+				.size(DEFAULT_FONT_SIZE)
+				.padding(6)
+				.width(Length::Fixed(100.0))
+				.style(grin_gui_core::theme::TextInputStyle::AddonsQuery);
+			let input: Element<Interaction> = input.into();
+
+			Row::new()
+				.push(label)
+				.push(input.map(Message::Interaction))
+				.align_items(Alignment::Center)
+		};
+
+	let minimum_confirmations_row = input_row(
+		localized_string("tx-min-confirmations"),
+		&state.minimum_confirmations_value,
+		LocalViewInteraction::MinimumConfirmations,
+	);
+	let num_change_outputs_row = input_row(
+		localized_string("tx-change-outputs"),
+		&state.num_change_outputs_value,
+		LocalViewInteraction::NumChangeOutputs,
+	);
+	let ttl_blocks_row = input_row(
+		localized_string("tx-ttl-blocks"),
+		&state.ttl_blocks_value,
+		LocalViewInteraction::TtlBlocks,
+	);
+
+	let use_all = Checkbox::new(
+		localized_string("tx-use-all"),
+		state.selection_strategy_is_use_all,
+		|b| {
+			Interaction::WalletOperationCreateTxViewInteraction(LocalViewInteraction::ToggleUseAll(
+				b,
+			))
+		},
+	)
+	.style(grin_gui_core::theme::CheckboxStyle::Normal)
+	.text_size(DEFAULT_FONT_SIZE)
+	.spacing(10);
+	let use_all: Element<Interaction> = use_all.into();
+
+	let late_lock = Checkbox::new(localized_string("tx-late-lock"), state.late_lock, |b| {
+		Interaction::WalletOperationCreateTxViewInteraction(LocalViewInteraction::ToggleLateLock(b))
+	})
+	.style(grin_gui_core::theme::CheckboxStyle::Normal)
+	.text_size(DEFAULT_FONT_SIZE)
+	.spacing(10);
+	let late_lock: Element<Interaction> = late_lock.into();
+
+	let save_label = if state.saved {
+		localized_string("tx-defaults-saved")
+	} else {
+		localized_string("tx-save-defaults")
+	};
+	let save_label_container = Container::new(Text::new(save_label).size(SMALLER_FONT_SIZE))
+		.height(Length::Fixed(14.0))
+		.center_y()
+		.center_x();
+
+	let save_button: Element<Interaction> = Button::new(save_label_container)
+		.style(grin_gui_core::theme::ButtonStyle::Bordered)
+		.on_press(Interaction::WalletOperationCreateTxViewInteraction(
+			LocalViewInteraction::SaveTxDefaults,
+		))
+		.padding(2)
+		.into();
+
+	let mut column = Column::new()
+		.push(minimum_confirmations_row)
+		.push(Space::new(Length::Fixed(0.0), Length::Fixed(5.0)))
+		.push(num_change_outputs_row)
+		.push(Space::new(Length::Fixed(0.0), Length::Fixed(5.0)))
+		.push(ttl_blocks_row)
+		.push(Space::new(Length::Fixed(0.0), Length::Fixed(unit_spacing)))
+		.push(use_all.map(Message::Interaction))
+		.push(Space::new(Length::Fixed(0.0), Length::Fixed(unit_spacing)))
+		.push(late_lock.map(Message::Interaction))
+		.push(Space::new(Length::Fixed(0.0), Length::Fixed(unit_spacing)));
+
+	if let Some(e) = state.error {
+		let error = Text::new(localized_string(e))
+			.size(DEFAULT_FONT_SIZE)
+			.horizontal_alignment(alignment::Horizontal::Left)
+			.style(grin_gui_core::theme::text::TextStyle::Warning);
+		column = column
+			.push(Container::new(error).style(ContainerStyle::NormalBackground))
+			.push(Space::new(Length::Fixed(0.0), Length::Fixed(unit_spacing)));
+	}
+
+	column.push(save_button.map(Message::Interaction))
+}
+
+/// Summary of a dry run send, shown while waiting for the user to confirm
+pub fn estimate_column<'a>(estimate: &TxEstimate) -> Column<'a, Message> {
+	let row = |label: String, value: String| {
+		let label = Container::new(Text::new(label).size(DEFAULT_FONT_SIZE))
+			.width(Length::Fixed(200.0))
+			.style(grin_gui_core::theme::ContainerStyle::NormalBackground);
+		let value = Container::new(Text::new(value).size(DEFAULT_FONT_SIZE))
+			.style(grin_gui_core::theme::ContainerStyle::NormalBackground);
+		Row::new().push(label).push(value)
+	};
+
+	let title = Text::new(localized_string("tx-estimate-title"))
+		.size(DEFAULT_FONT_SIZE)
+		.horizontal_alignment(alignment::Horizontal::Left);
+	let title_container =
+		Container::new(title).style(grin_gui_core::theme::ContainerStyle::NormalBackground);
+
+	let description = Text::new(localized_string("tx-estimate-description"))
+		.size(SMALLER_FONT_SIZE)
+		.horizontal_alignment(alignment::Horizontal::Left);
+	let description_container =
+		Container::new(description).style(grin_gui_core::theme::ContainerStyle::NormalBackground);
+
+	Column::new()
+		.push(title_container)
+		.push(Space::new(Length::Fixed(0.0), Length::Fixed(5.0)))
+		.push(description_container)
+		.push(Space::new(Length::Fixed(0.0), Length::Fixed(10.0)))
+		.push(row(
+			localized_string("tx-estimate-amount"),
+			amount_to_hr_string(estimate.amount, true),
+		))
+		.push(row(
+			localized_string("tx-estimate-fee"),
+			amount_to_hr_string(estimate.fee, true),
+		))
+		.push(row(
+			localized_string("tx-estimate-inputs"),
+			format!(
+				"{} ({})",
+				estimate.num_inputs,
+				amount_to_hr_string(estimate.inputs_total, true)
+			),
+		))
+		.push(row(
+			localized_string("tx-estimate-change"),
+			amount_to_hr_string(estimate.change, true),
+		))
+}
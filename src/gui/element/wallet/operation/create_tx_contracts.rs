@@ -1,3 +1,5 @@
+use super::create_tx::estimate_column;
+use super::outputs;
 use super::tx_list::{self, ExpandType};
 use crate::log_error;
 use async_std::prelude::FutureExt;
@@ -5,8 +7,8 @@ use grin_gui_core::{
 	config::Config,
 	error::GrinWalletInterfaceError,
 	wallet::{
-		ContractNewArgsAPI, ContractSetupArgsAPI, Slatepack, SlatepackAddress, TxLogEntry,
-		TxLogEntryType,
+		contacts::AddressBook, ContractNewArgsAPI, ContractSetupArgsAPI, Slatepack,
+		SlatepackAddress, TxEstimate, TxLogEntry, TxLogEntryType,
 	},
 };
 use grin_gui_widgets::widget::header;
@@ -60,6 +62,10 @@ pub struct StateContainer {
 	amount_error: bool,
 	// slatepack address error
 	slatepack_address_error: bool,
+	// Outputs picked in the output browser, wallet selects inputs itself if None
+	pub coin_control: Option<outputs::CoinControl>,
+	// Dry run of a debit awaiting confirmation, nothing is locked until confirmed
+	estimate: Option<TxEstimate>,
 }
 
 impl Default for StateContainer {
@@ -71,6 +77,8 @@ impl Default for StateContainer {
 			is_self_send: false,
 			amount_error: false,
 			slatepack_address_error: false,
+			coin_control: None,
+			estimate: None,
 		}
 	}
 }
@@ -85,8 +93,13 @@ pub enum LocalViewInteraction {
 	ContributionChoice(ContributionChoice),
 	Amount(String),
 	CreateTransaction(),
+	ConfirmTransaction,
+	EditTransaction,
 	SelfSendSelected(bool),
+	ChooseOutputs,
+	ClearCoinControl,
 
+	TxEstimateOk(TxEstimate),
 	TxCreatedOk(Slate, String),
 	SelfSendCreatedOk(Slate, TxLogEntry),
 	TxCreateError(Arc<RwLock<Option<anyhow::Error>>>),
@@ -102,6 +115,21 @@ pub fn handle_message<'a>(
 		.operation_state
 		.create_tx_contracts_state;
 
+	// Any change to the form makes a shown estimate stale
+	if matches!(
+		message,
+		LocalViewInteraction::Back
+			| LocalViewInteraction::RecipientAddress(_)
+			| LocalViewInteraction::ContributionChoice(_)
+			| LocalViewInteraction::Amount(_)
+			| LocalViewInteraction::SelfSendSelected(_)
+			| LocalViewInteraction::ChooseOutputs
+			| LocalViewInteraction::ClearCoinControl
+	) {
+		state.estimate = None;
+	}
+	let confirmed = matches!(message, LocalViewInteraction::ConfirmTransaction);
+
 	match message {
 		LocalViewInteraction::Back => {
 			log::debug!("Interaction::WalletOperationCreateTxViewInteraction(Back)");
@@ -129,6 +157,24 @@ pub fn handle_message<'a>(
 		LocalViewInteraction::Amount(s) => {
 			state.amount_value = s;
 		}
+		LocalViewInteraction::ChooseOutputs => {
+			let operation_state = &mut grin_gui.wallet_state.operation_state;
+			operation_state.outputs_state.opened_from_create = true;
+			// Contract sends select outputs as libwallet does by default, see `estimate_args`
+			operation_state.outputs_state.send_minimum_confirmations =
+				Some(InitTxArgs::default().minimum_confirmations);
+			operation_state.mode = crate::gui::element::wallet::operation::Mode::Outputs;
+			return Ok(outputs::show_command());
+		}
+		LocalViewInteraction::ClearCoinControl => {
+			state.coin_control = None;
+			grin_gui
+				.wallet_state
+				.operation_state
+				.outputs_state
+				.selected
+				.clear();
+		}
 		LocalViewInteraction::SelfSendSelected(v) => {
 			state.is_self_send = v;
 			if let Some(ref a) = grin_gui
@@ -140,7 +186,13 @@ pub fn handle_message<'a>(
 				state.recipient_address_value = a.clone();
 			}
 		}
-		LocalViewInteraction::CreateTransaction() => {
+		LocalViewInteraction::TxEstimateOk(estimate) => {
+			state.estimate = Some(estimate);
+		}
+		LocalViewInteraction::EditTransaction => {
+			state.estimate = None;
+		}
+		LocalViewInteraction::CreateTransaction() | LocalViewInteraction::ConfirmTransaction => {
 			grin_gui.error.take();
 			state.amount_error = false;
 			state.slatepack_address_error = false;
@@ -205,12 +257,63 @@ pub fn handle_message<'a>(
 				if let Some(a) = &grin_gui.wallet_state.operation_state.home_state.address {
 					args.setup_args.proof_args.sender_address = Some(a.pub_key);
 				}
+				if let Some(c) = &state.coin_control {
+					args.setup_args.selection_args.use_inputs = Some(c.commits.join(","));
+				}
 			};
 
+			// Only a debit to someone else spends inputs, so that's what gets a dry run first.
+			// The contract API has no estimate of its own, an equivalent standard send is used
+			if !confirmed
+				&& !state.is_self_send
+				&& state.contribution_choice == ContributionChoice::Debit
+			{
+				let init_args = estimate_args(
+					&args,
+					grin_gui
+						.wallet_state
+						.operation_state
+						.accounts_state
+						.active_account
+						.clone(),
+				);
+				// Only when the send itself is limited to the picked outputs
+				let coin_control = args
+					.setup_args
+					.selection_args
+					.use_inputs
+					.as_ref()
+					.and(state.coin_control.as_ref())
+					.map(|c| (c.commits.len() as u64, c.total));
+				let fut = grin_gui.wallet_worker.run(WalletInterface::estimate_tx(
+					w,
+					init_args,
+					coin_control,
+				));
+
+				return Ok(Command::perform(fut, |r| {
+					match r.context("Failed to estimate transaction") {
+						Ok(estimate) => Message::Interaction(
+							Interaction::WalletOperationCreateTxContractsViewInteraction(
+								LocalViewInteraction::TxEstimateOk(estimate),
+							),
+						),
+						Err(e) => Message::Interaction(
+							Interaction::WalletOperationCreateTxContractsViewInteraction(
+								LocalViewInteraction::TxCreateError(Arc::new(RwLock::new(Some(e)))),
+							),
+						),
+					}
+				}));
+			}
+			state.estimate = None;
+
 			if state.is_self_send {
-				let fut = move || WalletInterface::contract_self_send(w, args);
+				let fut = grin_gui
+					.wallet_worker
+					.run(WalletInterface::contract_self_send(w, args));
 
-				return Ok(Command::perform(fut(), |r| match r {
+				return Ok(Command::perform(fut, |r| match r {
 					Ok((unenc_slate, tx_log_entry)) => Message::Interaction(
 						Interaction::WalletOperationCreateTxContractsViewInteraction(
 							LocalViewInteraction::SelfSendCreatedOk(unenc_slate, tx_log_entry),
@@ -232,11 +335,13 @@ pub fn handle_message<'a>(
 					},
 				}));
 			} else {
-				let fut = move || {
-					WalletInterface::contract_new(w, args, state.recipient_address_value.clone())
-				};
+				let fut = grin_gui.wallet_worker.run(WalletInterface::contract_new(
+					w,
+					args,
+					state.recipient_address_value.clone(),
+				));
 
-				return Ok(Command::perform(fut(), |r| match r {
+				return Ok(Command::perform(fut, |r| match r {
 					Ok((enc_slate, unenc_slate)) => Message::Interaction(
 						Interaction::WalletOperationCreateTxContractsViewInteraction(
 							LocalViewInteraction::TxCreatedOk(enc_slate, unenc_slate),
@@ -261,11 +366,19 @@ pub fn handle_message<'a>(
 		}
 		LocalViewInteraction::TxCreatedOk(unencrypted_slate, encrypted_slate) => {
 			log::debug!("{}", unencrypted_slate);
+			// Picked outputs are now locked by this transaction
+			state.coin_control = None;
+			grin_gui
+				.wallet_state
+				.operation_state
+				.outputs_state
+				.selected
+				.clear();
 			grin_gui
 				.wallet_state
 				.operation_state
 				.show_slatepack_state
-				.encrypted_slate = Some(encrypted_slate.to_string());
+				.set_encrypted_slate(Some(encrypted_slate.to_string()));
 
 			grin_gui
 				.wallet_state
@@ -294,10 +407,20 @@ pub fn handle_message<'a>(
 				.confirm_state
 				.is_self_send = false;
 
+			let recipient = state.recipient_address_value.clone();
+			super::contacts::record_recipient(grin_gui, &recipient);
+
 			grin_gui.wallet_state.operation_state.mode =
 				crate::gui::element::wallet::operation::Mode::ShowSlatepack;
 		}
 		LocalViewInteraction::SelfSendCreatedOk(unencrypted_slate, tx_log_entry) => {
+			state.coin_control = None;
+			grin_gui
+				.wallet_state
+				.operation_state
+				.outputs_state
+				.selected
+				.clear();
 			grin_gui
 				.wallet_state
 				.operation_state
@@ -319,7 +442,38 @@ pub fn handle_message<'a>(
 	Ok(Command::none())
 }
 
-pub fn data_container<'a>(config: &'a Config, state: &'a StateContainer) -> Container<'a, Message> {
+/// Standard send equivalent to the contract debit `args`, for the dry run. Beyond the amount
+/// and the inputs to use, `args` leaves output selection at libwallet's defaults, and so
+/// does this
+fn estimate_args(args: &ContractNewArgsAPI, src_acct_name: String) -> InitTxArgs {
+	InitTxArgs {
+		src_acct_name: Some(src_acct_name),
+		amount: args
+			.setup_args
+			.net_change
+			.map(|c| c.unsigned_abs())
+			.unwrap_or_default(),
+		..Default::default()
+	}
+}
+
+/// Start a send spending the outputs picked on the outputs screen
+pub fn spend_outputs(grin_gui: &mut GrinGui, coin_control: outputs::CoinControl) {
+	let state = &mut grin_gui
+		.wallet_state
+		.operation_state
+		.create_tx_contracts_state;
+	state.coin_control = Some(coin_control);
+	state.contribution_choice = ContributionChoice::Debit;
+	state.is_self_send = false;
+	state.estimate = None;
+}
+
+pub fn data_container<'a>(
+	config: &'a Config,
+	state: &'a StateContainer,
+	address_book: &'a AddressBook,
+) -> Container<'a, Message> {
 	let unit_spacing = 15.0;
 
 	// Title row
@@ -395,10 +549,23 @@ pub fn data_container<'a>(config: &'a Config, state: &'a StateContainer) -> Cont
 		Column::new().push(checkbox_container)
 	};
 
-	let address_row = Row::new()
+	let mut address_row = Row::new()
 		//.push(recipient_address_container)
 		.push(recipient_address_input.map(Message::Interaction))
-		.push(Space::new(Length::Fixed(unit_spacing), Length::Fixed(0.0)))
+		.push(Space::new(Length::Fixed(unit_spacing), Length::Fixed(0.0)));
+
+	if let Some(picker) =
+		super::contacts::contact_picker(address_book, &state.recipient_address_value, |a| {
+			Interaction::WalletOperationCreateTxContractsViewInteraction(
+				LocalViewInteraction::RecipientAddress(a),
+			)
+		}) {
+		address_row = address_row
+			.push(picker.map(Message::Interaction))
+			.push(Space::new(Length::Fixed(unit_spacing), Length::Fixed(0.0)));
+	}
+
+	let address_row = address_row
 		.push(checkbox_column)
 		.align_items(Alignment::End);
 
@@ -483,25 +650,38 @@ pub fn data_container<'a>(config: &'a Config, state: &'a StateContainer) -> Cont
 	let button_height = Length::Fixed(BUTTON_HEIGHT);
 	let button_width = Length::Fixed(BUTTON_WIDTH);
 
+	// Once estimated, the buttons confirm the debit or go back to editing it
+	let (submit_label, submit_interaction, cancel_label, cancel_interaction) = match state.estimate
+	{
+		Some(_) => (
+			localized_string("tx-confirm-send"),
+			LocalViewInteraction::ConfirmTransaction,
+			localized_string("tx-edit"),
+			LocalViewInteraction::EditTransaction,
+		),
+		None => (
+			localized_string("tx-create-submit"),
+			LocalViewInteraction::CreateTransaction(),
+			localized_string("cancel"),
+			LocalViewInteraction::Back,
+		),
+	};
+
 	let submit_button_label_container =
-		Container::new(Text::new(localized_string("tx-create-submit")).size(DEFAULT_FONT_SIZE))
+		Container::new(Text::new(submit_label).size(DEFAULT_FONT_SIZE))
 			.width(button_width)
 			.height(button_height)
 			.center_x()
 			.center_y()
 			.align_x(alignment::Horizontal::Center);
 
-	let mut submit_button = Button::new(submit_button_label_container)
-		.style(grin_gui_core::theme::ButtonStyle::Primary);
-	let submit_button = submit_button.on_press(
-		Interaction::WalletOperationCreateTxContractsViewInteraction(
-			LocalViewInteraction::CreateTransaction(),
-		),
-	);
-	let submit_button: Element<Interaction> = submit_button.into();
+	let submit_button: Element<Interaction> = Button::new(submit_button_label_container)
+		.style(grin_gui_core::theme::ButtonStyle::Primary)
+		.on_press(Interaction::WalletOperationCreateTxContractsViewInteraction(submit_interaction))
+		.into();
 
 	let cancel_button_label_container =
-		Container::new(Text::new(localized_string("cancel")).size(DEFAULT_FONT_SIZE))
+		Container::new(Text::new(cancel_label).size(DEFAULT_FONT_SIZE))
 			.width(button_width)
 			.height(button_height)
 			.center_x()
@@ -510,11 +690,7 @@ pub fn data_container<'a>(config: &'a Config, state: &'a StateContainer) -> Cont
 
 	let cancel_button: Element<Interaction> = Button::new(cancel_button_label_container)
 		.style(grin_gui_core::theme::ButtonStyle::Primary)
-		.on_press(
-			Interaction::WalletOperationCreateTxContractsViewInteraction(
-				LocalViewInteraction::Back,
-			),
-		)
+		.on_press(Interaction::WalletOperationCreateTxContractsViewInteraction(cancel_interaction))
 		.into();
 
 	let submit_container = Container::new(submit_button.map(Message::Interaction)).padding(1);
@@ -562,6 +738,28 @@ pub fn data_container<'a>(config: &'a Config, state: &'a StateContainer) -> Cont
 			.push(Space::new(Length::Fixed(0.0), Length::Fixed(unit_spacing)));
 	}
 
+	// Only the paying side contributes inputs
+	if state.is_self_send || state.contribution_choice == ContributionChoice::Debit {
+		let coin_control_row = outputs::coin_control_row(
+			&state.coin_control,
+			Interaction::WalletOperationCreateTxContractsViewInteraction(
+				LocalViewInteraction::ChooseOutputs,
+			),
+			Interaction::WalletOperationCreateTxContractsViewInteraction(
+				LocalViewInteraction::ClearCoinControl,
+			),
+		);
+		column = column
+			.push(coin_control_row)
+			.push(Space::new(Length::Fixed(0.0), Length::Fixed(unit_spacing)));
+	}
+
+	if let Some(estimate) = &state.estimate {
+		column = column
+			.push(estimate_column(estimate))
+			.push(Space::new(Length::Fixed(0.0), Length::Fixed(unit_spacing)));
+	}
+
 	column = column
 		.push(button_row)
 		.push(Space::new(Length::Fixed(0.0), Length::Fixed(unit_spacing)))
@@ -0,0 +1,456 @@
+use crate::log_error;
+use grin_gui_core::{config::Config, wallet::proof::parse_date};
+use native_dialog::FileDialog;
+use std::path::PathBuf;
+
+use {
+	super::super::super::{
+		BUTTON_HEIGHT, BUTTON_WIDTH, DEFAULT_FONT_SIZE, DEFAULT_HEADER_FONT_SIZE, DEFAULT_PADDING,
+		SMALLER_FONT_SIZE,
+	},
+	crate::gui::{GrinGui, Interaction, Message},
+	crate::localization::localized_string,
+	crate::Result,
+	anyhow::Context,
+	grin_gui_core::error::GrinWalletInterfaceError,
+	grin_gui_core::theme::{Button, Column, Container, Element, Row, Scrollable, Text, TextInput},
+	grin_gui_core::wallet::{
+		worker::{JobHandle, WorkerStatus},
+		WalletInterface,
+	},
+	iced::widget::Space,
+	iced::{alignment, Alignment, Command, Length},
+	std::sync::{Arc, RwLock},
+};
+
+pub struct StateContainer {
+	pub from_value: String,
+	pub to_value: String,
+	// Chosen destination, the wallet's proof directory when not set
+	pub dir: Option<PathBuf>,
+	date_error: bool,
+	in_progress: bool,
+	// Running export on the wallet worker, and how far along it is
+	job: Option<JobHandle>,
+	progress: Option<u8>,
+	// Number of proofs written by the last export, and where to
+	exported: Option<(usize, PathBuf)>,
+}
+
+impl StateContainer {
+	/// Pick up the progress the running export reported to the wallet worker
+	pub fn update_progress(&mut self, status: &WorkerStatus) {
+		if let Some(job) = &self.job {
+			if status.running == Some(job.id()) {
+				self.progress = status.progress.as_ref().and_then(|p| p.percent);
+			}
+		}
+	}
+
+	fn finish_export(&mut self) {
+		self.in_progress = false;
+		self.job = None;
+		self.progress = None;
+	}
+}
+
+impl Default for StateContainer {
+	fn default() -> Self {
+		Self {
+			from_value: Default::default(),
+			to_value: Default::default(),
+			dir: None,
+			date_error: false,
+			in_progress: false,
+			job: None,
+			progress: None,
+			exported: None,
+		}
+	}
+}
+
+#[derive(Debug, Clone)]
+pub enum LocalViewInteraction {
+	Back,
+	From(String),
+	To(String),
+	ChooseDir,
+	Export,
+	ExportOk(usize, PathBuf),
+	ExportError(Arc<RwLock<Option<anyhow::Error>>>),
+}
+
+/// A date field, empty for an open ended range
+fn parse_date_value(value: &str) -> std::result::Result<Option<chrono::NaiveDate>, ()> {
+	if value.trim().is_empty() {
+		Ok(None)
+	} else {
+		parse_date(value).map(Some).ok_or(())
+	}
+}
+
+pub fn handle_message<'a>(
+	grin_gui: &mut GrinGui,
+	message: LocalViewInteraction,
+) -> Result<Command<Message>> {
+	let state = &mut grin_gui.wallet_state.operation_state.export_proofs_state;
+
+	match message {
+		LocalViewInteraction::Back => {
+			log::debug!("Interaction::WalletOperationExportProofsViewInteraction(Back)");
+			// Leaving stops a running export after the proof it's on
+			if let Some(job) = state.job.take() {
+				job.cancel();
+			}
+			state.finish_export();
+			state.exported = None;
+			grin_gui.wallet_state.operation_state.mode =
+				crate::gui::element::wallet::operation::Mode::Home;
+		}
+		LocalViewInteraction::From(s) => {
+			state.from_value = s;
+			state.date_error = false;
+		}
+		LocalViewInteraction::To(s) => {
+			state.to_value = s;
+			state.date_error = false;
+		}
+		LocalViewInteraction::ChooseDir => {
+			if let Ok(Some(d)) = FileDialog::new().show_open_single_dir() {
+				state.dir = Some(d);
+			}
+		}
+		LocalViewInteraction::Export => {
+			log::debug!("Interaction::WalletOperationExportProofsViewInteraction(Export)");
+			let (from, to) = match (
+				parse_date_value(&state.from_value),
+				parse_date_value(&state.to_value),
+			) {
+				(Ok(f), Ok(t)) => (f, t),
+				_ => {
+					state.date_error = true;
+					return Ok(Command::none());
+				}
+			};
+			let dir = match state.dir.clone() {
+				Some(d) => d,
+				None => match grin_gui.config.get_wallet_proof_dir() {
+					Some(d) => PathBuf::from(d),
+					None => return Ok(Command::none()),
+				},
+			};
+			grin_gui.error.take();
+			state.exported = None;
+			state.in_progress = true;
+
+			let w = grin_gui.wallet_interface.clone();
+			let export_dir = dir.clone();
+			let (job, fut) = grin_gui.wallet_worker.submit(move |job| {
+				WalletInterface::export_proofs(w, from, to, export_dir, Some(job))
+			});
+			state.job = Some(job);
+
+			return Ok(Command::perform(fut, move |r| {
+				match r.context("Failed to Export Proofs") {
+					Ok(exported) => Message::Interaction(
+						Interaction::WalletOperationExportProofsViewInteraction(
+							LocalViewInteraction::ExportOk(exported, dir),
+						),
+					),
+					Err(e) => Message::Interaction(
+						Interaction::WalletOperationExportProofsViewInteraction(
+							LocalViewInteraction::ExportError(Arc::new(RwLock::new(Some(e)))),
+						),
+					),
+				}
+			}));
+		}
+		LocalViewInteraction::ExportOk(exported, dir) => {
+			state.finish_export();
+			state.exported = Some((exported, dir));
+		}
+		LocalViewInteraction::ExportError(err) => {
+			let err = err.write().unwrap().take();
+			// Cancelled by going back, the form has already been reset
+			if let Some(GrinWalletInterfaceError::WalletJobCancelled) =
+				err.as_ref().and_then(|e| e.downcast_ref())
+			{
+				return Ok(Command::none());
+			}
+			state.finish_export();
+			grin_gui.error = err;
+			if let Some(e) = grin_gui.error.as_ref() {
+				log_error(e);
+			}
+		}
+	}
+
+	Ok(Command::none())
+}
+
+pub fn data_container<'a>(config: &'a Config, state: &'a StateContainer) -> Container<'a, Message> {
+	// Title row
+	let title = Text::new(localized_string("export-proofs-title"))
+		.size(DEFAULT_HEADER_FONT_SIZE)
+		.horizontal_alignment(alignment::Horizontal::Center);
+
+	let title_container = Container::new(title)
+		.style(grin_gui_core::theme::ContainerStyle::BrightBackground)
+		.padding(iced::Padding::from([
+			2, // top
+			0, // right
+			2, // bottom
+			5, // left
+		]));
+
+	let header_row = Row::new().push(title_container);
+
+	let header_container = Container::new(header_row).padding(iced::Padding::from([
+		0,                      // top
+		0,                      // right
+		DEFAULT_PADDING as u16, // bottom
+		0,                      // left
+	]));
+
+	let unit_spacing = 15.0;
+	let row_spacing = 5.0;
+
+	let button_height = Length::Fixed(BUTTON_HEIGHT);
+	let button_width = Length::Fixed(BUTTON_WIDTH);
+
+	let instruction = Text::new(localized_string("export-proofs-instruction"))
+		.size(SMALLER_FONT_SIZE)
+		.horizontal_alignment(alignment::Horizontal::Left);
+
+	let instruction_container =
+		Container::new(instruction).style(grin_gui_core::theme::ContainerStyle::NormalBackground);
+
+	// Date range
+	let from_label = Text::new(localized_string("export-proofs-from"))
+		.size(DEFAULT_FONT_SIZE)
+		.horizontal_alignment(alignment::Horizontal::Left);
+
+	let from_label_container =
+		Container::new(from_label).style(grin_gui_core::theme::ContainerStyle::NormalBackground);
+
+	let from_input = TextInput::new("YYYY-MM-DD", &state.from_value)
+		.on_input(|s| {
+			Interaction::WalletOperationExportProofsViewInteraction(LocalViewInteraction::From(s))
+		})
+		.size(DEFAULT_FONT_SIZE)
+		.padding(6)
+		.width(Length::Fixed(120.0))
+		.style(grin_gui_core::theme::TextInputStyle::AddonsQuery);
+
+	let from_input: Element<Interaction> = from_input.into();
+
+	let to_label = Text::new(localized_string("export-proofs-to"))
+		.size(DEFAULT_FONT_SIZE)
+		.horizontal_alignment(alignment::Horizontal::Left);
+
+	let to_label_container =
+		Container::new(to_label).style(grin_gui_core::theme::ContainerStyle::NormalBackground);
+
+	let to_input = TextInput::new("YYYY-MM-DD", &state.to_value)
+		.on_input(|s| {
+			Interaction::WalletOperationExportProofsViewInteraction(LocalViewInteraction::To(s))
+		})
+		.size(DEFAULT_FONT_SIZE)
+		.padding(6)
+		.width(Length::Fixed(120.0))
+		.style(grin_gui_core::theme::TextInputStyle::AddonsQuery);
+
+	let to_input: Element<Interaction> = to_input.into();
+
+	let date_row = Row::new()
+		.push(from_label_container)
+		.push(Space::with_width(Length::Fixed(DEFAULT_PADDING)))
+		.push(from_input.map(Message::Interaction))
+		.push(Space::with_width(Length::Fixed(unit_spacing)))
+		.push(to_label_container)
+		.push(Space::with_width(Length::Fixed(DEFAULT_PADDING)))
+		.push(to_input.map(Message::Interaction))
+		.align_items(Alignment::Center);
+
+	let mut column = Column::new()
+		.push(instruction_container)
+		.push(Space::new(Length::Fixed(0.0), Length::Fixed(unit_spacing)))
+		.push(date_row)
+		.push(Space::new(Length::Fixed(0.0), Length::Fixed(unit_spacing)));
+
+	if state.date_error {
+		let date_error = Text::new(localized_string("export-proofs-date-error"))
+			.size(DEFAULT_FONT_SIZE)
+			.horizontal_alignment(alignment::Horizontal::Left)
+			.style(grin_gui_core::theme::text::TextStyle::Warning);
+
+		column = column
+			.push(
+				Container::new(date_error)
+					.style(grin_gui_core::theme::ContainerStyle::NormalBackground),
+			)
+			.push(Space::new(Length::Fixed(0.0), Length::Fixed(unit_spacing)));
+	}
+
+	// Destination
+	let dir = match state.dir {
+		Some(ref d) => d.display().to_string(),
+		None => config.get_wallet_proof_dir().unwrap_or_default(),
+	};
+
+	let dir_label = Text::new(format!("{}:  ", localized_string("export-proofs-dir")))
+		.size(DEFAULT_FONT_SIZE)
+		.horizontal_alignment(alignment::Horizontal::Left);
+
+	let dir_label_container =
+		Container::new(dir_label).style(grin_gui_core::theme::ContainerStyle::NormalBackground);
+
+	let dir_value = Text::new(dir)
+		.size(DEFAULT_FONT_SIZE)
+		.horizontal_alignment(alignment::Horizontal::Left);
+
+	let dir_value_container =
+		Container::new(dir_value).style(grin_gui_core::theme::ContainerStyle::NormalBackground);
+
+	let choose_dir_label_container = Container::new(
+		Text::new(localized_string("export-proofs-choose-dir")).size(SMALLER_FONT_SIZE),
+	)
+	.height(Length::Fixed(14.0))
+	.center_y()
+	.center_x();
+
+	let choose_dir_button: Element<Interaction> = Button::new(choose_dir_label_container)
+		.style(grin_gui_core::theme::ButtonStyle::Bordered)
+		.on_press(Interaction::WalletOperationExportProofsViewInteraction(
+			LocalViewInteraction::ChooseDir,
+		))
+		.padding(2)
+		.into();
+
+	let dir_row = Row::new()
+		.push(dir_label_container)
+		.push(dir_value_container)
+		.push(Space::with_width(Length::Fixed(DEFAULT_PADDING)))
+		.push(choose_dir_button.map(Message::Interaction))
+		.align_items(Alignment::Center);
+
+	column = column
+		.push(dir_row)
+		.push(Space::new(Length::Fixed(0.0), Length::Fixed(unit_spacing)));
+
+	if let Some((exported, ref dir)) = state.exported {
+		let result = Text::new(format!(
+			"{} {}: {}",
+			exported,
+			localized_string("export-proofs-exported"),
+			dir.display()
+		))
+		.size(DEFAULT_FONT_SIZE);
+
+		column = column
+			.push(
+				Container::new(result)
+					.style(grin_gui_core::theme::ContainerStyle::NormalBackground),
+			)
+			.push(Space::new(Length::Fixed(0.0), Length::Fixed(row_spacing)));
+	}
+
+	if state.in_progress {
+		let progress = Text::new(format!(
+			"{}... {}%",
+			localized_string("export-proofs-in-progress"),
+			state.progress.unwrap_or(0)
+		))
+		.size(DEFAULT_FONT_SIZE);
+
+		column = column
+			.push(
+				Container::new(progress)
+					.style(grin_gui_core::theme::ContainerStyle::NormalBackground),
+			)
+			.push(Space::new(Length::Fixed(0.0), Length::Fixed(row_spacing)));
+	}
+
+	let export_button_label_container =
+		Container::new(Text::new(localized_string("export-proofs-submit")).size(DEFAULT_FONT_SIZE))
+			.width(button_width)
+			.height(button_height)
+			.center_x()
+			.center_y()
+			.align_x(alignment::Horizontal::Center);
+
+	let mut export_button = Button::new(export_button_label_container)
+		.style(grin_gui_core::theme::ButtonStyle::Primary);
+	if !state.in_progress {
+		export_button = export_button.on_press(
+			Interaction::WalletOperationExportProofsViewInteraction(LocalViewInteraction::Export),
+		);
+	}
+	let export_button: Element<Interaction> = export_button.into();
+
+	let cancel_button_label_container =
+		Container::new(Text::new(localized_string("back")).size(DEFAULT_FONT_SIZE))
+			.width(button_width)
+			.height(button_height)
+			.center_x()
+			.center_y()
+			.align_x(alignment::Horizontal::Center);
+
+	let cancel_button: Element<Interaction> = Button::new(cancel_button_label_container)
+		.style(grin_gui_core::theme::ButtonStyle::Primary)
+		.on_press(Interaction::WalletOperationExportProofsViewInteraction(
+			LocalViewInteraction::Back,
+		))
+		.into();
+
+	let export_container = Container::new(export_button.map(Message::Interaction)).padding(1);
+	let export_container = Container::new(export_container)
+		.style(grin_gui_core::theme::ContainerStyle::Segmented)
+		.padding(1);
+
+	let cancel_container = Container::new(cancel_button.map(Message::Interaction)).padding(1);
+	let cancel_container = Container::new(cancel_container)
+		.style(grin_gui_core::theme::ContainerStyle::Segmented)
+		.padding(1);
+
+	let button_row = Row::new()
+		.push(export_container)
+		.push(Space::new(Length::Fixed(unit_spacing), Length::Fixed(0.0)))
+		.push(cancel_container);
+
+	column = column
+		.push(Space::new(Length::Fixed(0.0), Length::Fixed(unit_spacing)))
+		.push(button_row);
+
+	let form_container = Container::new(column)
+		.width(Length::Fill)
+		.padding(iced::Padding::from([
+			0, // top
+			0, // right
+			0, // bottom
+			5, // left
+		]));
+
+	// form container should be scrollable in tiny windows
+	let scrollable = Scrollable::new(form_container)
+		.height(Length::Fill)
+		.style(grin_gui_core::theme::ScrollableStyle::Primary);
+
+	let content = Container::new(scrollable)
+		.width(Length::Fill)
+		.height(Length::Shrink)
+		.style(grin_gui_core::theme::ContainerStyle::NormalBackground);
+
+	let wrapper_column = Column::new()
+		.height(Length::Fill)
+		.push(header_container)
+		.push(content);
+
+	// Returns the final container.
+	Container::new(wrapper_column).padding(iced::Padding::from([
+		DEFAULT_PADDING, // top
+		DEFAULT_PADDING, // right
+		DEFAULT_PADDING, // bottom
+		DEFAULT_PADDING, // left
+	]))
+}
@@ -1,5 +1,5 @@
 use super::tx_list::{HeaderState, TxList, TxLogEntryWrap};
-use super::{action_menu, tx_list_display};
+use super::{accounts, action_menu, apply_tx, outputs, scan, tx_list_display};
 use super::{
 	chart::BalanceChart,
 	tx_list::{self, ExpandType},
@@ -8,12 +8,15 @@ use async_std::{prelude::FutureExt, task::current};
 use chrono::{DateTime, DurationRound, TimeZone, Utc};
 use grin_gui_core::error::GrinWalletInterfaceError;
 use grin_gui_core::node::SyncStatus;
+use grin_gui_core::wallet::contacts::AddressBook;
+use grin_gui_core::wallet::inbox::{self, InboxSlatepack};
 use grin_gui_core::wallet::SlatepackAddress;
 use grin_gui_core::{
 	config::{Config, Currency},
 	wallet::{InvoiceProof, RetrieveTxQueryArgs, TxLogEntry, TxLogEntryType},
 };
 use grin_gui_widgets::widget::header;
+use grin_gui_widgets::widget::qr_code::Data as QrData;
 use iced::Point;
 use iced_aw::Card;
 use iced_core::Widget;
@@ -24,7 +27,6 @@ use plotters::{
 use serde::{Deserialize, Serialize};
 use std::cell::RefCell;
 use std::fs::{self, File};
-use std::io::Read;
 use std::{collections::HashMap, path::PathBuf};
 
 use {
@@ -38,10 +40,12 @@ use {
 	crate::Result,
 	anyhow::Context,
 	grin_gui_core::theme::{
-		Button, Column, Container, Element, Header, PickList, Row, Scrollable, TableRow, Text,
-		TextInput,
+		Button, Column, Container, Element, Header, PickList, QrCode, Row, Scrollable, TableRow,
+		Text, TextInput,
+	},
+	grin_gui_core::wallet::{
+		subscriber::WalletMessage, StatusMessage, WalletInfo, WalletInterface,
 	},
-	grin_gui_core::wallet::{StatusMessage, WalletInfo, WalletInterface},
 	grin_gui_core::{
 		node::{amount_to_hr_string, ServerStats},
 		theme::ColorPalette,
@@ -57,13 +61,25 @@ pub struct StateContainer {
 	pub tx_list_display_state: tx_list_display::StateContainer,
 	pub address_value: Option<String>,
 	pub address: Option<SlatepackAddress>,
+	address_qr: Option<QrData>,
+	show_address_qr: bool,
 
 	wallet_info: Option<WalletInfo>,
-	wallet_status: String,
+	pub wallet_status: String,
 	last_summary_update: chrono::DateTime<chrono::Local>,
+	last_price_update: chrono::DateTime<chrono::Local>,
 	tx_header_state: HeaderState,
 	node_status: Option<ServerStats>,
 	pub node_synched: bool,
+	// A rescan holds the wallet for its whole duration, so anything needing
+	// write access to the wallet is disabled until it's done
+	pub scan_in_progress: bool,
+	// Remote wallets scan on their own host, so only local scans can be cancelled
+	pub scan_cancellable: bool,
+	// Slates received by the foreign listener, None while it isn't running
+	listener_received: Option<u64>,
+	// Slatepacks waiting in the wallet's inbox folder
+	pub inbox: Vec<InboxSlatepack>,
 
 	cursor_index: Option<usize>,
 	caption_index: Option<usize>,
@@ -82,6 +98,23 @@ impl StateContainer {
 			None => localized_string("unknown"),
 		}
 	}
+	/// Clear everything tied to the active account so it's fetched again after switching
+	pub fn reset_account_data(&mut self) {
+		self.wallet_info = None;
+		self.address_value = None;
+		self.address = None;
+		self.address_qr = None;
+		self.show_address_qr = false;
+		self.last_summary_update = Default::default();
+	}
+	/// Most recent daily price in the configured currency at or before `date`
+	pub fn price_at(&self, date: DateTime<Utc>) -> Option<f64> {
+		self.price_history
+			.iter()
+			.filter(|(d, _)| **d <= date)
+			.max_by_key(|(d, _)| **d)
+			.map(|(_, p)| *p)
+	}
 	pub fn update_node_status(&mut self, stats: &ServerStats) {
 		self.node_status = Some(stats.clone());
 		match stats.sync_status {
@@ -95,12 +128,23 @@ impl StateContainer {
 pub enum LocalViewInteraction {
 	Back,
 	Submit,
+	ShowAccounts,
+	ShowScan,
+	ShowOutputs,
+	ShowContacts,
+	ShowVerifyProof,
+	ShowRecoveryPhrase,
 	/// was updated from node, info
 	WalletInfoUpdateSuccess(bool, WalletInfo),
 	WalletInfoUpdateFailure(Arc<RwLock<Option<anyhow::Error>>>),
 	WalletSlatepackAddressUpdateSuccess((String, SlatepackAddress)),
+	ToggleAddressQr,
+	OpenInboxSlatepack(PathBuf),
+	DismissInboxSlatepack(PathBuf),
 	WalletCloseError(Arc<RwLock<Option<anyhow::Error>>>),
 	WalletCloseSuccess,
+	Lock,
+	WalletLocked,
 	CancelTx(u32, String),
 	TxDetails(TxLogEntryWrap),
 	TxProof(TxLogEntryWrap),
@@ -114,15 +158,30 @@ pub enum LocalViewInteraction {
 	MouseIndex(usize, usize),
 	MouseExit,
 	UpdatePrices,
+	PricesUpdated(HashMap<DateTime<Utc>, f64>),
+	PricesUpdateError(Arc<RwLock<Option<anyhow::Error>>>),
 }
 
-/// update the historical price data
-fn update_prices(state: &mut StateContainer, currency: Currency) -> Result<()> {
+/// Command fetching the historical price data
+fn update_prices_command(currency: Currency) -> Command<Message> {
 	// if we are using grin, we don't need to update the price history
 	if currency == Currency::GRIN {
-		return Ok(());
+		return Command::none();
 	}
 
+	Command::perform(fetch_prices(currency), |r| {
+		match r.context("Failed to update price history") {
+			Ok(prices) => Message::Interaction(Interaction::WalletOperationHomeViewInteraction(
+				LocalViewInteraction::PricesUpdated(prices),
+			)),
+			Err(e) => Message::Interaction(Interaction::WalletOperationHomeViewInteraction(
+				LocalViewInteraction::PricesUpdateError(Arc::new(RwLock::new(Some(e)))),
+			)),
+		}
+	})
+}
+
+async fn fetch_prices(currency: Currency) -> Result<HashMap<DateTime<Utc>, f64>> {
 	#[derive(Deserialize, Serialize, Debug)]
 	struct Price {
 		time: u64,
@@ -137,22 +196,65 @@ fn update_prices(state: &mut StateContainer, currency: Currency) -> Result<()> {
 	// pull price history from coingecko
 	// TODO this url should not be hardcoded
 	let price_history_url = format!("https://api.coingecko.com/api/v3/coins/grin/market_chart?vs_currency={}&days=11430&interval=daily", currency.shortname());
-	let mut res = reqwest::blocking::get(price_history_url)?;
-	let mut body = String::new();
-	res.read_to_string(&mut body)?;
+	let body = reqwest::get(price_history_url).await?.text().await?;
 
 	//debug!("price history data: {:#?}", body);
-	let history = serde_json::from_str::<PriceHistory>(&body).unwrap();
+	let history = serde_json::from_str::<PriceHistory>(&body)?;
 
 	let mut prices = std::collections::HashMap::new();
 	for price in history.prices {
-		let date_time = Utc.timestamp_millis_opt(price.time as i64).unwrap();
-		prices.insert(date_time, price.price);
+		if let Some(date_time) = Utc.timestamp_millis_opt(price.time as i64).single() {
+			prices.insert(date_time, price.price);
+		}
 	}
+	Ok(prices)
+}
+
+/// Command closing the wallet once it's been left idle for longer than configured
+pub fn idle_lock_command(grin_gui: &mut GrinGui) -> Option<Command<Message>> {
+	let lock_after = grin_gui.config.get_wallet_idle_lock()?;
+	if grin_gui.last_activity.elapsed() < lock_after
+		|| !grin_gui
+			.wallet_interface
+			.try_read()
+			.map_or(false, |w| w.wallet_is_open())
+	{
+		return None;
+	}
+	log::debug!("Locking wallet after {:?} idle", lock_after);
+	// Don't fire again while the wallet is closing
+	grin_gui.last_activity = std::time::Instant::now();
+	let fut = move || async {};
+	Some(Command::perform(fut(), |_| {
+		Message::Interaction(Interaction::WalletOperationHomeViewInteraction(
+			LocalViewInteraction::Lock,
+		))
+	}))
+}
 
-	// update the price hashmap in the state
-	state.price_history = prices;
-	Ok(())
+/// Blank out everything read from the wallet once it's closed, so none of it shows
+/// up again before a wallet is opened
+pub fn clear_wallet_data(grin_gui: &mut GrinGui) {
+	let operation_state = &mut grin_gui.wallet_state.operation_state;
+	operation_state.home_state.reset_account_data();
+	operation_state.home_state.listener_received = None;
+	operation_state.home_state.scan_in_progress = false;
+	operation_state.home_state.scan_cancellable = false;
+	operation_state.home_state.tx_list_display_state = Default::default();
+	operation_state.accounts_state = Default::default();
+	operation_state.tx_detail_state = Default::default();
+	operation_state.tx_proof_state = Default::default();
+	operation_state.show_slatepack_state = Default::default();
+	operation_state.recovery_phrase_state = Default::default();
+	operation_state.create_tx_state = Default::default();
+	operation_state.create_tx_contracts_state = Default::default();
+	operation_state.apply_tx_state = Default::default();
+	operation_state.verify_proof_state = Default::default();
+	operation_state.export_proofs_state = Default::default();
+	// Closing the wallet stopped its listener
+	grin_gui.wallet_settings_state.listener = None;
+
+	operation_state.mode = crate::gui::element::wallet::operation::Mode::Open;
 }
 
 // Okay to modify state and access wallet here
@@ -160,54 +262,57 @@ pub fn handle_tick<'a>(
 	grin_gui: &mut GrinGui,
 	time: chrono::DateTime<chrono::Local>,
 ) -> Result<Command<Message>> {
-	{
-		let w = grin_gui.wallet_interface.read().unwrap();
+	// Running jobs report their progress through the wallet worker
+	let worker_status = grin_gui.wallet_worker.status();
+	grin_gui
+		.wallet_state
+		.operation_state
+		.export_proofs_state
+		.update_progress(&worker_status);
+
+	let listener_received = {
+		// Held by the worker while it opens or closes the wallet, check again next tick
+		let w = match grin_gui.wallet_interface.try_read() {
+			Ok(w) => w,
+			Err(_) => return Ok(Command::none()),
+		};
 		if !w.wallet_is_open() {
 			return Ok(Command::none());
 		}
-	}
-	let messages = WalletInterface::get_wallet_updater_status(grin_gui.wallet_interface.clone())?;
+		w.foreign_listener_received()
+	};
 	let state = &mut grin_gui.wallet_state.operation_state.home_state;
-	let last_message = messages.get(0);
-	if let Some(m) = last_message {
-		state.wallet_status = match m {
-			StatusMessage::UpdatingOutputs(s) => format!("{}", s),
-			StatusMessage::UpdatingTransactions(s) => format!("{}", s),
-			StatusMessage::FullScanWarn(s) => format!("{}", s),
-			StatusMessage::Scanning(s, m) => format!("Scanning - {}% complete", m),
-			StatusMessage::ScanningComplete(s) => format!("{}", s),
-			StatusMessage::UpdateWarning(s) => format!("{}", s),
-		}
-	}
-
-	// don't display status if node is still synching
-	if !state.node_synched {
-		state.wallet_status = localized_string("awaiting-sync");
-	}
+	state.listener_received = listener_received;
 
 	// calls to API should be limited to once per minute
-	if time - state.last_summary_update
+	let prices = if time - state.last_price_update
 		> chrono::Duration::from_std(std::time::Duration::from_secs(60)).unwrap()
 	{
-		update_prices(state, grin_gui.config.currency)?;
+		state.last_price_update = chrono::Local::now();
+		update_prices_command(grin_gui.config.currency)
+	} else {
+		Command::none()
+	};
+
+	// Wallet calls would only queue up behind a scan or a long running operation
+	if !grin_gui.wallet_worker.is_idle() {
+		return Ok(prices);
 	}
 
-	if time - state.last_summary_update
-		> chrono::Duration::from_std(std::time::Duration::from_secs(10)).unwrap()
+	// Changes come in through the wallet subscription, only the first summary is fetched here
+	if state.wallet_info.is_none()
+		&& time - state.last_summary_update
+			> chrono::Duration::from_std(std::time::Duration::from_secs(10)).unwrap()
 	{
 		state.last_summary_update = chrono::Local::now();
-
-		let mut query_args = RetrieveTxQueryArgs::default();
-
-		query_args.exclude_cancelled = Some(true);
-		query_args.include_outstanding_only = Some(true);
-
 		let w = grin_gui.wallet_interface.clone();
 		let node_synched = state.node_synched;
 
-		let fut = move || WalletInterface::get_wallet_info(w.clone(), node_synched); //.join(WalletInterface::get_txs(w, Some(query_args)));
+		let fut = grin_gui
+			.wallet_worker
+			.run(WalletInterface::get_wallet_info(w.clone(), node_synched));
 
-		return Ok(Command::perform(fut(), |wallet_info_res| {
+		let summary = Command::perform(fut, |wallet_info_res| {
 			if wallet_info_res.is_err() {
 				let e = wallet_info_res
 					.context("Failed to retrieve wallet info status")
@@ -217,19 +322,20 @@ pub fn handle_tick<'a>(
 				));
 			}
 			let (node_success, wallet_info) = wallet_info_res.unwrap();
-			//let (_, txs) = txs_res.unwrap();
 			Message::Interaction(Interaction::WalletOperationHomeViewInteraction(
-				//LocalViewInteraction::WalletInfoUpdateSuccess(node_success, wallet_info, txs),
 				LocalViewInteraction::WalletInfoUpdateSuccess(node_success, wallet_info),
 			))
-		}));
+		});
+		return Ok(Command::batch(vec![prices, summary]));
 	}
 	// If slatepack address is not filled out, go get it
 	if state.address_value.is_none() {
 		let w = grin_gui.wallet_interface.clone();
 
-		let fut = move || WalletInterface::get_slatepack_address(w.clone());
-		return Ok(Command::perform(fut(), |get_slatepack_address_res| {
+		let fut = grin_gui
+			.wallet_worker
+			.run(WalletInterface::get_slatepack_address(w.clone()));
+		let address = Command::perform(fut, |get_slatepack_address_res| {
 			if get_slatepack_address_res.is_err() {
 				let e = get_slatepack_address_res
 					.context("Failed to retrieve wallet slatepack address")
@@ -243,9 +349,50 @@ pub fn handle_tick<'a>(
 					get_slatepack_address_res.unwrap(),
 				),
 			))
-		}));
+		});
+		return Ok(Command::batch(vec![prices, address]));
 	}
 
+	Ok(prices)
+}
+
+/// Changes of the open wallet, streamed in by the wallet subscription
+pub fn handle_wallet_message(
+	grin_gui: &mut GrinGui,
+	message: WalletMessage,
+) -> Result<Command<Message>> {
+	let state = &mut grin_gui.wallet_state.operation_state.home_state;
+	match message {
+		WalletMessage::UpdaterStatus(m) => {
+			state.wallet_status = match m {
+				StatusMessage::UpdatingOutputs(s) => format!("{}", s),
+				StatusMessage::UpdatingTransactions(s) => format!("{}", s),
+				StatusMessage::FullScanWarn(s) => format!("{}", s),
+				StatusMessage::Scanning(s, m) => format!("Scanning - {}% complete", m),
+				StatusMessage::ScanningComplete(s) => format!("{}", s),
+				StatusMessage::UpdateWarning(s) => format!("{}", s),
+			};
+			// don't display status if node is still synching
+			if !state.node_synched {
+				state.wallet_status = localized_string("awaiting-sync");
+			}
+		}
+		WalletMessage::WalletInfo(node_success, wallet_info) => {
+			return handle_message(
+				grin_gui,
+				LocalViewInteraction::WalletInfoUpdateSuccess(node_success, wallet_info),
+			);
+		}
+		WalletMessage::TxsChanged(txs) => {
+			debug!("{} transactions changed", txs.len());
+			state.tx_list_display_state.apply_changed_txs(txs);
+		}
+		WalletMessage::InboxChanged(inbox) => {
+			debug!("{} slatepacks waiting in inbox", inbox.len());
+			state.inbox = inbox;
+		}
+		WalletMessage::None => {}
+	}
 	Ok(Command::none())
 }
 
@@ -256,7 +403,16 @@ pub fn handle_message<'a>(
 	let state = &mut grin_gui.wallet_state.operation_state.home_state;
 	match message {
 		LocalViewInteraction::UpdatePrices => {
-			update_prices(state, grin_gui.config.currency)?;
+			return Ok(update_prices_command(grin_gui.config.currency));
+		}
+		LocalViewInteraction::PricesUpdated(prices) => {
+			state.price_history = prices;
+		}
+		LocalViewInteraction::PricesUpdateError(err) => {
+			// Only the chart misses out, not worth interrupting the user for
+			if let Some(e) = err.write().unwrap().take() {
+				log_error(&e);
+			}
 		}
 		LocalViewInteraction::MouseIndex(index1, index2) => {
 			state.cursor_index = Some(index1);
@@ -267,8 +423,12 @@ pub fn handle_message<'a>(
 			state.caption_index = None;
 		}
 		LocalViewInteraction::Back => {
+			// Nothing still waiting to run should find the wallet closed, and a scan is stopped
+			grin_gui.wallet_worker.cancel_all();
 			let wallet_interface = grin_gui.wallet_interface.clone();
-			let fut = WalletInterface::close_wallet(wallet_interface);
+			let fut = grin_gui
+				.wallet_worker
+				.run(WalletInterface::close_wallet(wallet_interface));
 
 			return Ok(Command::perform(fut, |r| {
 				match r.context("Failed to close wallet") {
@@ -286,6 +446,42 @@ pub fn handle_message<'a>(
 			}));
 		}
 		LocalViewInteraction::Submit => {}
+		LocalViewInteraction::ShowAccounts => {
+			grin_gui.wallet_state.operation_state.mode =
+				crate::gui::element::wallet::operation::Mode::Accounts;
+			return Ok(accounts::refresh_command());
+		}
+		LocalViewInteraction::ShowOutputs => {
+			grin_gui
+				.wallet_state
+				.operation_state
+				.outputs_state
+				.opened_from_create = false;
+			grin_gui.wallet_state.operation_state.mode =
+				crate::gui::element::wallet::operation::Mode::Outputs;
+			return Ok(outputs::show_command());
+		}
+		LocalViewInteraction::ShowContacts => {
+			grin_gui
+				.wallet_state
+				.operation_state
+				.contacts_state
+				.clear_form();
+			grin_gui.wallet_state.operation_state.mode =
+				crate::gui::element::wallet::operation::Mode::Contacts;
+		}
+		LocalViewInteraction::ShowVerifyProof => {
+			grin_gui.wallet_state.operation_state.mode =
+				crate::gui::element::wallet::operation::Mode::VerifyProof;
+		}
+		LocalViewInteraction::ShowRecoveryPhrase => {
+			grin_gui.wallet_state.operation_state.mode =
+				crate::gui::element::wallet::operation::Mode::RecoveryPhrase;
+		}
+		LocalViewInteraction::ShowScan => {
+			grin_gui.wallet_state.operation_state.mode =
+				crate::gui::element::wallet::operation::Mode::Scan;
+		}
 		LocalViewInteraction::WalletInfoUpdateSuccess(node_success, wallet_info) => {
 			debug!(
 				"Update Wallet Info Summary: {}, {:?}",
@@ -339,17 +535,37 @@ pub fn handle_message<'a>(
 		LocalViewInteraction::WalletCloseSuccess => {
 			// Also blank out all relevant info first, and perform all shutdown
 			// so it doesn't appear when opening another wallet
-			state.wallet_info = None;
-			state.address_value = None;
-			state.address = None;
+			clear_wallet_data(grin_gui);
+		}
+		LocalViewInteraction::Lock => {
+			grin_gui.wallet_worker.cancel_all();
+			let wallet_interface = grin_gui.wallet_interface.clone();
+			let fut = grin_gui
+				.wallet_worker
+				.run(WalletInterface::close_wallet(wallet_interface));
+
+			return Ok(Command::perform(fut, |r| {
+				match r.context("Failed to lock wallet") {
+					Ok(()) => {
+						Message::Interaction(Interaction::WalletOperationHomeViewInteraction(
+							LocalViewInteraction::WalletLocked,
+						))
+					}
+					Err(e) => {
+						Message::Interaction(Interaction::WalletOperationHomeViewInteraction(
+							LocalViewInteraction::WalletCloseError(Arc::new(RwLock::new(Some(e)))),
+						))
+					}
+				}
+			}));
+		}
+		LocalViewInteraction::WalletLocked => {
+			clear_wallet_data(grin_gui);
 			grin_gui
 				.wallet_state
 				.operation_state
-				.tx_detail_state
-				.current_tx = None;
-
-			grin_gui.wallet_state.operation_state.mode =
-				crate::gui::element::wallet::operation::Mode::Open;
+				.open_state
+				.wallet_message = localized_string("open-wallet-locked");
 		}
 		LocalViewInteraction::WalletCloseError(err) => {
 			grin_gui.error = err.write().unwrap().take();
@@ -358,16 +574,48 @@ pub fn handle_message<'a>(
 			}
 		}
 		LocalViewInteraction::WalletSlatepackAddressUpdateSuccess((address_string, address)) => {
+			state.address_qr = match QrData::new(&address_string) {
+				Ok(d) => Some(d),
+				Err(e) => {
+					log::error!("Unable to encode slatepack address QR code: {:?}", e);
+					None
+				}
+			};
 			state.address_value = Some(address_string);
 			state.address = Some(address);
 		}
+		LocalViewInteraction::ToggleAddressQr => {
+			state.show_address_qr = !state.show_address_qr;
+		}
+		LocalViewInteraction::OpenInboxSlatepack(path) => {
+			debug!("Open inbox slatepack: {:?}", path);
+			grin_gui.wallet_state.operation_state.mode =
+				crate::gui::element::wallet::operation::Mode::ApplyTx;
+			return apply_tx::handle_message(
+				grin_gui,
+				apply_tx::LocalViewInteraction::LoadInboxSlatepack(path),
+			);
+		}
+		LocalViewInteraction::DismissInboxSlatepack(path) => {
+			debug!("Dismiss inbox slatepack: {:?}", path);
+			state.inbox.retain(|s| s.path != path);
+			if let Err(e) = inbox::archive_file(&path) {
+				grin_gui.error = Some(anyhow::Error::from(e).context(format!(
+					"Failed to archive inbox slatepack {}",
+					path.display()
+				)));
+				if let Some(e) = grin_gui.error.as_ref() {
+					log_error(e);
+				}
+			}
+		}
 		LocalViewInteraction::TxDetails(tx_log_entry_wrap) => {
 			log::debug!("Interaction::WalletOperationHomeViewInteraction::TxDetails");
 			grin_gui
 				.wallet_state
 				.operation_state
 				.tx_detail_state
-				.current_tx = Some(tx_log_entry_wrap.tx);
+				.set_tx(tx_log_entry_wrap.tx);
 			grin_gui.wallet_state.operation_state.mode =
 				crate::gui::element::wallet::operation::Mode::TxDetail;
 		}
@@ -376,11 +624,14 @@ pub fn handle_message<'a>(
 			grin_gui.error.take();
 
 			let w = grin_gui.wallet_interface.clone();
-			let fut = move || {
-				WalletInterface::retrieve_payment_proof_invoice(w, Some(tx_log_entry_wrap.tx.id))
-			};
+			let fut = grin_gui
+				.wallet_worker
+				.run(WalletInterface::retrieve_payment_proof_invoice(
+					w,
+					Some(tx_log_entry_wrap.tx.id),
+				));
 
-			return Ok(Command::perform(fut(), |r| {
+			return Ok(Command::perform(fut, |r| {
 				match r.context("Failed to Retrieve Invoice Proof") {
 					Ok(ret) => {
 						Message::Interaction(Interaction::WalletOperationHomeViewInteraction(
@@ -405,9 +656,11 @@ pub fn handle_message<'a>(
 
 			let w = grin_gui.wallet_interface.clone();
 
-			let fut = move || WalletInterface::cancel_tx(w, id);
+			let fut = grin_gui
+				.wallet_worker
+				.run(WalletInterface::cancel_tx(w, id));
 
-			return Ok(Command::perform(fut(), |r| {
+			return Ok(Command::perform(fut, |r| {
 				match r.context("Failed to Cancel Transaction") {
 					Ok(ret) => {
 						Message::Interaction(Interaction::WalletOperationHomeViewInteraction(
@@ -479,7 +732,7 @@ pub fn handle_message<'a>(
 						.wallet_state
 						.operation_state
 						.show_slatepack_state
-						.encrypted_slate = Some(s.to_string());
+						.set_encrypted_slate(Some(s.to_string()));
 					// Just go to create tx success screen for now
 					grin_gui.wallet_state.operation_state.mode =
 						crate::gui::element::wallet::operation::Mode::ShowSlatepack;
@@ -496,7 +749,12 @@ pub fn handle_message<'a>(
 	Ok(Command::none())
 }
 
-pub fn data_container<'a>(config: &'a Config, state: &'a StateContainer) -> Container<'a, Message> {
+pub fn data_container<'a>(
+	config: &'a Config,
+	state: &'a StateContainer,
+	accounts_state: &'a accounts::StateContainer,
+	address_book: &'a AddressBook,
+) -> Container<'a, Message> {
 	// Buttons to perform operations go here, but empty container for now
 	let operations_menu = action_menu::data_container(config, &state.action_menu_state, &state);
 
@@ -561,7 +819,11 @@ pub fn data_container<'a>(config: &'a Config, state: &'a StateContainer) -> Cont
 	let title_container =
 		Container::new(title).style(grin_gui_core::theme::ContainerStyle::BrightBackground);
 
-	let subtitle = Text::new(wallet_name).size(SMALLER_FONT_SIZE);
+	let subtitle = Text::new(format!(
+		"{} - {}",
+		wallet_name, accounts_state.active_account
+	))
+	.size(SMALLER_FONT_SIZE);
 	let subtitle_container = Container::new(subtitle)
 		.style(grin_gui_core::theme::ContainerStyle::NormalBackground)
 		.padding(iced::Padding::from([
@@ -578,6 +840,7 @@ pub fn data_container<'a>(config: &'a Config, state: &'a StateContainer) -> Cont
 			.center_y()
 			.center_x();
 
+	// Closing stops a running scan first
 	let close_wallet_button: Element<Interaction> = Button::new(close_wallet_label_container)
 		.style(grin_gui_core::theme::ButtonStyle::Bordered)
 		.on_press(Interaction::WalletOperationHomeViewInteraction(
@@ -586,9 +849,109 @@ pub fn data_container<'a>(config: &'a Config, state: &'a StateContainer) -> Cont
 		.padding(2)
 		.into();
 
+	let accounts_label_container =
+		Container::new(Text::new(localized_string("accounts")).size(SMALLER_FONT_SIZE))
+			.height(Length::Fixed(14.0))
+			.center_y()
+			.center_x();
+
+	let mut accounts_button = Button::new(accounts_label_container)
+		.style(grin_gui_core::theme::ButtonStyle::Bordered)
+		.padding(2);
+	if !state.scan_in_progress {
+		accounts_button = accounts_button.on_press(
+			Interaction::WalletOperationHomeViewInteraction(LocalViewInteraction::ShowAccounts),
+		);
+	}
+	let accounts_button: Element<Interaction> = accounts_button.into();
+
+	let outputs_label_container =
+		Container::new(Text::new(localized_string("outputs")).size(SMALLER_FONT_SIZE))
+			.height(Length::Fixed(14.0))
+			.center_y()
+			.center_x();
+
+	let outputs_button: Element<Interaction> = Button::new(outputs_label_container)
+		.style(grin_gui_core::theme::ButtonStyle::Bordered)
+		.on_press(Interaction::WalletOperationHomeViewInteraction(
+			LocalViewInteraction::ShowOutputs,
+		))
+		.padding(2)
+		.into();
+
+	let contacts_label_container =
+		Container::new(Text::new(localized_string("contacts")).size(SMALLER_FONT_SIZE))
+			.height(Length::Fixed(14.0))
+			.center_y()
+			.center_x();
+
+	let contacts_button: Element<Interaction> = Button::new(contacts_label_container)
+		.style(grin_gui_core::theme::ButtonStyle::Bordered)
+		.on_press(Interaction::WalletOperationHomeViewInteraction(
+			LocalViewInteraction::ShowContacts,
+		))
+		.padding(2)
+		.into();
+
+	let verify_proof_label_container =
+		Container::new(Text::new(localized_string("verify-proof")).size(SMALLER_FONT_SIZE))
+			.height(Length::Fixed(14.0))
+			.center_y()
+			.center_x();
+
+	let verify_proof_button: Element<Interaction> = Button::new(verify_proof_label_container)
+		.style(grin_gui_core::theme::ButtonStyle::Bordered)
+		.on_press(Interaction::WalletOperationHomeViewInteraction(
+			LocalViewInteraction::ShowVerifyProof,
+		))
+		.padding(2)
+		.into();
+
+	let recovery_phrase_label_container =
+		Container::new(Text::new(localized_string("recovery-phrase")).size(SMALLER_FONT_SIZE))
+			.height(Length::Fixed(14.0))
+			.center_y()
+			.center_x();
+
+	let recovery_phrase_button: Element<Interaction> = Button::new(recovery_phrase_label_container)
+		.style(grin_gui_core::theme::ButtonStyle::Bordered)
+		.on_press(Interaction::WalletOperationHomeViewInteraction(
+			LocalViewInteraction::ShowRecoveryPhrase,
+		))
+		.padding(2)
+		.into();
+
+	let rescan_label_container =
+		Container::new(Text::new(localized_string("rescan")).size(SMALLER_FONT_SIZE))
+			.height(Length::Fixed(14.0))
+			.center_y()
+			.center_x();
+
+	let mut rescan_button = Button::new(rescan_label_container)
+		.style(grin_gui_core::theme::ButtonStyle::Bordered)
+		.padding(2);
+	if !state.scan_in_progress {
+		rescan_button = rescan_button.on_press(Interaction::WalletOperationHomeViewInteraction(
+			LocalViewInteraction::ShowScan,
+		));
+	}
+	let rescan_button: Element<Interaction> = rescan_button.into();
+
 	let subtitle_row = Row::new()
 		.push(subtitle_container)
 		.push(Space::with_width(Length::Fixed(2.0)))
+		.push(accounts_button.map(Message::Interaction))
+		.push(Space::with_width(Length::Fixed(2.0)))
+		.push(outputs_button.map(Message::Interaction))
+		.push(Space::with_width(Length::Fixed(2.0)))
+		.push(contacts_button.map(Message::Interaction))
+		.push(Space::with_width(Length::Fixed(2.0)))
+		.push(verify_proof_button.map(Message::Interaction))
+		.push(Space::with_width(Length::Fixed(2.0)))
+		.push(recovery_phrase_button.map(Message::Interaction))
+		.push(Space::with_width(Length::Fixed(2.0)))
+		.push(rescan_button.map(Message::Interaction))
+		.push(Space::with_width(Length::Fixed(2.0)))
 		.push(close_wallet_button.map(Message::Interaction));
 
 	let address_label = Text::new(format!(
@@ -641,20 +1004,46 @@ pub fn data_container<'a>(config: &'a Config, state: &'a StateContainer) -> Cont
 		.padding(2)
 		.into();
 
+	let address_qr_label_container =
+		Container::new(Text::new(localized_string("qr-code")).size(SMALLER_FONT_SIZE))
+			.height(Length::Fixed(14.0))
+			.width(Length::Fixed(30.0))
+			.center_y()
+			.center_x();
+
+	let mut address_qr_button = Button::new(address_qr_label_container)
+		.style(grin_gui_core::theme::ButtonStyle::Bordered)
+		.padding(2);
+
+	if state.address_qr.is_some() {
+		address_qr_button = address_qr_button.on_press(
+			Interaction::WalletOperationHomeViewInteraction(LocalViewInteraction::ToggleAddressQr),
+		);
+	}
+
+	let address_qr_button: Element<Interaction> = address_qr_button.into();
+
 	let address_row = Row::new()
 		.push(address_label_container)
 		.push(Space::with_width(Length::Fixed(2.0)))
 		.push(address_container)
 		.push(Space::with_width(Length::Fixed(2.0)))
-		.push(copy_address_button.map(Message::Interaction));
-
-	let title_container = Container::new(
-		Column::new()
-			.push(title_container)
-			.push(subtitle_row)
-			.push(address_row),
-	)
-	.padding(iced::Padding::from([
+		.push(copy_address_button.map(Message::Interaction))
+		.push(Space::with_width(Length::Fixed(2.0)))
+		.push(address_qr_button.map(Message::Interaction));
+
+	let mut title_column = Column::new()
+		.push(title_container)
+		.push(subtitle_row)
+		.push(address_row);
+
+	if let (true, Some(address_qr)) = (state.show_address_qr, state.address_qr.as_ref()) {
+		title_column = title_column
+			.push(Space::with_height(Length::Fixed(5.0)))
+			.push(QrCode::new(address_qr).cell_size(3.0));
+	}
+
+	let title_container = Container::new(title_column).padding(iced::Padding::from([
 		0, // top
 		0, // right
 		0, // bottom
@@ -848,21 +1237,59 @@ pub fn data_container<'a>(config: &'a Config, state: &'a StateContainer) -> Cont
 		.horizontal_alignment(alignment::Horizontal::Right)
 		.vertical_alignment(alignment::Vertical::Center);
 
-	let status_container_contents = Row::new()
+	let mut status_container_contents = Row::new()
 		.push(Space::new(
 			Length::Fixed(DEFAULT_PADDING),
 			Length::Fixed(0.0),
 		))
 		.push(status_container_node_state_label_text)
-		.push(status_container_node_state_text)
+		.push(status_container_node_state_text);
+
+	if state.listener_received.is_some() {
+		let listener_text = Text::new(localized_string("foreign-listener-status"))
+			.size(DEFAULT_FONT_SIZE)
+			.height(Length::Fill)
+			.horizontal_alignment(alignment::Horizontal::Left)
+			.vertical_alignment(alignment::Vertical::Center);
+		status_container_contents = status_container_contents
+			.push(Space::with_width(Length::Fixed(DEFAULT_PADDING)))
+			.push(listener_text);
+	}
+
+	status_container_contents = status_container_contents
 		.push(Space::new(Length::Fill, Length::Fill))
 		.push(status_container_label_text)
 		.push(status_container_separator_text)
-		.push(status_container_status_text)
-		.push(Space::new(
-			Length::Fixed(DEFAULT_PADDING),
-			Length::Fixed(0.0),
-		));
+		.push(status_container_status_text);
+
+	if state.scan_in_progress && state.scan_cancellable {
+		let cancel_scan_label_container =
+			Container::new(Text::new(localized_string("cancel")).size(SMALLER_FONT_SIZE))
+				.height(Length::Fixed(14.0))
+				.center_y()
+				.center_x();
+
+		let cancel_scan_button: Element<Interaction> = Button::new(cancel_scan_label_container)
+			.style(grin_gui_core::theme::ButtonStyle::Bordered)
+			.on_press(Interaction::WalletOperationScanViewInteraction(
+				scan::LocalViewInteraction::CancelScan,
+			))
+			.padding(2)
+			.into();
+
+		status_container_contents = status_container_contents
+			.push(Space::with_width(Length::Fixed(DEFAULT_PADDING)))
+			.push(
+				Container::new(cancel_scan_button.map(Message::Interaction))
+					.height(Length::Fill)
+					.center_y(),
+			);
+	}
+
+	let status_container_contents = status_container_contents.push(Space::new(
+		Length::Fixed(DEFAULT_PADDING),
+		Length::Fixed(0.0),
+	));
 
 	let status_container = Container::new(status_container_contents)
 		.style(grin_gui_core::theme::ContainerStyle::BrightForeground)
@@ -874,9 +1301,88 @@ pub fn data_container<'a>(config: &'a Config, state: &'a StateContainer) -> Cont
 		.align_items(Alignment::Center)
 		.spacing(25);
 
+	// Slatepacks waiting in the inbox folder
+	let mut inbox_column = Column::new().spacing(5);
+	if !state.inbox.is_empty() {
+		let inbox_label = Text::new(localized_string("inbox-title")).size(DEFAULT_FONT_SIZE);
+		inbox_column = inbox_column.push(
+			Container::new(inbox_label)
+				.style(grin_gui_core::theme::ContainerStyle::BrightBackground),
+		);
+	}
+
+	for s in state.inbox.iter() {
+		let desc = match &s.decoded {
+			Ok((slate, tx)) => {
+				let tx_desc = match tx {
+					Some(t) => format!("{} {}", localized_string("inbox-tx-match"), t.id),
+					None => localized_string("inbox-tx-new"),
+				};
+				format!(
+					"{} - {} - {}",
+					amount_to_hr_string(slate.amount, false),
+					slate.state,
+					tx_desc
+				)
+			}
+			Err(e) => format!("{}: {}", localized_string("inbox-undecodable"), e),
+		};
+
+		let name = Text::new(s.file_name()).size(SMALLER_FONT_SIZE);
+		let desc = Text::new(desc).size(SMALLER_FONT_SIZE);
+
+		let open_label_container =
+			Container::new(Text::new(localized_string("inbox-open")).size(SMALLER_FONT_SIZE))
+				.height(Length::Fixed(14.0))
+				.center_y()
+				.center_x();
+
+		let mut open_button = Button::new(open_label_container)
+			.style(grin_gui_core::theme::ButtonStyle::Bordered)
+			.padding(2);
+
+		// Applying needs write access, which a rescan holds
+		if s.decoded.is_ok() && !state.scan_in_progress {
+			open_button = open_button.on_press(Interaction::WalletOperationHomeViewInteraction(
+				LocalViewInteraction::OpenInboxSlatepack(s.path.clone()),
+			));
+		}
+
+		let open_button: Element<Interaction> = open_button.into();
+
+		let dismiss_label_container =
+			Container::new(Text::new(localized_string("inbox-dismiss")).size(SMALLER_FONT_SIZE))
+				.height(Length::Fixed(14.0))
+				.center_y()
+				.center_x();
+
+		let dismiss_button: Element<Interaction> = Button::new(dismiss_label_container)
+			.style(grin_gui_core::theme::ButtonStyle::Bordered)
+			.on_press(Interaction::WalletOperationHomeViewInteraction(
+				LocalViewInteraction::DismissInboxSlatepack(s.path.clone()),
+			))
+			.padding(2)
+			.into();
+
+		let inbox_row = Row::new()
+			.align_items(Alignment::Center)
+			.spacing(DEFAULT_PADDING)
+			.push(name)
+			.push(desc)
+			.push(Space::with_width(Length::Fill))
+			.push(open_button.map(Message::Interaction))
+			.push(dismiss_button.map(Message::Interaction));
+
+		inbox_column = inbox_column.push(inbox_row);
+	}
+
+	if !state.inbox.is_empty() {
+		inbox_column = inbox_column.push(Space::with_height(Length::Fixed(DEFAULT_PADDING)));
+	}
+
 	// Buttons to perform operations go here, but empty container for now
 	let tx_list_display =
-		tx_list_display::data_container(config, &state, &state.tx_list_display_state)
+		tx_list_display::data_container(config, &state, &state.tx_list_display_state, address_book)
 			.height(Length::Fill);
 
 	// Overall Home screen layout column
@@ -884,6 +1390,7 @@ pub fn data_container<'a>(config: &'a Config, state: &'a StateContainer) -> Cont
 		.push(header_container)
 		.push(first_row_container)
 		.push(Space::with_height(Length::Fixed(DEFAULT_PADDING * 3.0)))
+		.push(inbox_column)
 		.push(tx_list_display)
 		.push(Space::with_height(Length::Fixed(DEFAULT_PADDING)))
 		.push(status_row)
@@ -1,17 +1,25 @@
+pub mod accounts;
 pub mod action_menu;
 pub mod apply_tx;
 pub mod apply_tx_confirm;
 pub mod chart;
+pub mod contacts;
+pub mod create_invoice;
 pub mod create_tx;
 pub mod create_tx_contracts;
+pub mod export_proofs;
 pub mod home;
 pub mod open;
+pub mod outputs;
+pub mod recovery_phrase;
+pub mod scan;
 pub mod show_slatepack;
 pub mod tx_detail;
 pub mod tx_done;
 pub mod tx_list;
 pub mod tx_list_display;
 pub mod tx_proof;
+pub mod verify_proof;
 
 use {
 	crate::gui::{GrinGui, Message},
@@ -21,20 +29,31 @@ use {
 	grin_gui_core::theme::{
 		Button, Column, Container, Element, PickList, Row, Scrollable, Text, TextInput,
 	},
+	grin_gui_core::wallet::contacts::AddressBook,
 	iced::{Command, Length},
 };
 
 pub struct StateContainer {
 	pub mode: Mode,
 	pub open_state: open::StateContainer,
+	pub accounts_state: accounts::StateContainer,
+	pub scan_state: scan::StateContainer,
+	pub outputs_state: outputs::StateContainer,
+	pub contacts_state: contacts::StateContainer,
+	// Contacts of the open wallet, shared by the send forms and transaction views
+	pub address_book: AddressBook,
 	pub home_state: home::StateContainer,
 	pub create_tx_state: create_tx::StateContainer,
 	pub create_tx_contracts_state: create_tx_contracts::StateContainer,
+	pub create_invoice_state: create_invoice::StateContainer,
 	pub show_slatepack_state: show_slatepack::StateContainer,
 	pub apply_tx_state: apply_tx::StateContainer,
 	pub tx_detail_state: tx_detail::StateContainer,
 	pub tx_proof_state: tx_proof::StateContainer,
 	pub tx_done_state: tx_done::StateContainer,
+	pub verify_proof_state: verify_proof::StateContainer,
+	pub export_proofs_state: export_proofs::StateContainer,
+	pub recovery_phrase_state: recovery_phrase::StateContainer,
 	// When changed to true, this should stay false until a wallet is opened with a password
 	has_wallet_open_check_failed_one_time: bool,
 }
@@ -43,12 +62,20 @@ pub struct StateContainer {
 pub enum Mode {
 	Open,
 	Home,
+	Accounts,
+	Scan,
+	Outputs,
+	Contacts,
 	CreateTx,
+	CreateInvoice,
 	ApplyTx,
 	ShowSlatepack,
 	TxDetail,
 	TxProof,
 	TxDone,
+	VerifyProof,
+	ExportProofs,
+	RecoveryPhrase,
 }
 
 impl Default for StateContainer {
@@ -56,14 +83,23 @@ impl Default for StateContainer {
 		Self {
 			mode: Mode::Home,
 			open_state: Default::default(),
+			accounts_state: Default::default(),
+			scan_state: Default::default(),
+			outputs_state: Default::default(),
+			contacts_state: Default::default(),
+			address_book: Default::default(),
 			home_state: Default::default(),
 			create_tx_state: Default::default(),
 			create_tx_contracts_state: Default::default(),
+			create_invoice_state: Default::default(),
 			show_slatepack_state: Default::default(),
 			apply_tx_state: Default::default(),
 			tx_detail_state: Default::default(),
 			tx_proof_state: Default::default(),
 			tx_done_state: Default::default(),
+			verify_proof_state: Default::default(),
+			export_proofs_state: Default::default(),
+			recovery_phrase_state: Default::default(),
 			has_wallet_open_check_failed_one_time: false,
 		}
 	}
@@ -97,18 +133,41 @@ pub fn handle_message(
 pub fn data_container<'a>(state: &'a StateContainer, config: &'a Config) -> Container<'a, Message> {
 	let content = match state.mode {
 		Mode::Open => open::data_container(&state.open_state, config),
-		Mode::Home => home::data_container(config, &state.home_state),
+		Mode::Home => home::data_container(
+			config,
+			&state.home_state,
+			&state.accounts_state,
+			&state.address_book,
+		),
+		Mode::Accounts => accounts::data_container(config, &state.accounts_state),
+		Mode::Scan => scan::data_container(config, &state.scan_state),
+		Mode::Outputs => outputs::data_container(config, &state.outputs_state),
+		Mode::Contacts => {
+			contacts::data_container(config, &state.contacts_state, &state.address_book)
+		}
 		Mode::CreateTx => match config.tx_method {
-			TxMethod::Legacy => create_tx::data_container(config, &state.create_tx_state),
-			TxMethod::Contracts => {
-				create_tx_contracts::data_container(config, &state.create_tx_contracts_state)
+			TxMethod::Legacy => {
+				create_tx::data_container(config, &state.create_tx_state, &state.address_book)
 			}
+			TxMethod::Contracts => create_tx_contracts::data_container(
+				config,
+				&state.create_tx_contracts_state,
+				&state.address_book,
+			),
 		},
+		Mode::CreateInvoice => create_invoice::data_container(config, &state.create_invoice_state),
 		Mode::ShowSlatepack => show_slatepack::data_container(config, &state.show_slatepack_state),
 		Mode::ApplyTx => apply_tx::data_container(config, &state.apply_tx_state),
-		Mode::TxDetail => tx_detail::data_container(config, &state.tx_detail_state),
+		Mode::TxDetail => {
+			tx_detail::data_container(config, &state.tx_detail_state, &state.address_book)
+		}
 		Mode::TxProof => tx_proof::data_container(config, &state.tx_proof_state),
 		Mode::TxDone => tx_done::data_container(config, &state.tx_done_state),
+		Mode::VerifyProof => verify_proof::data_container(config, &state.verify_proof_state),
+		Mode::ExportProofs => export_proofs::data_container(config, &state.export_proofs_state),
+		Mode::RecoveryPhrase => {
+			recovery_phrase::data_container(config, &state.recovery_phrase_state)
+		}
 	};
 
 	let column = Column::new().push(content);
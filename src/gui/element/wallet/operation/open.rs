@@ -15,7 +15,10 @@ use {
 		Button, Column, Container, Element, PickList, Row, Scrollable, Text, TextInput,
 	},
 	grin_gui_core::{
-		node::ChainTypes::Mainnet, node::ChainTypes::Testnet, wallet::WalletInterface,
+		error::GrinWalletInterfaceError,
+		node::ChainTypes::Mainnet,
+		node::ChainTypes::Testnet,
+		wallet::{SecretString, WalletInterface, Zeroizing},
 	},
 	iced::widget::{button, pick_list, scrollable, text_input, Space},
 	iced::{alignment, Alignment, Command, Length},
@@ -41,7 +44,7 @@ impl Default for StateContainer {
 
 #[derive(Debug, Clone)]
 pub struct PasswordState {
-	pub input_value: String,
+	pub input_value: SecretString,
 }
 
 impl Default for PasswordState {
@@ -54,7 +57,7 @@ impl Default for PasswordState {
 
 #[derive(Debug, Clone)]
 pub enum LocalViewInteraction {
-	//TODO: ZeroingString these
+	// Moved into a SecretString as soon as it arrives
 	PasswordInput(String),
 	PasswordInputEnterPressed,
 	OpenWallet,
@@ -82,7 +85,7 @@ pub fn handle_message<'a>(
 			grin_gui.wallet_state.operation_state.open_state = Default::default();
 		}
 		LocalViewInteraction::PasswordInput(password) => {
-			state.password_state.input_value = password;
+			state.password_state.input_value = Zeroizing::new(password);
 			state.wallet_message = localized_string("open-wallet-password");
 		}
 		LocalViewInteraction::PasswordInputEnterPressed => {
@@ -93,41 +96,55 @@ pub fn handle_message<'a>(
 
 			log::debug!("setup::wallet::operation::open::OpenWallet");
 
-			let password = state.password_state.input_value.clone();
+			// Taken out of the input, a failed attempt has to be typed again anyway
+			let password = std::mem::take(&mut state.password_state.input_value);
 			let wallet_interface = grin_gui.wallet_interface.clone();
-			let running_chain_type = grin_gui.node_interface.read().unwrap().chain_type.unwrap();
+			let (running_chain_type, node_start_failed) = {
+				let node = grin_gui.node_interface.read().unwrap();
+				(node.chain_type, node.start_failed)
+			};
 			let wallet_index = grin_gui.config.current_wallet_index.unwrap();
 			let current_wallet = &grin_gui.config.wallets[wallet_index];
 			let wallet_chain_type = current_wallet.chain_type;
 
 			if current_wallet.use_embedded_node {
-				// restart embedded server is chain types differ
-				if running_chain_type != wallet_chain_type {
+				// restart embedded server is chain types differ, or it failed to start before
+				if running_chain_type != Some(wallet_chain_type) || node_start_failed {
 					let mut node = grin_gui.node_interface.write().unwrap();
-					node.restart_server(wallet_chain_type);
-				}
-
-				let node_interface = grin_gui.node_interface.read().unwrap();
-				if let Some(c) = &node_interface.config {
-					if let Some(m) = &c.members {
-						WalletInterface::set_use_embedded_node(wallet_interface.clone(), true);
-						let mut w = wallet_interface.write().unwrap();
-						w.check_node_foreign_api_secret_path =
-							m.server.foreign_api_secret_path.clone();
+					if let Err(e) = node.restart_server(wallet_chain_type) {
+						let e = anyhow::Error::from(e).context("Failed to restart embedded node");
+						log_error(&e);
+						grin_gui.error = Some(e);
+						state.wallet_message = localized_string("open-wallet-node-error");
+						return Ok(Command::none());
 					}
 				}
 			}
-			let tld = current_wallet.tld.clone().unwrap();
-			let fut = move || {
-				WalletInterface::open_wallet(
-					wallet_interface,
-					password.clone(),
-					tld,
-					current_wallet.chain_type,
-				)
+			let embedded_node_secret_path = match &grin_gui.node_interface.read().unwrap().config {
+				Some(c) if current_wallet.use_embedded_node => c
+					.members
+					.as_ref()
+					.map(|m| m.server.foreign_api_secret_path.clone()),
+				_ => None,
 			};
+			let backend = current_wallet.backend.clone();
+			// Remote owner API wallets don't need a local top level directory
+			let tld = current_wallet.tld.clone().unwrap_or_default();
+			// Set up on the worker too, the wallet may still be busy with a previous job
+			let fut = grin_gui.wallet_worker.run(async move {
+				if let Some(secret_path) = embedded_node_secret_path {
+					WalletInterface::set_use_embedded_node(wallet_interface.clone(), true);
+					wallet_interface
+						.write()
+						.unwrap()
+						.check_node_foreign_api_secret_path = secret_path;
+				}
+				WalletInterface::set_backend(wallet_interface.clone(), backend);
+				WalletInterface::open_wallet(wallet_interface, password, tld, wallet_chain_type)
+					.await
+			});
 
-			return Ok(Command::perform(fut(), |r| {
+			return Ok(Command::perform(fut, |r| {
 				match r.context("Failed to Open Wallet") {
 					Ok(()) => {
 						Message::Interaction(Interaction::WalletOperationOpenViewInteraction(
@@ -152,21 +169,58 @@ pub fn handle_message<'a>(
 
 			// reset user input values
 			grin_gui.wallet_state.operation_state.open_state = Default::default();
+			grin_gui.wallet_state.operation_state.address_book =
+				super::contacts::load_address_book(&grin_gui.config);
+			grin_gui.watch_wallet_inbox();
+
+			let mut commands = vec![];
+			// A remote wallet runs its own listener
+			if grin_gui.config.foreign_listener.enabled
+				&& !grin_gui.wallet_interface.read().unwrap().is_remote()
+			{
+				commands.push(crate::gui::element::settings::wallet::start_listener_command());
+			}
+			if grin_gui
+				.wallet_state
+				.operation_state
+				.scan_state
+				.scan_on_open
+			{
+				grin_gui.wallet_state.operation_state.scan_state = Default::default();
+				commands.push(crate::gui::element::wallet::operation::scan::scan_on_open_command());
+			}
+			return Ok(Command::batch(commands));
 		}
 
 		LocalViewInteraction::WalletOpenError(err) => {
-			//grin_gui.error = err.write().unwrap().take();
-			// display wallet message to user
-			state.wallet_message = localized_string("open-wallet-error");
-			err.write()
-				.unwrap()
-				.take()
-				.and_then(|e| Some(log_error(&e)));
+			if let Some(e) = err.write().unwrap().take() {
+				log_error(&e);
+				// A broken wallet directory isn't fixed by retyping the password, so show
+				// the cause and let the user repair it or pick another wallet
+				if is_wallet_directory_error(&e) {
+					state.wallet_message = localized_string("open-wallet-directory-error");
+					grin_gui.error = Some(e);
+				} else {
+					// display wallet message to user
+					state.wallet_message = localized_string("open-wallet-error");
+				}
+			}
 		}
 	}
 	Ok(Command::none())
 }
 
+fn is_wallet_directory_error(error: &anyhow::Error) -> bool {
+	matches!(
+		error.downcast_ref::<GrinWalletInterfaceError>(),
+		Some(
+			GrinWalletInterfaceError::WalletConfig { .. }
+				| GrinWalletInterfaceError::WalletInstantiation { .. }
+				| GrinWalletInterfaceError::InvalidWalletPath { .. }
+		)
+	)
+}
+
 pub fn data_container<'a>(state: &'a StateContainer, config: &Config) -> Container<'a, Message> {
 	let mut display_name_string = match config.current_wallet_index {
 		Some(index) => config.wallets[index].display_name.clone(),
@@ -0,0 +1,1023 @@
+use crate::log_error;
+use grin_gui_core::config::{Config, TxMethod};
+use grin_gui_widgets::widget::header;
+
+use {
+	super::super::super::{
+		BUTTON_HEIGHT, BUTTON_WIDTH, DEFAULT_FONT_SIZE, DEFAULT_HEADER_FONT_SIZE, DEFAULT_PADDING,
+		SMALLER_FONT_SIZE,
+	},
+	super::tx_list::SortDirection,
+	crate::gui::{GrinGui, Interaction, Message},
+	crate::localization::localized_string,
+	crate::Result,
+	anyhow::Context,
+	grin_gui_core::theme::{
+		Button, Column, Container, Element, Header, PickList, Row, Scrollable, TableRow, Text,
+	},
+	grin_gui_core::wallet::{
+		mixnet::{self, MixnetClient, MixnetSwap, SwapStatus},
+		OutputCommitMapping, OutputStatus, WalletInterface,
+	},
+	grin_gui_core::{node::amount_to_hr_string, theme::ContainerStyle},
+	iced::widget::Space,
+	iced::{alignment, Alignment, Command, Length},
+	std::path::PathBuf,
+	std::sync::{Arc, RwLock},
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Hash, Eq)]
+pub enum ColumnKey {
+	Commitment,
+	Value,
+	Status,
+	Confirmations,
+	Height,
+	LockHeight,
+	Coinbase,
+	TxId,
+	Coinswap,
+}
+
+impl ColumnKey {
+	fn title(self) -> String {
+		use ColumnKey::*;
+
+		match self {
+			Commitment => localized_string("output-commitment"),
+			Value => localized_string("output-value"),
+			Status => localized_string("output-status"),
+			Confirmations => localized_string("output-confirmations"),
+			Height => localized_string("output-height"),
+			LockHeight => localized_string("output-lock-height"),
+			Coinbase => localized_string("output-coinbase"),
+			TxId => localized_string("output-tx-id"),
+			Coinswap => localized_string("output-coinswap"),
+		}
+	}
+
+	fn as_string(self) -> String {
+		use ColumnKey::*;
+
+		let s = match self {
+			Commitment => "output-commitment",
+			Value => "output-value",
+			Status => "output-status",
+			Confirmations => "output-confirmations",
+			Height => "output-height",
+			LockHeight => "output-lock-height",
+			Coinbase => "output-coinbase",
+			TxId => "output-tx-id",
+			Coinswap => "output-coinswap",
+		};
+
+		s.to_string()
+	}
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum OutputFilter {
+	Spendable,
+	Unspent,
+	Unconfirmed,
+	Locked,
+	Spent,
+	All,
+}
+
+impl OutputFilter {
+	pub const ALL: [OutputFilter; 6] = [
+		OutputFilter::Spendable,
+		OutputFilter::Unspent,
+		OutputFilter::Unconfirmed,
+		OutputFilter::Locked,
+		OutputFilter::Spent,
+		OutputFilter::All,
+	];
+
+	fn matches(
+		self,
+		output: &OutputCommitMapping,
+		height: u64,
+		minimum_confirmations: u64,
+	) -> bool {
+		match self {
+			OutputFilter::Spendable => is_spendable(output, height, minimum_confirmations),
+			OutputFilter::Unspent => output.output.status == OutputStatus::Unspent,
+			OutputFilter::Unconfirmed => output.output.status == OutputStatus::Unconfirmed,
+			OutputFilter::Locked => output.output.status == OutputStatus::Locked,
+			OutputFilter::Spent => output.output.status == OutputStatus::Spent,
+			OutputFilter::All => true,
+		}
+	}
+}
+
+impl std::fmt::Display for OutputFilter {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		let s = match self {
+			OutputFilter::Spendable => localized_string("outputs-filter-spendable"),
+			OutputFilter::Unspent => localized_string("outputs-filter-unspent"),
+			OutputFilter::Unconfirmed => localized_string("outputs-filter-unconfirmed"),
+			OutputFilter::Locked => localized_string("outputs-filter-locked"),
+			OutputFilter::Spent => localized_string("outputs-filter-spent"),
+			OutputFilter::All => localized_string("outputs-filter-all"),
+		};
+		write!(f, "{}", s)
+	}
+}
+
+/// Outputs hand-picked to fund a transaction
+#[derive(Debug, Clone, Default)]
+pub struct CoinControl {
+	pub commits: Vec<String>,
+	pub total: u64,
+}
+
+pub struct StateContainer {
+	pub outputs: Vec<OutputCommitMapping>,
+	// Chain height the outputs were retrieved at
+	pub height: u64,
+	// Confirmations an output needs before the wallet will select it, as configured for sends
+	minimum_confirmations: u64,
+	// What the send form we got here from asks for, if it differs from the saved defaults
+	pub send_minimum_confirmations: Option<u64>,
+	// Commitments of outputs picked to spend
+	pub selected: Vec<String>,
+	// Whether we got here from a send form, and should go back to it
+	pub opened_from_create: bool,
+	filter: OutputFilter,
+	sort_key: ColumnKey,
+	sort_direction: SortDirection,
+	header_state: header::State,
+	columns: Vec<(ColumnKey, Length)>,
+	is_loading: bool,
+	// Coinswaps submitted from this wallet, as recorded in the wallet directory
+	swaps: Vec<MixnetSwap>,
+	swap_in_progress: bool,
+}
+
+impl Default for StateContainer {
+	fn default() -> Self {
+		Self {
+			outputs: vec![],
+			height: 0,
+			minimum_confirmations: 1,
+			send_minimum_confirmations: None,
+			selected: vec![],
+			opened_from_create: false,
+			filter: OutputFilter::Spendable,
+			sort_key: ColumnKey::Value,
+			sort_direction: SortDirection::Desc,
+			header_state: Default::default(),
+			columns: vec![
+				(ColumnKey::Commitment, Length::Fixed(160.0)),
+				(ColumnKey::Value, Length::Fixed(110.0)),
+				(ColumnKey::Status, Length::Fixed(100.0)),
+				(ColumnKey::Confirmations, Length::Fixed(100.0)),
+				(ColumnKey::Height, Length::Fixed(80.0)),
+				(ColumnKey::LockHeight, Length::Fixed(90.0)),
+				(ColumnKey::Coinbase, Length::Fixed(80.0)),
+				(ColumnKey::TxId, Length::Fixed(60.0)),
+				(ColumnKey::Coinswap, Length::Fixed(90.0)),
+			],
+			is_loading: false,
+			swaps: vec![],
+			swap_in_progress: false,
+		}
+	}
+}
+
+impl StateContainer {
+	/// Outputs passing the current filter, in the current sort order
+	fn visible_outputs(&self) -> Vec<&OutputCommitMapping> {
+		let mut outputs: Vec<&OutputCommitMapping> = self
+			.outputs
+			.iter()
+			.filter(|o| {
+				self.filter
+					.matches(o, self.height, self.minimum_confirmations)
+			})
+			.collect();
+
+		let height = self.height;
+		outputs.sort_by(|a, b| {
+			let (a, b) = (&a.output, &b.output);
+			let ordering = match self.sort_key {
+				ColumnKey::Commitment => a.commit.cmp(&b.commit),
+				ColumnKey::Value => a.value.cmp(&b.value),
+				ColumnKey::Status => a.status.to_string().cmp(&b.status.to_string()),
+				ColumnKey::Confirmations => a
+					.num_confirmations(height)
+					.cmp(&b.num_confirmations(height)),
+				ColumnKey::Height => a.height.cmp(&b.height),
+				ColumnKey::LockHeight => a.lock_height.cmp(&b.lock_height),
+				ColumnKey::Coinbase => a.is_coinbase.cmp(&b.is_coinbase),
+				ColumnKey::TxId => a.tx_log_entry.cmp(&b.tx_log_entry),
+				ColumnKey::Coinswap => self
+					.swap_status(a.commit.as_deref())
+					.cmp(&self.swap_status(b.commit.as_deref())),
+			};
+			match self.sort_direction {
+				SortDirection::Asc => ordering,
+				SortDirection::Desc => ordering.reverse(),
+			}
+		});
+		outputs
+	}
+
+	/// Where the output stands in a coinswap, as the input or the output the mix creates
+	fn swap_status(&self, commit: Option<&str>) -> Option<String> {
+		let commit = commit?;
+		let key = self.swaps.iter().rev().find_map(|s| {
+			if s.input_commit == commit {
+				Some(match s.status {
+					SwapStatus::Pending => "coinswap-submitted",
+					SwapStatus::Completed => "coinswap-swapped",
+					SwapStatus::Failed(_) => "coinswap-failed",
+					SwapStatus::Abandoned => "coinswap-abandoned",
+				})
+			} else if s.output_commit == commit {
+				match s.status {
+					SwapStatus::Pending => Some("coinswap-pending"),
+					SwapStatus::Completed => Some("coinswap-completed"),
+					SwapStatus::Failed(_) | SwapStatus::Abandoned => None,
+				}
+			} else {
+				None
+			}
+		})?;
+		Some(localized_string(key))
+	}
+
+	/// Swap still waiting on the mix that spends this output
+	fn pending_swap(&self, commit: &str) -> Option<&MixnetSwap> {
+		self.swaps
+			.iter()
+			.rev()
+			.find(|s| s.input_commit == commit && s.status == SwapStatus::Pending)
+	}
+
+	pub fn coin_control(&self) -> CoinControl {
+		let total = self
+			.outputs
+			.iter()
+			.filter(|o| {
+				o.output
+					.commit
+					.as_ref()
+					.map(|c| self.selected.contains(c))
+					.unwrap_or(false)
+			})
+			.map(|o| o.output.value)
+			.sum();
+		CoinControl {
+			commits: self.selected.clone(),
+			total,
+		}
+	}
+}
+
+/// Whether the wallet would accept this output as an input right now, this takes care of
+/// coinbase maturity as well as confirmations
+fn is_spendable(output: &OutputCommitMapping, height: u64, minimum_confirmations: u64) -> bool {
+	output
+		.output
+		.eligible_to_spend(height, minimum_confirmations)
+}
+
+#[derive(Debug, Clone)]
+pub enum LocalViewInteraction {
+	Back,
+	Refresh,
+	SelectFilter(OutputFilter),
+	SortColumn(ColumnKey),
+	ToggleSelected(String),
+	ClearSelection,
+	SpendSelected,
+	SwapSelected,
+	AbandonSwap,
+
+	OutputsRefreshOk((bool, u64, Vec<OutputCommitMapping>)),
+	OutputsRefreshError(Arc<RwLock<Option<anyhow::Error>>>),
+	SwapSubmitted(MixnetSwap),
+	SwapAbandoned(MixnetSwap),
+	SwapError(Arc<RwLock<Option<anyhow::Error>>>),
+}
+
+/// Directory of the current wallet, where its coinswaps and contacts are recorded
+pub fn wallet_dir(config: &Config) -> Option<PathBuf> {
+	let i = config.current_wallet_index?;
+	config.wallets[i].tld.clone()
+}
+
+pub fn handle_message<'a>(
+	grin_gui: &mut GrinGui,
+	message: LocalViewInteraction,
+) -> Result<Command<Message>> {
+	let state = &mut grin_gui.wallet_state.operation_state.outputs_state;
+
+	match message {
+		LocalViewInteraction::Back => {
+			log::debug!("Interaction::WalletOperationOutputsViewInteraction(Back)");
+			grin_gui.wallet_state.operation_state.mode = if state.opened_from_create {
+				crate::gui::element::wallet::operation::Mode::CreateTx
+			} else {
+				crate::gui::element::wallet::operation::Mode::Home
+			};
+			state.opened_from_create = false;
+		}
+		LocalViewInteraction::Refresh => {
+			grin_gui.error.take();
+			state.is_loading = true;
+			state.minimum_confirmations = match state.send_minimum_confirmations {
+				Some(c) if state.opened_from_create => c,
+				_ => grin_gui.config.tx_defaults.minimum_confirmations,
+			};
+			state.swaps = match wallet_dir(&grin_gui.config) {
+				Some(dir) => mixnet::load_swaps(&dir).unwrap_or_else(|e| {
+					log::error!("Unable to load coinswaps: {}", e);
+					vec![]
+				}),
+				None => vec![],
+			};
+
+			let w = grin_gui.wallet_interface.clone();
+			let node_synched = grin_gui
+				.wallet_state
+				.operation_state
+				.home_state
+				.node_synched;
+			let fut =
+				grin_gui
+					.wallet_worker
+					.run(WalletInterface::get_outputs(w, true, node_synched));
+
+			return Ok(Command::perform(fut, |r| {
+				match r.context("Failed to retrieve outputs") {
+					Ok(ret) => {
+						Message::Interaction(Interaction::WalletOperationOutputsViewInteraction(
+							LocalViewInteraction::OutputsRefreshOk(ret),
+						))
+					}
+					Err(e) => {
+						Message::Interaction(Interaction::WalletOperationOutputsViewInteraction(
+							LocalViewInteraction::OutputsRefreshError(Arc::new(RwLock::new(Some(
+								e,
+							)))),
+						))
+					}
+				}
+			}));
+		}
+		LocalViewInteraction::SelectFilter(filter) => {
+			state.filter = filter;
+		}
+		LocalViewInteraction::SortColumn(key) => {
+			if state.sort_key == key {
+				state.sort_direction = state.sort_direction.toggle();
+			} else {
+				state.sort_key = key;
+				state.sort_direction = SortDirection::Desc;
+			}
+		}
+		LocalViewInteraction::ToggleSelected(commit) => {
+			if let Some(pos) = state.selected.iter().position(|c| *c == commit) {
+				state.selected.remove(pos);
+			} else {
+				state.selected.push(commit);
+			}
+		}
+		LocalViewInteraction::ClearSelection => {
+			state.selected.clear();
+		}
+		LocalViewInteraction::SpendSelected => {
+			// Only contract sends can be limited to the picked outputs
+			if grin_gui.config.tx_method != TxMethod::Contracts {
+				return Ok(Command::none());
+			}
+			let coin_control = state.coin_control();
+			state.opened_from_create = false;
+			super::create_tx_contracts::spend_outputs(grin_gui, coin_control);
+			grin_gui.wallet_state.operation_state.mode =
+				crate::gui::element::wallet::operation::Mode::CreateTx;
+		}
+		LocalViewInteraction::SwapSelected => {
+			log::debug!("Interaction::WalletOperationOutputsViewInteraction(SwapSelected)");
+			let commit = match state.selected.as_slice() {
+				[commit] => commit.clone(),
+				_ => return Ok(Command::none()),
+			};
+			grin_gui.error.take();
+
+			let config = &grin_gui.config;
+			let client = match MixnetClient::new(
+				&config.mixnet.server_url,
+				&config.tor_dispatch.socks_proxy_addr,
+			) {
+				Ok(c) => c,
+				Err(e) => {
+					let e = anyhow::Error::from(e).context("Failed to start coinswap");
+					log_error(&e);
+					grin_gui.error = Some(e);
+					return Ok(Command::none());
+				}
+			};
+			state.swap_in_progress = true;
+
+			let w = grin_gui.wallet_interface.clone();
+			let server_keys = config.mixnet_keys.clone().unwrap_or_default();
+			let fut = grin_gui.wallet_worker.run(WalletInterface::mixnet_swap(
+				w,
+				commit,
+				server_keys,
+				config.mixnet.fee_per_hop,
+				client,
+			));
+
+			return Ok(Command::perform(fut, |r| {
+				match r.context("Failed to start coinswap") {
+					Ok(swap) => {
+						Message::Interaction(Interaction::WalletOperationOutputsViewInteraction(
+							LocalViewInteraction::SwapSubmitted(swap),
+						))
+					}
+					Err(e) => {
+						Message::Interaction(Interaction::WalletOperationOutputsViewInteraction(
+							LocalViewInteraction::SwapError(Arc::new(RwLock::new(Some(e)))),
+						))
+					}
+				}
+			}));
+		}
+		LocalViewInteraction::AbandonSwap => {
+			log::debug!("Interaction::WalletOperationOutputsViewInteraction(AbandonSwap)");
+			let swap = match state.selected.as_slice() {
+				[commit] => match state.pending_swap(commit) {
+					Some(s) => s.clone(),
+					None => return Ok(Command::none()),
+				},
+				_ => return Ok(Command::none()),
+			};
+			grin_gui.error.take();
+			state.swap_in_progress = true;
+
+			let w = grin_gui.wallet_interface.clone();
+			let fut = grin_gui
+				.wallet_worker
+				.run(WalletInterface::mixnet_abandon_swap(w, swap));
+
+			return Ok(Command::perform(fut, |r| {
+				match r.context("Failed to abandon coinswap") {
+					Ok(swap) => {
+						Message::Interaction(Interaction::WalletOperationOutputsViewInteraction(
+							LocalViewInteraction::SwapAbandoned(swap),
+						))
+					}
+					Err(e) => {
+						Message::Interaction(Interaction::WalletOperationOutputsViewInteraction(
+							LocalViewInteraction::SwapError(Arc::new(RwLock::new(Some(e)))),
+						))
+					}
+				}
+			}));
+		}
+		LocalViewInteraction::SwapAbandoned(swap) => {
+			state.swap_in_progress = false;
+			state.selected.clear();
+			for s in state.swaps.iter_mut() {
+				if s.input_commit == swap.input_commit && s.status == SwapStatus::Pending {
+					*s = swap.clone();
+				}
+			}
+			if let Some(dir) = wallet_dir(&grin_gui.config) {
+				if let Err(e) = mixnet::save_swaps(&dir, &state.swaps) {
+					log::error!("Unable to record coinswap: {}", e);
+				}
+			}
+			return Ok(show_command());
+		}
+		LocalViewInteraction::SwapSubmitted(swap) => {
+			state.swap_in_progress = false;
+			state.selected.clear();
+			if let SwapStatus::Failed(reason) = &swap.status {
+				let e = anyhow::anyhow!("{}", reason).context("Coinswap was not accepted");
+				log_error(&e);
+				grin_gui.error = Some(e);
+			}
+			// Failed swaps are kept too, so the output shows why it wasn't swapped
+			state.swaps.push(swap);
+			if let Some(dir) = wallet_dir(&grin_gui.config) {
+				if let Err(e) = mixnet::save_swaps(&dir, &state.swaps) {
+					log::error!("Unable to record coinswap: {}", e);
+				}
+			}
+			return Ok(show_command());
+		}
+		LocalViewInteraction::SwapError(err) => {
+			state.swap_in_progress = false;
+			grin_gui.error = err.write().unwrap().take();
+			if let Some(e) = grin_gui.error.as_ref() {
+				log_error(e);
+			}
+		}
+		LocalViewInteraction::OutputsRefreshOk((_, height, outputs)) => {
+			state.is_loading = false;
+			state.height = height;
+			// Swaps are done once their new output confirms
+			let mut swaps_changed = false;
+			for swap in state.swaps.iter_mut() {
+				swaps_changed |= swap.update_status(&outputs);
+			}
+			// Drop any picks that are no longer spendable, or swaps that can be abandoned
+			let minimum_confirmations = state.minimum_confirmations;
+			let pending: Vec<String> = state
+				.selected
+				.iter()
+				.filter(|c| state.pending_swap(c).is_some())
+				.cloned()
+				.collect();
+			state.selected.retain(|c| {
+				pending.contains(c)
+					|| outputs.iter().any(|o| {
+						o.output.commit.as_ref() == Some(c)
+							&& is_spendable(o, height, minimum_confirmations)
+					})
+			});
+			if swaps_changed {
+				if let Some(dir) = wallet_dir(&grin_gui.config) {
+					if let Err(e) = mixnet::save_swaps(&dir, &state.swaps) {
+						log::error!("Unable to record coinswap status: {}", e);
+					}
+				}
+			}
+			state.outputs = outputs;
+		}
+		LocalViewInteraction::OutputsRefreshError(err) => {
+			state.is_loading = false;
+			grin_gui.error = err.write().unwrap().take();
+			if let Some(e) = grin_gui.error.as_ref() {
+				log_error(e);
+			}
+		}
+	}
+
+	Ok(Command::none())
+}
+
+/// Command that switches to the output browser and fetches outputs
+pub fn show_command() -> Command<Message> {
+	let fut = move || async {};
+	Command::perform(fut(), |_| {
+		Message::Interaction(Interaction::WalletOperationOutputsViewInteraction(
+			LocalViewInteraction::Refresh,
+		))
+	})
+}
+
+/// Row shown on the send forms, with what's been hand-picked and buttons to pick or clear
+pub fn coin_control_row<'a>(
+	coin_control: &Option<CoinControl>,
+	choose: Interaction,
+	clear: Interaction,
+) -> Row<'a, Message> {
+	let summary = match coin_control {
+		Some(c) => format!(
+			"{}: {} ({})",
+			localized_string("outputs-selected"),
+			c.commits.len(),
+			amount_to_hr_string(c.total, true)
+		),
+		None => localized_string("outputs-automatic-selection"),
+	};
+	let summary_container = Container::new(Text::new(summary).size(DEFAULT_FONT_SIZE))
+		.style(ContainerStyle::NormalBackground)
+		.padding(iced::Padding::from([
+			3, // top
+			0, // right
+			0, // bottom
+			0, // left
+		]));
+
+	let choose_label_container =
+		Container::new(Text::new(localized_string("outputs-choose")).size(SMALLER_FONT_SIZE))
+			.height(Length::Fixed(14.0))
+			.center_y()
+			.center_x();
+
+	let choose_button: Element<Interaction> = Button::new(choose_label_container)
+		.style(grin_gui_core::theme::ButtonStyle::Bordered)
+		.on_press(choose)
+		.padding(2)
+		.into();
+
+	let mut row = Row::new()
+		.push(summary_container)
+		.push(Space::with_width(Length::Fixed(DEFAULT_PADDING)))
+		.push(choose_button.map(Message::Interaction));
+
+	if coin_control.is_some() {
+		let clear_label_container = Container::new(
+			Text::new(localized_string("outputs-clear-selection")).size(SMALLER_FONT_SIZE),
+		)
+		.height(Length::Fixed(14.0))
+		.center_y()
+		.center_x();
+
+		let clear_button: Element<Interaction> = Button::new(clear_label_container)
+			.style(grin_gui_core::theme::ButtonStyle::Bordered)
+			.on_press(clear)
+			.padding(2)
+			.into();
+
+		row = row
+			.push(Space::with_width(Length::Fixed(2.0)))
+			.push(clear_button.map(Message::Interaction));
+	}
+
+	row
+}
+
+fn row_title(key: ColumnKey, state: &StateContainer) -> String {
+	if key == state.sort_key {
+		match state.sort_direction {
+			SortDirection::Asc => format!("{} ▲", key.title()),
+			SortDirection::Desc => format!("{} ▼", key.title()),
+		}
+	} else {
+		key.title()
+	}
+}
+
+fn titles_row_header<'a>(state: &'a StateContainer) -> Header<'a, Message> {
+	let mut row_titles = vec![];
+
+	for (key, width) in state.columns.iter() {
+		let mut row_header = Button::new(
+			Text::new(row_title(*key, state))
+				.size(DEFAULT_FONT_SIZE)
+				.width(Length::Fill),
+		)
+		.width(Length::Fill)
+		.on_press(Interaction::WalletOperationOutputsViewInteraction(
+			LocalViewInteraction::SortColumn(*key),
+		));
+
+		if *key == state.sort_key {
+			row_header = row_header.style(grin_gui_core::theme::ButtonStyle::SelectedColumn);
+		} else {
+			row_header = row_header.style(grin_gui_core::theme::ButtonStyle::ColumnHeader);
+		}
+
+		let row_header: Element<Interaction> = row_header.into();
+
+		let row_container = Container::new(row_header.map(Message::Interaction))
+			.width(*width)
+			.style(grin_gui_core::theme::ContainerStyle::NormalBackground);
+
+		row_titles.push((key.as_string(), row_container));
+	}
+
+	Header::new(state.header_state.clone(), row_titles, None, None)
+		.spacing(1)
+		.height(Length::Fixed(25.0))
+}
+
+fn data_row_container<'a>(
+	output: &'a OutputCommitMapping,
+	state: &'a StateContainer,
+	is_odd: Option<bool>,
+) -> Container<'a, Message> {
+	let default_height = Length::Fixed(26.0);
+	let o = &output.output;
+
+	let commit = o.commit.clone().unwrap_or_default();
+	let display_commit = if commit.len() > 16 {
+		format!("{}...{}", &commit[..10], &commit[commit.len() - 6..])
+	} else {
+		commit.clone()
+	};
+	let spendable = is_spendable(output, state.height, state.minimum_confirmations);
+	let abandonable = state.pending_swap(&commit).is_some();
+	let is_selected = state.selected.contains(&commit);
+
+	// Coinbase outputs that haven't hit their lock height yet show as immature
+	let status = if o.is_coinbase && o.lock_height > state.height && o.status != OutputStatus::Spent
+	{
+		localized_string("output-immature")
+	} else {
+		o.status.to_string()
+	};
+	let confirmations = match o.status {
+		OutputStatus::Unconfirmed | OutputStatus::Reverted => "0".to_owned(),
+		_ => o.num_confirmations(state.height).to_string(),
+	};
+	let lock_height = if o.lock_height > 0 {
+		o.lock_height.to_string()
+	} else {
+		"-".to_owned()
+	};
+	let coinbase = if o.is_coinbase {
+		localized_string("yes")
+	} else {
+		localized_string("no")
+	};
+	let tx_id = match o.tx_log_entry {
+		Some(id) => id.to_string(),
+		None => "-".to_owned(),
+	};
+	let coinswap = state
+		.swap_status(o.commit.as_deref())
+		.unwrap_or_else(|| "-".to_owned());
+
+	let mut row = Row::new().spacing(1);
+	for (key, width) in state.columns.iter() {
+		let value = match key {
+			ColumnKey::Commitment => display_commit.clone(),
+			ColumnKey::Value => amount_to_hr_string(o.value, true),
+			ColumnKey::Status => status.clone(),
+			ColumnKey::Confirmations => confirmations.clone(),
+			ColumnKey::Height => o.height.to_string(),
+			ColumnKey::LockHeight => lock_height.clone(),
+			ColumnKey::Coinbase => coinbase.clone(),
+			ColumnKey::TxId => tx_id.clone(),
+			ColumnKey::Coinswap => coinswap.clone(),
+		};
+		let cell = Container::new(Text::new(value).size(DEFAULT_FONT_SIZE))
+			.padding(5)
+			.height(default_height)
+			.width(*width)
+			.center_y()
+			.style(grin_gui_core::theme::ContainerStyle::HoverableForeground);
+		row = row.push(cell);
+	}
+
+	let mut table_row = TableRow::new(row).width(Length::Fill).inner_row_height(26);
+
+	// Only outputs the wallet could spend can be picked, or the input of a swap to give up on
+	if spendable || abandonable {
+		table_row = table_row.on_press(move |_| {
+			Message::Interaction(Interaction::WalletOperationOutputsViewInteraction(
+				LocalViewInteraction::ToggleSelected(commit.clone()),
+			))
+		});
+	}
+
+	if is_selected {
+		table_row = table_row.style(grin_gui_core::theme::TableRowStyle::TableRowSelected)
+	} else if is_odd == Some(true) {
+		table_row = table_row.style(grin_gui_core::theme::TableRowStyle::TableRowAlternate)
+	} else {
+		table_row = table_row.style(grin_gui_core::theme::TableRowStyle::Default)
+	}
+
+	Container::new(table_row)
+}
+
+pub fn data_container<'a>(config: &'a Config, state: &'a StateContainer) -> Container<'a, Message> {
+	let button_height = Length::Fixed(BUTTON_HEIGHT);
+	let button_width = Length::Fixed(BUTTON_WIDTH);
+
+	// Title row
+	let title = Text::new(localized_string("outputs-title"))
+		.size(DEFAULT_HEADER_FONT_SIZE)
+		.horizontal_alignment(alignment::Horizontal::Center);
+
+	let title_container = Container::new(title)
+		.style(grin_gui_core::theme::ContainerStyle::BrightBackground)
+		.padding(iced::Padding::from([
+			2, // top
+			0, // right
+			2, // bottom
+			5, // left
+		]));
+
+	let filter_pick_list = PickList::new(&OutputFilter::ALL[..], Some(state.filter), |f| {
+		Message::Interaction(Interaction::WalletOperationOutputsViewInteraction(
+			LocalViewInteraction::SelectFilter(f),
+		))
+	})
+	.text_size(DEFAULT_FONT_SIZE)
+	.width(Length::Fixed(120.0))
+	.style(grin_gui_core::theme::PickListStyle::Primary);
+
+	let refresh_label_container =
+		Container::new(Text::new(localized_string("refresh")).size(SMALLER_FONT_SIZE))
+			.height(Length::Fixed(14.0))
+			.center_y()
+			.center_x();
+
+	let refresh_button: Element<Interaction> = Button::new(refresh_label_container)
+		.style(grin_gui_core::theme::ButtonStyle::Bordered)
+		.on_press(Interaction::WalletOperationOutputsViewInteraction(
+			LocalViewInteraction::Refresh,
+		))
+		.padding(2)
+		.into();
+
+	let header_row = Row::new()
+		.push(title_container)
+		.push(Space::with_width(Length::Fill))
+		.push(filter_pick_list)
+		.push(Space::with_width(Length::Fixed(DEFAULT_PADDING)))
+		.push(refresh_button.map(Message::Interaction))
+		.align_items(Alignment::Center);
+
+	let header_container = Container::new(header_row).padding(iced::Padding::from([
+		0,                      // top
+		0,                      // right
+		DEFAULT_PADDING as u16, // bottom
+		0,                      // left
+	]));
+
+	let table_header_container =
+		Container::new(titles_row_header(state)).padding(iced::Padding::from([
+			0,                          // top
+			DEFAULT_PADDING as u16 * 3, // right - should roughly match width of content scroll bar to align table headers
+			0,                          // bottom
+			0,                          // left
+		]));
+
+	let mut content = Column::new().spacing(1);
+	let visible_outputs = state.visible_outputs();
+
+	for (idx, output) in visible_outputs.iter().enumerate() {
+		let is_odd = if config.alternating_row_colors {
+			Some(idx % 2 != 0)
+		} else {
+			None
+		};
+		content = content.push(data_row_container(output, state, is_odd));
+	}
+
+	let mut outputs_content = Column::new().height(Length::Fill);
+
+	if !visible_outputs.is_empty() {
+		outputs_content = outputs_content
+			.push(table_header_container)
+			.push(Scrollable::new(content).style(grin_gui_core::theme::ScrollableStyle::Primary));
+	} else {
+		let no_outputs_label = if state.is_loading {
+			Text::new(localized_string("outputs-loading")).size(DEFAULT_FONT_SIZE)
+		} else {
+			Text::new(localized_string("no-outputs")).size(DEFAULT_FONT_SIZE)
+		};
+		let no_outputs_container =
+			Container::new(no_outputs_label).style(ContainerStyle::NormalBackground);
+		outputs_content = outputs_content.push(no_outputs_container);
+	}
+
+	// Selection summary
+	let coin_control = state.coin_control();
+	let selected_text = Text::new(format!(
+		"{}: {} ({})",
+		localized_string("outputs-selected"),
+		coin_control.commits.len(),
+		amount_to_hr_string(coin_control.total, true)
+	))
+	.size(DEFAULT_FONT_SIZE);
+	let mut selected_column = Column::new().push(selected_text);
+	// `InitTxArgs` can't name the inputs to spend, only contract sends can use the picked ones
+	if config.tx_method != TxMethod::Contracts {
+		selected_column = selected_column.push(
+			Text::new(localized_string("outputs-legacy-unsupported")).size(SMALLER_FONT_SIZE),
+		);
+	}
+	let selected_container = Container::new(selected_column)
+		.style(ContainerStyle::NormalBackground)
+		.center_y()
+		.height(button_height);
+
+	let spend_button_label_container = Container::new(
+		Text::new(localized_string("outputs-spend-selected")).size(DEFAULT_FONT_SIZE),
+	)
+	.width(button_width)
+	.height(button_height)
+	.center_x()
+	.center_y()
+	.align_x(alignment::Horizontal::Center);
+
+	let picked_pending_swap = state
+		.selected
+		.iter()
+		.any(|c| state.pending_swap(c).is_some());
+
+	let mut spend_button =
+		Button::new(spend_button_label_container).style(grin_gui_core::theme::ButtonStyle::Primary);
+	if config.tx_method == TxMethod::Contracts && !state.selected.is_empty() && !picked_pending_swap
+	{
+		spend_button = spend_button.on_press(Interaction::WalletOperationOutputsViewInteraction(
+			LocalViewInteraction::SpendSelected,
+		));
+	}
+	let spend_button: Element<Interaction> = spend_button.into();
+
+	let swap_button_label = if state.swap_in_progress {
+		localized_string("outputs-swap-in-progress")
+	} else if picked_pending_swap {
+		localized_string("outputs-abandon-swap")
+	} else {
+		localized_string("outputs-swap-selected")
+	};
+	let swap_button_label_container =
+		Container::new(Text::new(swap_button_label).size(DEFAULT_FONT_SIZE))
+			.width(button_width)
+			.height(button_height)
+			.center_x()
+			.center_y()
+			.align_x(alignment::Horizontal::Center);
+
+	// Swaps go one output at a time
+	let mut swap_button =
+		Button::new(swap_button_label_container).style(grin_gui_core::theme::ButtonStyle::Primary);
+	if state.selected.len() == 1 && !state.swap_in_progress {
+		let interaction = if picked_pending_swap {
+			LocalViewInteraction::AbandonSwap
+		} else {
+			LocalViewInteraction::SwapSelected
+		};
+		swap_button = swap_button.on_press(Interaction::WalletOperationOutputsViewInteraction(
+			interaction,
+		));
+	}
+	let swap_button: Element<Interaction> = swap_button.into();
+
+	let clear_button_label_container = Container::new(
+		Text::new(localized_string("outputs-clear-selection")).size(DEFAULT_FONT_SIZE),
+	)
+	.width(button_width)
+	.height(button_height)
+	.center_x()
+	.center_y()
+	.align_x(alignment::Horizontal::Center);
+
+	let clear_button: Element<Interaction> = Button::new(clear_button_label_container)
+		.style(grin_gui_core::theme::ButtonStyle::Primary)
+		.on_press(Interaction::WalletOperationOutputsViewInteraction(
+			LocalViewInteraction::ClearSelection,
+		))
+		.into();
+
+	let back_button_label_container =
+		Container::new(Text::new(localized_string("back")).size(DEFAULT_FONT_SIZE))
+			.width(button_width)
+			.height(button_height)
+			.center_x()
+			.center_y()
+			.align_x(alignment::Horizontal::Center);
+
+	let back_button: Element<Interaction> = Button::new(back_button_label_container)
+		.style(grin_gui_core::theme::ButtonStyle::Primary)
+		.on_press(Interaction::WalletOperationOutputsViewInteraction(
+			LocalViewInteraction::Back,
+		))
+		.into();
+
+	let spend_container = Container::new(spend_button.map(Message::Interaction)).padding(1);
+	let spend_container = Container::new(spend_container)
+		.style(grin_gui_core::theme::ContainerStyle::Segmented)
+		.padding(1);
+
+	let swap_container = Container::new(swap_button.map(Message::Interaction)).padding(1);
+	let swap_container = Container::new(swap_container)
+		.style(grin_gui_core::theme::ContainerStyle::Segmented)
+		.padding(1);
+
+	let clear_container = Container::new(clear_button.map(Message::Interaction)).padding(1);
+	let clear_container = Container::new(clear_container)
+		.style(grin_gui_core::theme::ContainerStyle::Segmented)
+		.padding(1);
+
+	let back_container = Container::new(back_button.map(Message::Interaction)).padding(1);
+	let back_container = Container::new(back_container)
+		.style(grin_gui_core::theme::ContainerStyle::Segmented)
+		.padding(1);
+
+	let unit_spacing = 15.0;
+	let button_row = Row::new()
+		.push(selected_container)
+		.push(Space::with_width(Length::Fill))
+		.push(spend_container)
+		.push(Space::new(Length::Fixed(unit_spacing), Length::Fixed(0.0)))
+		.push(swap_container)
+		.push(Space::new(Length::Fixed(unit_spacing), Length::Fixed(0.0)))
+		.push(clear_container)
+		.push(Space::new(Length::Fixed(unit_spacing), Length::Fixed(0.0)))
+		.push(back_container)
+		.align_items(Alignment::Center);
+
+	let column = Column::new()
+		.push(header_container)
+		.push(outputs_content)
+		.push(Space::with_height(Length::Fixed(DEFAULT_PADDING)))
+		.push(button_row);
+
+	// Returns the final container.
+	Container::new(column)
+		.padding(iced::Padding::from([
+			DEFAULT_PADDING, // top
+			DEFAULT_PADDING, // right
+			DEFAULT_PADDING, // bottom
+			DEFAULT_PADDING, // left
+		]))
+		.style(grin_gui_core::theme::ContainerStyle::PanelBordered)
+}
@@ -0,0 +1,465 @@
+use crate::log_error;
+use grin_gui_core::{
+	config::Config,
+	wallet::recovery::{check_word, quiz_indices},
+	wallet::{SecretString, Zeroizing},
+};
+
+use {
+	super::super::super::{
+		BUTTON_HEIGHT, BUTTON_WIDTH, DEFAULT_FONT_SIZE, DEFAULT_HEADER_FONT_SIZE, DEFAULT_PADDING,
+		SMALLER_FONT_SIZE,
+	},
+	crate::gui::{GrinGui, Interaction, Message},
+	crate::localization::localized_string,
+	crate::Result,
+	anyhow::Context,
+	grin_gui_core::theme::{Button, Column, Container, Element, Row, Scrollable, Text, TextInput},
+	grin_gui_core::wallet::WalletInterface,
+	iced::widget::Space,
+	iced::{alignment, Alignment, Command, Length},
+	iced_aw::Card,
+	std::sync::{Arc, RwLock},
+};
+
+pub struct StateContainer {
+	pub password_value: SecretString,
+	phrase: Option<SecretString>,
+	password_error: bool,
+	// Words asked for in the backup check, by position in the phrase, with the answers given.
+	// The phrase is hidden while this isn't empty
+	quiz: Vec<(usize, String)>,
+	quiz_result: Option<bool>,
+}
+
+impl Default for StateContainer {
+	fn default() -> Self {
+		Self {
+			password_value: Default::default(),
+			phrase: None,
+			password_error: false,
+			quiz: vec![],
+			quiz_result: None,
+		}
+	}
+}
+
+#[derive(Debug, Clone)]
+pub enum LocalViewInteraction {
+	Back,
+	Password(String),
+	Show,
+	ShowOk(SecretString),
+	ShowError(Arc<RwLock<Option<anyhow::Error>>>),
+	StartQuiz,
+	QuizAnswer(usize, String),
+	CheckQuiz,
+	AbandonQuiz,
+}
+
+pub fn handle_message<'a>(
+	grin_gui: &mut GrinGui,
+	message: LocalViewInteraction,
+) -> Result<Command<Message>> {
+	let state = &mut grin_gui.wallet_state.operation_state.recovery_phrase_state;
+
+	match message {
+		LocalViewInteraction::Back => {
+			log::debug!("Interaction::WalletOperationRecoveryPhraseViewInteraction(Back)");
+			// Don't keep the phrase around once the user leaves
+			*state = Default::default();
+			grin_gui.wallet_state.operation_state.mode =
+				crate::gui::element::wallet::operation::Mode::Home;
+		}
+		LocalViewInteraction::Password(s) => {
+			state.password_value = Zeroizing::new(s);
+			state.password_error = false;
+		}
+		LocalViewInteraction::Show => {
+			log::debug!("Interaction::WalletOperationRecoveryPhraseViewInteraction(Show)");
+			grin_gui.error.take();
+			state.password_error = false;
+
+			let w = grin_gui.wallet_interface.clone();
+			// Taken out of the input, a wrong password has to be typed again anyway
+			let password = std::mem::take(&mut state.password_value);
+			let fut = grin_gui
+				.wallet_worker
+				.run(WalletInterface::get_recovery_phrase(w, password));
+
+			return Ok(Command::perform(fut, |r| {
+				match r.context("Failed to Retrieve Recovery Phrase") {
+					Ok(phrase) => Message::Interaction(
+						Interaction::WalletOperationRecoveryPhraseViewInteraction(
+							LocalViewInteraction::ShowOk(phrase),
+						),
+					),
+					Err(e) => Message::Interaction(
+						Interaction::WalletOperationRecoveryPhraseViewInteraction(
+							LocalViewInteraction::ShowError(Arc::new(RwLock::new(Some(e)))),
+						),
+					),
+				}
+			}));
+		}
+		LocalViewInteraction::ShowOk(phrase) => {
+			state.phrase = Some(phrase);
+		}
+		LocalViewInteraction::ShowError(err) => {
+			// Most likely a wrong password, which the user is told about in place
+			state.password_error = true;
+			err.write()
+				.unwrap()
+				.take()
+				.and_then(|e| Some(log_error(&e)));
+		}
+		LocalViewInteraction::StartQuiz => {
+			if let Some(ref phrase) = state.phrase {
+				state.quiz = quiz_indices(phrase)
+					.into_iter()
+					.map(|i| (i, String::new()))
+					.collect();
+				state.quiz_result = None;
+			}
+		}
+		LocalViewInteraction::QuizAnswer(n, s) => {
+			if let Some(q) = state.quiz.get_mut(n) {
+				q.1 = s;
+			}
+			state.quiz_result = None;
+		}
+		LocalViewInteraction::CheckQuiz => {
+			if let Some(ref phrase) = state.phrase {
+				let passed = state
+					.quiz
+					.iter()
+					.all(|(i, answer)| check_word(phrase, *i, answer));
+				if passed {
+					state.quiz.clear();
+				}
+				state.quiz_result = Some(passed);
+			}
+		}
+		LocalViewInteraction::AbandonQuiz => {
+			state.quiz.clear();
+			state.quiz_result = None;
+		}
+	}
+
+	Ok(Command::none())
+}
+
+fn message_line<'a>(text: String, ok: bool) -> Container<'a, Message> {
+	let mut text = Text::new(text)
+		.size(DEFAULT_FONT_SIZE)
+		.horizontal_alignment(alignment::Horizontal::Left);
+	if !ok {
+		text = text.style(grin_gui_core::theme::text::TextStyle::Warning);
+	}
+	Container::new(text).style(grin_gui_core::theme::ContainerStyle::NormalBackground)
+}
+
+fn primary_button<'a>(label: String, on_press: Option<Interaction>) -> Container<'a, Message> {
+	let label_container = Container::new(Text::new(label).size(DEFAULT_FONT_SIZE))
+		.width(Length::Fixed(BUTTON_WIDTH))
+		.height(Length::Fixed(BUTTON_HEIGHT))
+		.center_x()
+		.center_y()
+		.align_x(alignment::Horizontal::Center);
+
+	let mut button = Button::new(label_container).style(grin_gui_core::theme::ButtonStyle::Primary);
+	if let Some(i) = on_press {
+		button = button.on_press(i);
+	}
+	let button: Element<Interaction> = button.into();
+
+	let container = Container::new(button.map(Message::Interaction)).padding(1);
+	Container::new(container)
+		.style(grin_gui_core::theme::ContainerStyle::Segmented)
+		.padding(1)
+}
+
+pub fn data_container<'a>(config: &'a Config, state: &'a StateContainer) -> Container<'a, Message> {
+	// Title row
+	let title = Text::new(localized_string("recovery-phrase-title"))
+		.size(DEFAULT_HEADER_FONT_SIZE)
+		.horizontal_alignment(alignment::Horizontal::Center);
+
+	let title_container = Container::new(title)
+		.style(grin_gui_core::theme::ContainerStyle::BrightBackground)
+		.padding(iced::Padding::from([
+			2, // top
+			0, // right
+			2, // bottom
+			5, // left
+		]));
+
+	let header_row = Row::new().push(title_container);
+
+	let header_container = Container::new(header_row).padding(iced::Padding::from([
+		0,                      // top
+		0,                      // right
+		DEFAULT_PADDING as u16, // bottom
+		0,                      // left
+	]));
+
+	let unit_spacing = 15.0;
+	let row_spacing = 5.0;
+
+	let mut column = Column::new();
+
+	match state.phrase {
+		None => {
+			let password_label = Text::new(localized_string("recovery-phrase-password"))
+				.size(DEFAULT_FONT_SIZE)
+				.horizontal_alignment(alignment::Horizontal::Left);
+
+			let password_label_container = Container::new(password_label)
+				.style(grin_gui_core::theme::ContainerStyle::NormalBackground);
+
+			let password_input =
+				TextInput::new(&localized_string("password")[..], &state.password_value)
+					.on_submit(Interaction::WalletOperationRecoveryPhraseViewInteraction(
+						LocalViewInteraction::Show,
+					))
+					.on_input(|s| {
+						Interaction::WalletOperationRecoveryPhraseViewInteraction(
+							LocalViewInteraction::Password(s),
+						)
+					})
+					.size(DEFAULT_FONT_SIZE)
+					.padding(6)
+					.width(Length::Fixed(400.0))
+					.style(grin_gui_core::theme::TextInputStyle::AddonsQuery)
+					.password();
+
+			let password_input: Element<Interaction> = password_input.into();
+
+			column = column
+				.push(password_label_container)
+				.push(Space::new(Length::Fixed(0.0), Length::Fixed(row_spacing)))
+				.push(password_input.map(Message::Interaction))
+				.push(Space::new(Length::Fixed(0.0), Length::Fixed(unit_spacing)));
+
+			if state.password_error {
+				column = column
+					.push(message_line(
+						localized_string("recovery-phrase-password-error"),
+						false,
+					))
+					.push(Space::new(Length::Fixed(0.0), Length::Fixed(unit_spacing)));
+			}
+
+			let show_interaction = if state.password_value.is_empty() {
+				None
+			} else {
+				Some(Interaction::WalletOperationRecoveryPhraseViewInteraction(
+					LocalViewInteraction::Show,
+				))
+			};
+
+			let button_row = Row::new()
+				.push(primary_button(
+					localized_string("recovery-phrase-show"),
+					show_interaction,
+				))
+				.push(Space::new(Length::Fixed(unit_spacing), Length::Fixed(0.0)))
+				.push(primary_button(
+					localized_string("back"),
+					Some(Interaction::WalletOperationRecoveryPhraseViewInteraction(
+						LocalViewInteraction::Back,
+					)),
+				));
+
+			column = column
+				.push(button_row)
+				.push(Space::new(Length::Fixed(0.0), Length::Fixed(unit_spacing)));
+		}
+		// Hidden during the backup check, so the answers can't just be read off it
+		Some(ref phrase) if state.quiz.is_empty() => {
+			let description = Text::new(localized_string("setup-grin-wallet-recovery-phrase"))
+				.size(DEFAULT_FONT_SIZE)
+				.horizontal_alignment(alignment::Horizontal::Left);
+
+			let description_container = Container::new(description)
+				.style(grin_gui_core::theme::ContainerStyle::NormalBackground);
+
+			let recovery_phrase_card = Card::new(
+				Text::new(localized_string("setup-grin-wallet-recovery-phrase-title"))
+					.size(DEFAULT_HEADER_FONT_SIZE),
+				Text::new(phrase.as_str()).size(DEFAULT_FONT_SIZE),
+			)
+			.foot(
+				Column::new()
+					.spacing(10)
+					.padding(5)
+					.width(Length::Fill)
+					.align_items(Alignment::Center)
+					.push(
+						Button::new(
+							Text::new(localized_string("copy-to-clipboard"))
+								.size(SMALLER_FONT_SIZE)
+								.horizontal_alignment(alignment::Horizontal::Center),
+						)
+						.style(grin_gui_core::theme::ButtonStyle::NormalText)
+						.on_press(Message::Interaction(Interaction::WriteToClipboard(
+							phrase.to_string(),
+						))),
+					),
+			)
+			.max_width(400.0)
+			.style(grin_gui_core::theme::CardStyle::Normal);
+
+			column = column
+				.push(description_container)
+				.push(Space::new(Length::Fixed(0.0), Length::Fixed(unit_spacing)))
+				.push(recovery_phrase_card)
+				.push(Space::new(Length::Fixed(0.0), Length::Fixed(unit_spacing)));
+
+			if state.quiz_result == Some(true) {
+				column = column
+					.push(message_line(
+						localized_string("recovery-phrase-quiz-correct"),
+						true,
+					))
+					.push(Space::new(Length::Fixed(0.0), Length::Fixed(unit_spacing)));
+			}
+
+			let button_row = Row::new()
+				.push(primary_button(
+					localized_string("recovery-phrase-quiz"),
+					Some(Interaction::WalletOperationRecoveryPhraseViewInteraction(
+						LocalViewInteraction::StartQuiz,
+					)),
+				))
+				.push(Space::new(Length::Fixed(unit_spacing), Length::Fixed(0.0)))
+				.push(primary_button(
+					localized_string("back"),
+					Some(Interaction::WalletOperationRecoveryPhraseViewInteraction(
+						LocalViewInteraction::Back,
+					)),
+				));
+
+			column = column
+				.push(button_row)
+				.push(Space::new(Length::Fixed(0.0), Length::Fixed(unit_spacing)));
+		}
+		Some(_) => {
+			let quiz_instruction = Text::new(localized_string("recovery-phrase-quiz-instruction"))
+				.size(SMALLER_FONT_SIZE)
+				.horizontal_alignment(alignment::Horizontal::Left);
+
+			column = column
+				.push(
+					Container::new(quiz_instruction)
+						.style(grin_gui_core::theme::ContainerStyle::NormalBackground),
+				)
+				.push(Space::new(Length::Fixed(0.0), Length::Fixed(row_spacing)));
+
+			for (n, (i, answer)) in state.quiz.iter().enumerate() {
+				let word_label = Text::new(format!(
+					"{} {}",
+					localized_string("recovery-phrase-quiz-word"),
+					i + 1
+				))
+				.size(DEFAULT_FONT_SIZE)
+				.width(Length::Fixed(80.0));
+
+				let word_input = TextInput::new("", answer)
+					.on_input(move |s| {
+						Interaction::WalletOperationRecoveryPhraseViewInteraction(
+							LocalViewInteraction::QuizAnswer(n, s),
+						)
+					})
+					.size(DEFAULT_FONT_SIZE)
+					.padding(6)
+					.width(Length::Fixed(200.0))
+					.style(grin_gui_core::theme::TextInputStyle::AddonsQuery);
+
+				let word_input: Element<Interaction> = word_input.into();
+
+				let word_row = Row::new()
+					.push(
+						Container::new(word_label)
+							.style(grin_gui_core::theme::ContainerStyle::NormalBackground),
+					)
+					.push(word_input.map(Message::Interaction))
+					.align_items(Alignment::Center);
+
+				column = column
+					.push(word_row)
+					.push(Space::new(Length::Fixed(0.0), Length::Fixed(row_spacing)));
+			}
+
+			column = column.push(Space::new(Length::Fixed(0.0), Length::Fixed(unit_spacing)));
+
+			// A passed check goes back to showing the phrase
+			if state.quiz_result == Some(false) {
+				column = column
+					.push(message_line(
+						localized_string("recovery-phrase-quiz-incorrect"),
+						false,
+					))
+					.push(Space::new(Length::Fixed(0.0), Length::Fixed(unit_spacing)));
+			}
+
+			let button_row = Row::new()
+				.push(primary_button(
+					localized_string("recovery-phrase-quiz-check"),
+					Some(Interaction::WalletOperationRecoveryPhraseViewInteraction(
+						LocalViewInteraction::CheckQuiz,
+					)),
+				))
+				.push(Space::new(Length::Fixed(unit_spacing), Length::Fixed(0.0)))
+				.push(primary_button(
+					localized_string("recovery-phrase-quiz-abandon"),
+					Some(Interaction::WalletOperationRecoveryPhraseViewInteraction(
+						LocalViewInteraction::AbandonQuiz,
+					)),
+				))
+				.push(Space::new(Length::Fixed(unit_spacing), Length::Fixed(0.0)))
+				.push(primary_button(
+					localized_string("back"),
+					Some(Interaction::WalletOperationRecoveryPhraseViewInteraction(
+						LocalViewInteraction::Back,
+					)),
+				));
+
+			column = column
+				.push(button_row)
+				.push(Space::new(Length::Fixed(0.0), Length::Fixed(unit_spacing)));
+		}
+	}
+
+	let form_container = Container::new(column)
+		.width(Length::Fill)
+		.padding(iced::Padding::from([
+			0, // top
+			0, // right
+			0, // bottom
+			5, // left
+		]));
+
+	// form container should be scrollable in tiny windows
+	let scrollable = Scrollable::new(form_container)
+		.height(Length::Fill)
+		.style(grin_gui_core::theme::ScrollableStyle::Primary);
+
+	let content = Container::new(scrollable)
+		.width(Length::Fill)
+		.height(Length::Shrink)
+		.style(grin_gui_core::theme::ContainerStyle::NormalBackground);
+
+	let wrapper_column = Column::new()
+		.height(Length::Fill)
+		.push(header_container)
+		.push(content);
+
+	// Returns the final container.
+	Container::new(wrapper_column).padding(iced::Padding::from([
+		DEFAULT_PADDING, // top
+		DEFAULT_PADDING, // right
+		DEFAULT_PADDING, // bottom
+		DEFAULT_PADDING, // left
+	]))
+}
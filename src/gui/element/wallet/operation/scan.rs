@@ -0,0 +1,370 @@
+use crate::log_error;
+use grin_gui_core::config::Config;
+
+use {
+	super::super::super::{
+		BUTTON_HEIGHT, BUTTON_WIDTH, DEFAULT_FONT_SIZE, DEFAULT_HEADER_FONT_SIZE, DEFAULT_PADDING,
+		SMALLER_FONT_SIZE,
+	},
+	crate::gui::{GrinGui, Interaction, Message},
+	crate::localization::localized_string,
+	crate::Result,
+	anyhow::Context,
+	grin_gui_core::error::GrinWalletInterfaceError,
+	grin_gui_core::theme::ContainerStyle,
+	grin_gui_core::theme::{Button, Column, Container, Element, Row, Scrollable, Text, TextInput},
+	grin_gui_core::wallet::{worker::JobHandle, WalletInterface},
+	iced::widget::{Checkbox, Space},
+	iced::{alignment, Alignment, Command, Length},
+	std::sync::{Arc, RwLock},
+};
+
+pub struct StateContainer {
+	pub start_height_value: String,
+	pub delete_unconfirmed: bool,
+	// Set after restoring from seed, so a full scan starts once the wallet is opened
+	pub scan_on_open: bool,
+	// whether start height has errored
+	start_height_error: bool,
+	// Running scan, cancelling it fails the scan's requests to the node
+	job: Option<JobHandle>,
+}
+
+impl Default for StateContainer {
+	fn default() -> Self {
+		Self {
+			start_height_value: Default::default(),
+			delete_unconfirmed: false,
+			scan_on_open: false,
+			start_height_error: false,
+			job: None,
+		}
+	}
+}
+
+#[derive(Debug, Clone)]
+pub enum LocalViewInteraction {
+	Back,
+	StartHeight(String),
+	DeleteUnconfirmed(bool),
+	StartScan,
+	CancelScan,
+
+	ScanOk,
+	ScanError(Arc<RwLock<Option<anyhow::Error>>>),
+}
+
+pub fn handle_message<'a>(
+	grin_gui: &mut GrinGui,
+	message: LocalViewInteraction,
+) -> Result<Command<Message>> {
+	let state = &mut grin_gui.wallet_state.operation_state.scan_state;
+
+	match message {
+		LocalViewInteraction::Back => {
+			log::debug!("Interaction::WalletOperationScanViewInteraction(Back)");
+			state.start_height_error = false;
+			grin_gui.wallet_state.operation_state.mode =
+				crate::gui::element::wallet::operation::Mode::Home;
+		}
+		LocalViewInteraction::StartHeight(s) => {
+			state.start_height_value = s;
+			state.start_height_error = false;
+		}
+		LocalViewInteraction::DeleteUnconfirmed(b) => {
+			state.delete_unconfirmed = b;
+		}
+		LocalViewInteraction::StartScan => {
+			grin_gui.error.take();
+
+			// Blank start height scans from genesis
+			let start_height = match state.start_height_value.trim() {
+				"" => None,
+				h => match h.parse::<u64>() {
+					Ok(h) if h > 0 => Some(h),
+					_ => {
+						state.start_height_error = true;
+						return Ok(Command::none());
+					}
+				},
+			};
+			let delete_unconfirmed = state.delete_unconfirmed;
+			state.scan_on_open = false;
+
+			// Libwallet's scan can't be told to stop, so cancelling makes the wallet's node
+			// client fail the scan's requests. A remote wallet scans on its own host and can
+			// only be waited for.
+			let w = grin_gui.wallet_interface.clone();
+			let (interrupt, remote) = {
+				let w = w.read().unwrap();
+				(w.node_client().interrupt_handle(), w.is_remote())
+			};
+			let (job, fut) = grin_gui.wallet_worker.submit(move |job| async move {
+				let hook = interrupt.clone();
+				let res = job
+					.interruptible(
+						move || hook.interrupt(),
+						WalletInterface::scan(w, start_height, delete_unconfirmed),
+					)
+					.await;
+				// Let requests through again, whichever way the scan ended
+				match res {
+					Err(_) if interrupt.resume() => {
+						Err(GrinWalletInterfaceError::WalletJobCancelled)
+					}
+					res => {
+						interrupt.resume();
+						res
+					}
+				}
+			});
+			state.job = Some(job);
+
+			let home_state = &mut grin_gui.wallet_state.operation_state.home_state;
+			home_state.scan_in_progress = true;
+			home_state.scan_cancellable = !remote;
+			home_state.wallet_status = localized_string("scan-starting");
+			grin_gui.wallet_state.operation_state.mode =
+				crate::gui::element::wallet::operation::Mode::Home;
+
+			return Ok(Command::perform(fut, |r| {
+				match r.context("Failed to scan wallet") {
+					Ok(()) => {
+						Message::Interaction(Interaction::WalletOperationScanViewInteraction(
+							LocalViewInteraction::ScanOk,
+						))
+					}
+					Err(e) => {
+						Message::Interaction(Interaction::WalletOperationScanViewInteraction(
+							LocalViewInteraction::ScanError(Arc::new(RwLock::new(Some(e)))),
+						))
+					}
+				}
+			}));
+		}
+		LocalViewInteraction::CancelScan => {
+			if let Some(job) = state.job.take() {
+				job.cancel();
+			}
+			// Still scanning until the scan's next request to the node fails
+			let home_state = &mut grin_gui.wallet_state.operation_state.home_state;
+			home_state.scan_cancellable = false;
+			home_state.wallet_status = localized_string("scan-cancelling");
+		}
+		LocalViewInteraction::ScanOk => {
+			state.job = None;
+			let home_state = &mut grin_gui.wallet_state.operation_state.home_state;
+			home_state.scan_in_progress = false;
+			home_state.scan_cancellable = false;
+
+			// Outputs and transactions may have changed, refresh everything
+			home_state.reset_account_data();
+			let fut = move || async {};
+			return Ok(Command::perform(fut(), |_| {
+				Message::Interaction(Interaction::WalletOperationHomeTxListDisplayInteraction(
+					super::tx_list_display::LocalViewInteraction::RefreshList,
+				))
+			}));
+		}
+		LocalViewInteraction::ScanError(err) => {
+			state.job = None;
+			let home_state = &mut grin_gui.wallet_state.operation_state.home_state;
+			home_state.scan_in_progress = false;
+			home_state.scan_cancellable = false;
+			let err = err.write().unwrap().take();
+			// Stopped on purpose, from the cancel button or by closing or locking the wallet
+			if let Some(GrinWalletInterfaceError::WalletJobCancelled) =
+				err.as_ref().and_then(|e| e.downcast_ref())
+			{
+				home_state.wallet_status = localized_string("scan-cancelled");
+				return Ok(Command::none());
+			}
+			grin_gui.error = err;
+			if let Some(e) = grin_gui.error.as_ref() {
+				log_error(e);
+			}
+		}
+	}
+
+	Ok(Command::none())
+}
+
+/// Command that starts a full scan straight away, used after restoring from seed
+pub fn scan_on_open_command() -> Command<Message> {
+	let fut = move || async {};
+	Command::perform(fut(), |_| {
+		Message::Interaction(Interaction::WalletOperationScanViewInteraction(
+			LocalViewInteraction::StartScan,
+		))
+	})
+}
+
+pub fn data_container<'a>(config: &'a Config, state: &'a StateContainer) -> Container<'a, Message> {
+	// Title row
+	let title = Text::new(localized_string("scan-title"))
+		.size(DEFAULT_HEADER_FONT_SIZE)
+		.horizontal_alignment(alignment::Horizontal::Center);
+
+	let title_container = Container::new(title)
+		.style(grin_gui_core::theme::ContainerStyle::BrightBackground)
+		.padding(iced::Padding::from([
+			2, // top
+			0, // right
+			2, // bottom
+			5, // left
+		]));
+
+	let header_row = Row::new().push(title_container);
+
+	let header_container = Container::new(header_row).padding(iced::Padding::from([
+		0,                      // top
+		0,                      // right
+		DEFAULT_PADDING as u16, // bottom
+		0,                      // left
+	]));
+
+	let description = Text::new(localized_string("scan-description"))
+		.size(SMALLER_FONT_SIZE)
+		.horizontal_alignment(alignment::Horizontal::Left);
+	let description_container = Container::new(description).style(ContainerStyle::NormalBackground);
+
+	let start_height = Text::new(localized_string("scan-start-height"))
+		.size(DEFAULT_FONT_SIZE)
+		.horizontal_alignment(alignment::Horizontal::Left);
+	let start_height_container =
+		Container::new(start_height).style(ContainerStyle::NormalBackground);
+
+	let start_height_input = TextInput::new("1", &state.start_height_value)
+		.on_input(|s| {
+			Interaction::WalletOperationScanViewInteraction(LocalViewInteraction::StartHeight(s))
+		})
+		.size(DEFAULT_FONT_SIZE)
+		.padding(6)
+		.width(Length::Fixed(100.0))
+		.style(grin_gui_core::theme::TextInputStyle::AddonsQuery);
+	let start_height_input: Element<Interaction> = start_height_input.into();
+
+	let start_height_error = Text::new(localized_string("scan-start-height-error"))
+		.size(DEFAULT_FONT_SIZE)
+		.horizontal_alignment(alignment::Horizontal::Left)
+		.style(grin_gui_core::theme::text::TextStyle::Warning);
+	let start_height_error_container =
+		Container::new(start_height_error).style(ContainerStyle::NormalBackground);
+
+	let delete_unconfirmed = Checkbox::new(
+		localized_string("scan-delete-unconfirmed"),
+		state.delete_unconfirmed,
+		|b| {
+			Interaction::WalletOperationScanViewInteraction(
+				LocalViewInteraction::DeleteUnconfirmed(b),
+			)
+		},
+	)
+	.style(grin_gui_core::theme::CheckboxStyle::Normal)
+	.text_size(DEFAULT_FONT_SIZE)
+	.spacing(10);
+	let delete_unconfirmed: Element<Interaction> = delete_unconfirmed.into();
+	let delete_unconfirmed_container = Container::new(delete_unconfirmed.map(Message::Interaction))
+		.style(ContainerStyle::NormalBackground);
+
+	let button_height = Length::Fixed(BUTTON_HEIGHT);
+	let button_width = Length::Fixed(BUTTON_WIDTH);
+
+	let submit_button_label_container =
+		Container::new(Text::new(localized_string("scan-start")).size(DEFAULT_FONT_SIZE))
+			.width(button_width)
+			.height(button_height)
+			.center_x()
+			.center_y()
+			.align_x(alignment::Horizontal::Center);
+
+	let submit_button: Element<Interaction> = Button::new(submit_button_label_container)
+		.style(grin_gui_core::theme::ButtonStyle::Primary)
+		.on_press(Interaction::WalletOperationScanViewInteraction(
+			LocalViewInteraction::StartScan,
+		))
+		.into();
+
+	let cancel_button_label_container =
+		Container::new(Text::new(localized_string("cancel")).size(DEFAULT_FONT_SIZE))
+			.width(button_width)
+			.height(button_height)
+			.center_x()
+			.center_y()
+			.align_x(alignment::Horizontal::Center);
+
+	let cancel_button: Element<Interaction> = Button::new(cancel_button_label_container)
+		.style(grin_gui_core::theme::ButtonStyle::Primary)
+		.on_press(Interaction::WalletOperationScanViewInteraction(
+			LocalViewInteraction::Back,
+		))
+		.into();
+
+	let submit_container = Container::new(submit_button.map(Message::Interaction)).padding(1);
+	let submit_container = Container::new(submit_container)
+		.style(grin_gui_core::theme::ContainerStyle::Segmented)
+		.padding(1);
+
+	let cancel_container = Container::new(cancel_button.map(Message::Interaction)).padding(1);
+	let cancel_container = Container::new(cancel_container)
+		.style(grin_gui_core::theme::ContainerStyle::Segmented)
+		.padding(1);
+
+	let unit_spacing = 15.0;
+	let button_row = Row::new()
+		.push(submit_container)
+		.push(Space::new(Length::Fixed(unit_spacing), Length::Fixed(0.0)))
+		.push(cancel_container);
+
+	let mut column = Column::new()
+		.push(description_container)
+		.push(Space::new(Length::Fixed(0.0), Length::Fixed(unit_spacing)))
+		.push(start_height_container)
+		.push(Space::new(Length::Fixed(0.0), Length::Fixed(unit_spacing)))
+		.push(start_height_input.map(Message::Interaction))
+		.push(Space::new(Length::Fixed(0.0), Length::Fixed(unit_spacing)));
+
+	if state.start_height_error {
+		column = column
+			.push(start_height_error_container)
+			.push(Space::new(Length::Fixed(0.0), Length::Fixed(unit_spacing)));
+	}
+
+	column = column
+		.push(delete_unconfirmed_container)
+		.push(Space::new(Length::Fixed(0.0), Length::Fixed(unit_spacing)))
+		.push(button_row)
+		.align_items(Alignment::Start);
+
+	let form_container = Container::new(column)
+		.width(Length::Fill)
+		.padding(iced::Padding::from([
+			0, // top
+			0, // right
+			0, // bottom
+			5, // left
+		]));
+
+	// form container should be scrollable in tiny windows
+	let scrollable = Scrollable::new(form_container)
+		.height(Length::Fill)
+		.style(grin_gui_core::theme::ScrollableStyle::Primary);
+
+	let content = Container::new(scrollable)
+		.width(Length::Fill)
+		.height(Length::Shrink)
+		.style(grin_gui_core::theme::ContainerStyle::NormalBackground);
+
+	let wrapper_column = Column::new()
+		.height(Length::Fill)
+		.push(header_container)
+		.push(content);
+
+	// Returns the final container.
+	Container::new(wrapper_column).padding(iced::Padding::from([
+		DEFAULT_PADDING, // top
+		DEFAULT_PADDING, // right
+		DEFAULT_PADDING, // bottom
+		DEFAULT_PADDING, // left
+	]))
+}
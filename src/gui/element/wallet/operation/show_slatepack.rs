@@ -1,3 +1,5 @@
+use crate::log_error;
+
 use {
 	super::super::super::{
 		BUTTON_HEIGHT, BUTTON_WIDTH, DEFAULT_FONT_SIZE, DEFAULT_HEADER_FONT_SIZE, DEFAULT_PADDING,
@@ -7,33 +9,51 @@ use {
 	crate::localization::localized_string,
 	crate::Result,
 	grin_gui_core::config::Config,
+	grin_gui_core::error::GrinWalletInterfaceError,
+	grin_gui_core::qr::{split_frames, QR_FRAME_MAX_LEN},
 	grin_gui_core::theme::ColorPalette,
 	grin_gui_core::theme::{
-		Column, Container, Element, PickList, Row, Scrollable, Text, TextInput,
+		Column, Container, Element, PickList, QrCode, Row, Scrollable, Text, TextInput,
 	},
+	grin_gui_core::wallet::{Slate, WalletInterface},
+	grin_gui_widgets::widget::qr_code::Data as QrData,
 	iced::widget::{button, pick_list, scrollable, text_input, Button, Checkbox, Space},
 	iced::{alignment, Alignment, Command, Length},
 	iced_aw::Card,
+	std::sync::{Arc, RwLock},
 };
 
 pub struct StateContainer {
 	// Encrypted slate to send to recipient
-	pub encrypted_slate: Option<String>,
+	encrypted_slate: Option<String>,
+	// QR frames of the encrypted slate, and the one currently shown
+	qr_frames: Vec<QrData>,
+	qr_frame: usize,
 	// Where the 'submit' or back button leads to
 	pub submit_mode: Option<crate::gui::element::wallet::operation::Mode>,
 	// Label to display as title
 	pub title_label: String,
 	// description
 	pub desc: String,
+	// Slate is being delivered over Tor, only shown if that fails
+	dispatching: bool,
+	// Recipient address that isn't a contact yet, offered for saving
+	save_contact_address: Option<String>,
+	save_contact_name: String,
 }
 
 impl Default for StateContainer {
 	fn default() -> Self {
 		Self {
 			encrypted_slate: Default::default(),
+			qr_frames: vec![],
+			qr_frame: 0,
 			submit_mode: None,
 			title_label: localized_string("tx-view"),
 			desc: localized_string("tx-view-desc"),
+			dispatching: false,
+			save_contact_address: None,
+			save_contact_name: Default::default(),
 		}
 	}
 }
@@ -43,11 +63,46 @@ impl StateContainer {
 		self.title_label = localized_string("tx-view");
 		self.desc = localized_string("tx-view-desc");
 	}
+
+	/// Asks whether to save the recipient's address as a contact below the slatepack
+	pub fn offer_contact(&mut self, address: String) {
+		self.save_contact_address = Some(address);
+		self.save_contact_name = Default::default();
+	}
+
+	/// Sets the slatepack to display, encoding it into as many QR frames as needed
+	pub fn set_encrypted_slate(&mut self, encrypted_slate: Option<String>) {
+		self.qr_frame = 0;
+		self.qr_frames = match encrypted_slate.as_ref() {
+			Some(s) => split_frames(s.trim(), QR_FRAME_MAX_LEN)
+				.iter()
+				.filter_map(|f| match QrData::new(f) {
+					Ok(d) => Some(d),
+					Err(e) => {
+						log::error!("Unable to encode slatepack QR frame: {:?}", e);
+						None
+					}
+				})
+				.collect(),
+			None => vec![],
+		};
+		self.encrypted_slate = encrypted_slate;
+	}
 }
 
 #[derive(Debug, Clone)]
 pub enum LocalViewInteraction {
 	Submit,
+	PrevQrFrame,
+	NextQrFrame,
+	SaveContactName(String),
+	SaveContact,
+	DismissSaveContact,
+
+	TorDispatchOk,
+	RecipientUnreachable(String),
+	RecipientResponse(String),
+	TorDispatchError(Arc<RwLock<Option<anyhow::Error>>>),
 }
 
 pub fn handle_message(
@@ -57,7 +112,7 @@ pub fn handle_message(
 	let state = &mut grin_gui.wallet_state.operation_state.show_slatepack_state;
 	match message {
 		LocalViewInteraction::Submit => {
-			state.encrypted_slate = None;
+			state.set_encrypted_slate(None);
 			state.reset_defaults();
 			if let Some(ref m) = state.submit_mode {
 				grin_gui.wallet_state.operation_state.mode = m.clone();
@@ -66,11 +121,131 @@ pub fn handle_message(
 					crate::gui::element::wallet::operation::Mode::Home;
 			}
 			state.submit_mode = None;
+			state.dispatching = false;
+			state.save_contact_address = None;
+		}
+		LocalViewInteraction::PrevQrFrame => {
+			state.qr_frame = state.qr_frame.saturating_sub(1);
+		}
+		LocalViewInteraction::NextQrFrame => {
+			if state.qr_frame + 1 < state.qr_frames.len() {
+				state.qr_frame += 1;
+			}
+		}
+		LocalViewInteraction::SaveContactName(s) => state.save_contact_name = s,
+		LocalViewInteraction::SaveContact => {
+			let address = match state.save_contact_address.clone() {
+				Some(a) => a,
+				None => return Ok(Command::none()),
+			};
+			let name = state.save_contact_name.clone();
+			grin_gui.error.take();
+
+			match super::contacts::save_contact(grin_gui, None, &name, &address, "") {
+				Ok(()) => {
+					grin_gui
+						.wallet_state
+						.operation_state
+						.show_slatepack_state
+						.save_contact_address = None
+				}
+				Err(e) => {
+					log_error(&e);
+					grin_gui.error = Some(e);
+				}
+			}
+		}
+		LocalViewInteraction::DismissSaveContact => state.save_contact_address = None,
+		// The screen may have been left while delivery was pending, in which case it
+		// shouldn't be changed under whatever it shows next
+		LocalViewInteraction::TorDispatchOk => {
+			if !state.dispatching {
+				return Ok(Command::none());
+			}
+			state.dispatching = false;
+			state.set_encrypted_slate(None);
+			state.title_label = localized_string("tx-tor-sent-title");
+			state.desc = localized_string("tx-tor-sent-desc");
+		}
+		LocalViewInteraction::RecipientUnreachable(reason) => {
+			log::debug!("Tor dispatch failed, falling back to slatepack: {}", reason);
+			if !state.dispatching {
+				return Ok(Command::none());
+			}
+			state.dispatching = false;
+			state.desc = localized_string("tx-tor-unreachable-desc");
+		}
+		// The recipient may hold the slate already, a slatepack on top could see it signed twice
+		LocalViewInteraction::RecipientResponse(reason) => {
+			log::error!("Tor dispatch failed after sending the slate: {}", reason);
+			if !state.dispatching {
+				return Ok(Command::none());
+			}
+			state.dispatching = false;
+			state.set_encrypted_slate(None);
+			state.title_label = localized_string("tx-tor-uncertain-title");
+			state.desc = format!("{} ({})", localized_string("tx-tor-uncertain-desc"), reason);
+		}
+		LocalViewInteraction::TorDispatchError(err) => {
+			// Delivered but not finalized, the transaction can still be cancelled from the list
+			if state.dispatching {
+				state.dispatching = false;
+				state.set_encrypted_slate(None);
+				state.title_label = localized_string("tx-tor-finalize-error-title");
+				state.desc = localized_string("tx-tor-finalize-error-desc");
+			}
+			grin_gui.error = err.write().unwrap().take();
+			if let Some(e) = grin_gui.error.as_ref() {
+				log_error(e);
+			}
 		}
 	}
 	Ok(Command::none())
 }
 
+/// Try delivering a freshly created send over Tor while the slatepack screen shows progress.
+/// The slatepack is only revealed if the recipient can't be reached
+pub fn tor_dispatch_command(
+	grin_gui: &mut GrinGui,
+	slate: Slate,
+	dest_slatepack_address: String,
+) -> Command<Message> {
+	let state = &mut grin_gui.wallet_state.operation_state.show_slatepack_state;
+	state.dispatching = true;
+	state.title_label = localized_string("tx-create-success-title");
+	state.desc = localized_string("tx-tor-sending-desc");
+
+	let w = grin_gui.wallet_interface.clone();
+	let socks_proxy_addr = grin_gui.config.tor_dispatch.socks_proxy_addr.clone();
+	let fut = grin_gui.wallet_worker.run(WalletInterface::send_tx_tor(
+		w,
+		slate,
+		dest_slatepack_address,
+		socks_proxy_addr,
+	));
+
+	Command::perform(fut, |r| match r {
+		Ok(_) => Message::Interaction(Interaction::WalletOperationShowSlatepackViewInteraction(
+			LocalViewInteraction::TorDispatchOk,
+		)),
+		Err(GrinWalletInterfaceError::RecipientUnreachable(reason)) => {
+			Message::Interaction(Interaction::WalletOperationShowSlatepackViewInteraction(
+				LocalViewInteraction::RecipientUnreachable(reason),
+			))
+		}
+		Err(GrinWalletInterfaceError::RecipientResponse(reason)) => {
+			Message::Interaction(Interaction::WalletOperationShowSlatepackViewInteraction(
+				LocalViewInteraction::RecipientResponse(reason),
+			))
+		}
+		Err(e) => Message::Interaction(Interaction::WalletOperationShowSlatepackViewInteraction(
+			LocalViewInteraction::TorDispatchError(Arc::new(RwLock::new(Some(
+				anyhow::Error::from(e).context("Failed to finalize transaction sent over Tor"),
+			)))),
+		)),
+	})
+}
+
 pub fn data_container<'a>(
 	_config: &'a Config,
 	state: &'a StateContainer,
@@ -135,6 +310,59 @@ pub fn data_container<'a>(
 	.max_width(400.0)
 	.style(grin_gui_core::theme::CardStyle::Normal);
 
+	// QR code of the slatepack, split over several frames when too long for a single code
+	let mut qr_column = Column::new()
+		.spacing(10)
+		.width(Length::Fill)
+		.align_items(Alignment::Center);
+
+	if let Some(frame) = state.qr_frames.get(state.qr_frame) {
+		qr_column = qr_column.push(QrCode::new(frame).cell_size(3.0));
+
+		if state.qr_frames.len() > 1 {
+			let prev_button: Element<Interaction> = Button::new(
+				Text::new("<")
+					.size(SMALLER_FONT_SIZE)
+					.horizontal_alignment(alignment::Horizontal::Center),
+			)
+			.style(grin_gui_core::theme::ButtonStyle::NormalText)
+			.on_press(Interaction::WalletOperationShowSlatepackViewInteraction(
+				LocalViewInteraction::PrevQrFrame,
+			))
+			.into();
+
+			let next_button: Element<Interaction> = Button::new(
+				Text::new(">")
+					.size(SMALLER_FONT_SIZE)
+					.horizontal_alignment(alignment::Horizontal::Center),
+			)
+			.style(grin_gui_core::theme::ButtonStyle::NormalText)
+			.on_press(Interaction::WalletOperationShowSlatepackViewInteraction(
+				LocalViewInteraction::NextQrFrame,
+			))
+			.into();
+
+			let frame_label = Text::new(format!(
+				"{} {} / {}",
+				localized_string("qr-frame"),
+				state.qr_frame + 1,
+				state.qr_frames.len()
+			))
+			.size(SMALLER_FONT_SIZE);
+
+			qr_column = qr_column.push(
+				Row::new()
+					.spacing(10)
+					.align_items(Alignment::Center)
+					.push(prev_button.map(Message::Interaction))
+					.push(frame_label)
+					.push(next_button.map(Message::Interaction)),
+			);
+		}
+	}
+
+	let qr_container = Container::new(qr_column).max_width(400.0);
+
 	let unit_spacing = 15.0;
 
 	let button_height = Length::Fixed(BUTTON_HEIGHT);
@@ -163,22 +391,85 @@ pub fn data_container<'a>(
 	let unit_spacing = 15.0;
 	let button_row = Row::new().push(cancel_container);
 
-	let column = Column::new()
-		.push(description_container)
-		.push(Space::new(
-			Length::Fixed(0.0),
-			Length::Fixed(unit_spacing + 5.0),
+	let save_contact_row = state.save_contact_address.as_ref().map(|address| {
+		let prompt = Text::new(format!(
+			"{} {}",
+			localized_string("contacts-save-prompt"),
+			super::contacts::short_address(address)
 		))
-		.push(encrypted_slate_card)
-		.push(Space::new(
-			Length::Fixed(0.0),
-			Length::Fixed(unit_spacing + 10.0),
+		.size(DEFAULT_FONT_SIZE);
+
+		let name_input: Element<Interaction> =
+			TextInput::new(&localized_string("contacts-name"), &state.save_contact_name)
+				.on_input(|s| {
+					Interaction::WalletOperationShowSlatepackViewInteraction(
+						LocalViewInteraction::SaveContactName(s),
+					)
+				})
+				.on_submit(Interaction::WalletOperationShowSlatepackViewInteraction(
+					LocalViewInteraction::SaveContact,
+				))
+				.size(DEFAULT_FONT_SIZE)
+				.padding(6)
+				.width(Length::Fixed(200.0))
+				.style(grin_gui_core::theme::TextInputStyle::AddonsQuery)
+				.into();
+
+		let save_button: Element<Interaction> =
+			Button::new(Text::new(localized_string("contacts-add")).size(SMALLER_FONT_SIZE))
+				.style(grin_gui_core::theme::ButtonStyle::Bordered)
+				.on_press(Interaction::WalletOperationShowSlatepackViewInteraction(
+					LocalViewInteraction::SaveContact,
+				))
+				.padding(2)
+				.into();
+
+		let dismiss_button: Element<Interaction> = Button::new(
+			Text::new(localized_string("contacts-save-dismiss")).size(SMALLER_FONT_SIZE),
+		)
+		.style(grin_gui_core::theme::ButtonStyle::Bordered)
+		.on_press(Interaction::WalletOperationShowSlatepackViewInteraction(
+			LocalViewInteraction::DismissSaveContact,
 		))
-		.push(button_row)
-		.push(Space::new(
-			Length::Fixed(0.0),
-			Length::Fixed(unit_spacing + 10.0),
-		));
+		.padding(2)
+		.into();
+
+		Row::new()
+			.spacing(5)
+			.align_items(Alignment::Center)
+			.push(prompt)
+			.push(name_input.map(Message::Interaction))
+			.push(save_button.map(Message::Interaction))
+			.push(dismiss_button.map(Message::Interaction))
+	});
+
+	let mut column = Column::new().push(description_container).push(Space::new(
+		Length::Fixed(0.0),
+		Length::Fixed(unit_spacing + 5.0),
+	));
+
+	// Nothing to hand over while Tor delivery is pending or once it succeeded
+	if !state.dispatching && state.encrypted_slate.is_some() {
+		column = column
+			.push(encrypted_slate_card)
+			.push(Space::new(Length::Fixed(0.0), Length::Fixed(unit_spacing)))
+			.push(qr_container)
+			.push(Space::new(
+				Length::Fixed(0.0),
+				Length::Fixed(unit_spacing + 10.0),
+			));
+	}
+
+	if let Some(row) = save_contact_row {
+		column = column
+			.push(row)
+			.push(Space::new(Length::Fixed(0.0), Length::Fixed(unit_spacing)));
+	}
+
+	column = column.push(button_row).push(Space::new(
+		Length::Fixed(0.0),
+		Length::Fixed(unit_spacing + 10.0),
+	));
 
 	let form_container = Container::new(column)
 		.width(Length::Fill)
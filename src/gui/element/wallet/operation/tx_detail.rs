@@ -4,7 +4,9 @@ use async_std::prelude::FutureExt;
 use grin_gui_core::{
 	config::Config,
 	error::GrinWalletInterfaceError,
-	wallet::{TxLogEntry, TxLogEntryType},
+	wallet::contacts::AddressBook,
+	wallet::proof::ProofFile,
+	wallet::{PaymentProof, TxLogEntry, TxLogEntryType},
 };
 use grin_gui_widgets::widget::header;
 use iced_aw::Card;
@@ -42,29 +44,59 @@ use {
 pub struct StateContainer {
 	// Transaction that we're viewing
 	pub current_tx: Option<TxLogEntry>,
+	// Payment proof of the transaction, once retrieved
+	pub payment_proof: Option<PaymentProof>,
+	// Whether the proof verified with us as (sender, recipient)
+	pub proof_verified: Option<(bool, bool)>,
+	// Where the proof was last exported to
+	pub exported_to: Option<PathBuf>,
+}
+
+impl StateContainer {
+	pub fn set_tx(&mut self, tx: TxLogEntry) {
+		self.current_tx = Some(tx);
+		self.payment_proof = None;
+		self.proof_verified = None;
+		self.exported_to = None;
+	}
 }
 
 impl Default for StateContainer {
 	fn default() -> Self {
 		Self {
 			current_tx: Default::default(),
+			payment_proof: Default::default(),
+			proof_verified: Default::default(),
+			exported_to: Default::default(),
 		}
 	}
 }
 
+/// Only confirmed sends made with a recipient address have a proof to retrieve
+fn has_payment_proof(tx: &TxLogEntry) -> bool {
+	tx.confirmed && tx.tx_type == TxLogEntryType::TxSent && tx.payment_proof.is_some()
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum Action {}
 
 #[derive(Debug, Clone)]
 pub enum LocalViewInteraction {
 	Back,
+	RetrievePaymentProof,
+	PaymentProofRetrievedOk(PaymentProof),
+	VerifyPaymentProof,
+	PaymentProofVerifiedOk((bool, bool)),
+	/// Export to the wallet's proof directory, or a chosen path when set
+	ExportPaymentProof(bool),
+	PaymentProofError(Arc<RwLock<Option<anyhow::Error>>>),
 }
 
 pub fn handle_message<'a>(
 	grin_gui: &mut GrinGui,
 	message: LocalViewInteraction,
 ) -> Result<Command<Message>> {
-	let state = &mut grin_gui.wallet_state.operation_state.create_tx_state;
+	let state = &mut grin_gui.wallet_state.operation_state.tx_detail_state;
 
 	match message {
 		LocalViewInteraction::Back => {
@@ -72,12 +104,101 @@ pub fn handle_message<'a>(
 			grin_gui.wallet_state.operation_state.mode =
 				crate::gui::element::wallet::operation::Mode::Home;
 		}
+		LocalViewInteraction::RetrievePaymentProof => {
+			log::debug!(
+				"Interaction::WalletOperationTxDetailViewInteraction(RetrievePaymentProof)"
+			);
+			let tx_id = match state.current_tx {
+				Some(ref tx) => tx.id,
+				None => return Ok(Command::none()),
+			};
+			grin_gui.error.take();
+
+			let w = grin_gui.wallet_interface.clone();
+			let fut = grin_gui
+				.wallet_worker
+				.run(WalletInterface::retrieve_payment_proof(w, tx_id));
+
+			return Ok(Command::perform(fut, |r| {
+				match r.context("Failed to Retrieve Payment Proof") {
+					Ok(proof) => {
+						Message::Interaction(Interaction::WalletOperationTxDetailViewInteraction(
+							LocalViewInteraction::PaymentProofRetrievedOk(proof),
+						))
+					}
+					Err(e) => {
+						Message::Interaction(Interaction::WalletOperationTxDetailViewInteraction(
+							LocalViewInteraction::PaymentProofError(Arc::new(RwLock::new(Some(e)))),
+						))
+					}
+				}
+			}));
+		}
+		LocalViewInteraction::PaymentProofRetrievedOk(proof) => {
+			state.payment_proof = Some(proof);
+			state.proof_verified = None;
+		}
+		LocalViewInteraction::VerifyPaymentProof => {
+			log::debug!("Interaction::WalletOperationTxDetailViewInteraction(VerifyPaymentProof)");
+			let proof = match state.payment_proof {
+				Some(ref p) => p.clone(),
+				None => return Ok(Command::none()),
+			};
+			grin_gui.error.take();
+
+			let w = grin_gui.wallet_interface.clone();
+			let fut = grin_gui
+				.wallet_worker
+				.run(WalletInterface::verify_payment_proof(w, proof));
+
+			return Ok(Command::perform(fut, |r| {
+				match r.context("Failed to Verify Payment Proof") {
+					Ok(ret) => {
+						Message::Interaction(Interaction::WalletOperationTxDetailViewInteraction(
+							LocalViewInteraction::PaymentProofVerifiedOk(ret),
+						))
+					}
+					Err(e) => {
+						Message::Interaction(Interaction::WalletOperationTxDetailViewInteraction(
+							LocalViewInteraction::PaymentProofError(Arc::new(RwLock::new(Some(e)))),
+						))
+					}
+				}
+			}));
+		}
+		LocalViewInteraction::PaymentProofVerifiedOk(ret) => {
+			state.proof_verified = Some(ret);
+		}
+		LocalViewInteraction::ExportPaymentProof(choose_path) => {
+			log::debug!(
+				"Interaction::WalletOperationTxDetailViewInteraction(ExportPaymentProof({}))",
+				choose_path
+			);
+			if let (Some(tx), Some(proof)) = (&state.current_tx, &state.payment_proof) {
+				let proof = ProofFile::Payment(proof.clone());
+				if let Some(path) =
+					super::tx_proof::export_proof(&grin_gui.config, tx, &proof, choose_path)?
+				{
+					state.exported_to = Some(path);
+				}
+			}
+		}
+		LocalViewInteraction::PaymentProofError(err) => {
+			grin_gui.error = err.write().unwrap().take();
+			if let Some(e) = grin_gui.error.as_ref() {
+				log_error(e);
+			}
+		}
 	}
 
 	Ok(Command::none())
 }
 
-pub fn data_container<'a>(config: &'a Config, state: &'a StateContainer) -> Container<'a, Message> {
+pub fn data_container<'a>(
+	config: &'a Config,
+	state: &'a StateContainer,
+	address_book: &'a AddressBook,
+) -> Container<'a, Message> {
 	// Title row
 	let title = Text::new(localized_string("tx-details-title"))
 		.size(DEFAULT_HEADER_FONT_SIZE)
@@ -154,6 +275,32 @@ pub fn data_container<'a>(config: &'a Config, state: &'a StateContainer) -> Cont
 			.push(tx_type_row)
 			.push(Space::new(Length::Fixed(0.0), Length::Fixed(row_spacing)));
 
+		// Other party, by contact name where known
+		if let Some(counterparty) = tx_list::counterparty_display(tx, address_book) {
+			let counterparty_label =
+				Text::new(format!("{}:  ", localized_string("tx-counterparty")))
+					.size(DEFAULT_FONT_SIZE)
+					.horizontal_alignment(alignment::Horizontal::Left);
+
+			let counterparty_label_container = Container::new(counterparty_label)
+				.style(grin_gui_core::theme::ContainerStyle::NormalBackground);
+
+			let counterparty_value = Text::new(counterparty)
+				.size(DEFAULT_FONT_SIZE)
+				.horizontal_alignment(alignment::Horizontal::Left);
+
+			let counterparty_value_container = Container::new(counterparty_value)
+				.style(grin_gui_core::theme::ContainerStyle::NormalBackground);
+
+			let counterparty_row = Row::new()
+				.push(counterparty_label_container)
+				.push(counterparty_value_container);
+
+			column = column
+				.push(counterparty_row)
+				.push(Space::new(Length::Fixed(0.0), Length::Fixed(row_spacing)));
+		}
+
 		// UUID
 		let shared_tx_id_label = Text::new(format!("{}:  ", localized_string("tx-shared-id")))
 			.size(DEFAULT_FONT_SIZE)
@@ -435,6 +582,123 @@ pub fn data_container<'a>(config: &'a Config, state: &'a StateContainer) -> Cont
 		column = column
 			.push(tx_net_difference_row)
 			.push(Space::new(Length::Fixed(0.0), Length::Fixed(unit_spacing)));
+
+		// Payment Proof
+		if has_payment_proof(tx) {
+			match state.payment_proof {
+				None => {
+					let retrieve_proof_label_container = Container::new(
+						Text::new(localized_string("tx-payment-proof-retrieve"))
+							.size(DEFAULT_FONT_SIZE),
+					)
+					.width(Length::Fixed(BUTTON_WIDTH * 2.0))
+					.height(button_height)
+					.center_x()
+					.center_y()
+					.align_x(alignment::Horizontal::Center);
+
+					let retrieve_proof_button: Element<Interaction> =
+						Button::new(retrieve_proof_label_container)
+							.style(grin_gui_core::theme::ButtonStyle::Primary)
+							.on_press(Interaction::WalletOperationTxDetailViewInteraction(
+								LocalViewInteraction::RetrievePaymentProof,
+							))
+							.into();
+
+					let retrieve_proof_container =
+						Container::new(retrieve_proof_button.map(Message::Interaction)).padding(1);
+					let retrieve_proof_container = Container::new(retrieve_proof_container)
+						.style(grin_gui_core::theme::ContainerStyle::Segmented)
+						.padding(1);
+
+					column = column
+						.push(retrieve_proof_container)
+						.push(Space::new(Length::Fixed(0.0), Length::Fixed(unit_spacing)));
+				}
+				Some(ref proof) => {
+					let card_contents = serde_json::to_string_pretty(proof).unwrap_or_default();
+
+					let verify_button = Button::new(
+						Text::new(localized_string("tx-payment-proof-verify"))
+							.size(SMALLER_FONT_SIZE)
+							.horizontal_alignment(alignment::Horizontal::Center),
+					)
+					.style(grin_gui_core::theme::ButtonStyle::NormalText)
+					.on_press(Message::Interaction(
+						Interaction::WalletOperationTxDetailViewInteraction(
+							LocalViewInteraction::VerifyPaymentProof,
+						),
+					));
+
+					let export_button = super::tx_proof::proof_export_button(
+						"tx-proof-export",
+						Interaction::WalletOperationTxDetailViewInteraction(
+							LocalViewInteraction::ExportPaymentProof(false),
+						),
+					);
+
+					let export_as_button = super::tx_proof::proof_export_button(
+						"tx-proof-export-as",
+						Interaction::WalletOperationTxDetailViewInteraction(
+							LocalViewInteraction::ExportPaymentProof(true),
+						),
+					);
+
+					let copy_button = Button::new(
+						Text::new(localized_string("copy-to-clipboard"))
+							.size(SMALLER_FONT_SIZE)
+							.horizontal_alignment(alignment::Horizontal::Center),
+					)
+					.style(grin_gui_core::theme::ButtonStyle::NormalText)
+					.on_press(Message::Interaction(Interaction::WriteToClipboard(
+						card_contents.clone(),
+					)));
+
+					let json_proof_card = Card::new(
+						Text::new(localized_string("tx-payment-proof"))
+							.size(DEFAULT_HEADER_FONT_SIZE),
+						Text::new(card_contents).size(DEFAULT_FONT_SIZE),
+					)
+					.foot(
+						Row::new()
+							.spacing(10)
+							.padding(5)
+							.width(Length::Fill)
+							.align_items(Alignment::Center)
+							.push(copy_button)
+							.push(export_button)
+							.push(export_as_button)
+							.push(verify_button),
+					)
+					.max_width(400.0)
+					.style(grin_gui_core::theme::CardStyle::Normal);
+
+					column = column
+						.push(Row::new().push(json_proof_card))
+						.push(Space::new(Length::Fixed(0.0), Length::Fixed(row_spacing)));
+
+					if let Some((is_sender, is_recipient)) = state.proof_verified {
+						let result_key = if is_sender {
+							"tx-payment-proof-valid-sender"
+						} else if is_recipient {
+							"tx-payment-proof-valid-recipient"
+						} else {
+							"tx-payment-proof-valid"
+						};
+						let result_text =
+							Text::new(localized_string(result_key)).size(DEFAULT_FONT_SIZE);
+						column = column.push(result_text);
+					}
+
+					if let Some(ref path) = state.exported_to {
+						column = column.push(super::tx_proof::exported_to_text(path));
+					}
+
+					column =
+						column.push(Space::new(Length::Fixed(0.0), Length::Fixed(unit_spacing)));
+				}
+			}
+		}
 	}
 
 	let cancel_button_label_container =
@@ -14,14 +14,19 @@ use {
 	},
 	grin_gui_core::{
 		config::Config,
+		export::{kernel_excess_hex, ExportColumn},
 		node::amount_to_hr_string,
 		theme::{ButtonStyle, ColorPalette, ContainerStyle},
-		wallet::TxLogEntry,
+		wallet::{
+			contacts::{counterparty_address, AddressBook},
+			TxLogEntry,
+		},
 	},
 	grin_gui_widgets::widget::header,
 	iced::widget::{button, pick_list, scrollable, text_input, Space},
 	iced::{alignment, Alignment, Command, Length},
 	serde::{Deserialize, Serialize},
+	serde_json::{json, Value},
 	std::collections::HashMap,
 	strfmt::strfmt,
 };
@@ -75,6 +80,7 @@ pub enum ColumnKey {
 	PaymentProof,
 	Kernel,
 	TxData,
+	Counterparty,
 	// Only used for sorting, not an actual visible column that can be shown
 	FuzzyScore,
 }
@@ -102,6 +108,7 @@ impl ColumnKey {
 			PaymentProof => localized_string("tx_payment_proof"),
 			Kernel => localized_string("tx_kernel"),
 			TxData => localized_string("tx_data"),
+			Counterparty => localized_string("tx-counterparty"),
 			FuzzyScore => unreachable!("fuzzy score not used as an actual column"),
 		}
 	}
@@ -128,11 +135,57 @@ impl ColumnKey {
 			PaymentProof => "tx_payment_proof",
 			Kernel => "tx_kernel",
 			TxData => "tx_data",
+			Counterparty => "tx-counterparty",
 			FuzzyScore => unreachable!("fuzzy score not used as an actual column"),
 		};
 
 		s.to_string()
 	}
+
+	/// Columns written by an export even when hidden in the list
+	pub const EXPORT_ALWAYS: [ColumnKey; 6] = [
+		ColumnKey::AmountCredited,
+		ColumnKey::AmountDebited,
+		ColumnKey::Fee,
+		ColumnKey::ConfirmationTime,
+		ColumnKey::Kernel,
+		ColumnKey::PaymentProof,
+	];
+
+	pub fn export_column(self) -> ExportColumn {
+		ExportColumn {
+			key: self.as_string(),
+			title: self.title(),
+		}
+	}
+
+	/// Value of this column for `tx` in an export. Amounts keep full precision
+	pub fn export_value(self, tx: &TxLogEntry) -> Value {
+		use ColumnKey::*;
+
+		match self {
+			Id => json!(tx.id),
+			Type => json!(tx.tx_type.to_string().replace("\n", "")),
+			SharedTransactionId => json!(tx.tx_slate_id.map(|t| t.to_string())),
+			CreationTime => json!(tx.creation_ts.to_rfc3339()),
+			Status => json!(create_tx_display_status(tx)),
+			TTLCutoff => json!(tx.ttl_cutoff_height),
+			Height => json!(tx.kernel_lookup_min_height),
+			IsConfirmed => json!(tx.confirmed),
+			ConfirmationTime => json!(tx.confirmation_ts.map(|t| t.to_rfc3339())),
+			NumInputs => json!(tx.num_inputs),
+			NumOutputs => json!(tx.num_outputs),
+			AmountCredited => json!(amount_to_hr_string(tx.amount_credited, false)),
+			AmountDebited => json!(amount_to_hr_string(tx.amount_debited, false)),
+			Fee => json!(tx.fee.map(|f| amount_to_hr_string(f.fee(), false))),
+			NetDifference => json!(net_difference(tx, false)),
+			PaymentProof => json!(tx.payment_proof.is_some()),
+			Kernel => json!(kernel_excess_hex(tx)),
+			TxData => json!(tx.stored_tx.is_some()),
+			Counterparty => json!(counterparty_address(tx)),
+			FuzzyScore => unreachable!("fuzzy score not used as an actual column"),
+		}
+	}
 }
 
 impl From<&str> for ColumnKey {
@@ -156,6 +209,7 @@ impl From<&str> for ColumnKey {
 			"tx_payment_proof" => ColumnKey::PaymentProof,
 			"tx_kernel" => ColumnKey::Kernel,
 			"tx_data" => ColumnKey::TxData,
+			"tx-counterparty" => ColumnKey::Counterparty,
 			_ => panic!("Unknown ColumnKey for {}", s),
 		}
 	}
@@ -168,7 +222,7 @@ pub enum SortDirection {
 }
 
 impl SortDirection {
-	fn toggle(self) -> SortDirection {
+	pub fn toggle(self) -> SortDirection {
 		match self {
 			SortDirection::Asc => SortDirection::Desc,
 			SortDirection::Desc => SortDirection::Asc,
@@ -391,6 +445,13 @@ impl Default for HeaderState {
 					hidden: true,
 					order: 17,
 				},
+				ColumnState {
+					key: ColumnKey::Counterparty,
+					//  btn_state: Default::default(),
+					width: Length::Fixed(150.0),
+					hidden: false,
+					order: 18,
+				},
 			],
 		}
 	}
@@ -486,6 +547,10 @@ impl Default for ColumnSettings {
 					key: ColumnKey::TxData,
 					order: 17,
 				},
+				ColumnSettingState {
+					key: ColumnKey::Counterparty,
+					order: 18,
+				},
 			],
 		}
 	}
@@ -517,6 +582,7 @@ pub enum TxListColumnKey {
 	PaymentProof,
 	Kernel,
 	TxData,
+	Counterparty,
 }
 
 impl TxListColumnKey {
@@ -541,6 +607,7 @@ impl TxListColumnKey {
 			PaymentProof => localized_string("tx_payment_proof"),
 			Kernel => localized_string("tx_kernel"),
 			TxData => localized_string("tx_data"),
+			Counterparty => localized_string("tx-counterparty"),
 		}
 	}
 
@@ -565,6 +632,7 @@ impl TxListColumnKey {
 			PaymentProof => "tx_payment_proof",
 			Kernel => "tx_kernel",
 			TxData => "tx_data",
+			Counterparty => "tx-counterparty",
 		};
 
 		s.to_string()
@@ -591,6 +659,7 @@ impl From<&str> for TxListColumnKey {
 			"tx_payment_proof" => TxListColumnKey::PaymentProof,
 			"tx_kernel" => TxListColumnKey::Kernel,
 			"tx_data" => TxListColumnKey::TxData,
+			"tx-counterparty" => TxListColumnKey::Counterparty,
 			_ => panic!("Unknown CatalogTxListColumnKey for {}", s),
 		}
 	}
@@ -751,6 +820,13 @@ impl Default for TxListHeaderState {
 					hidden: true,
 					order: 17,
 				},
+				TxListColumnState {
+					key: ColumnKey::Counterparty,
+					//  btn_state: Default::default(),
+					width: Length::Fixed(110.0),
+					hidden: true,
+					order: 18,
+				},
 			],
 		}
 	}
@@ -836,6 +912,10 @@ impl Default for TxListColumnSettings {
 					key: ColumnKey::TxData,
 					order: 17,
 				},
+				TxListColumnSettingState {
+					key: ColumnKey::Counterparty,
+					order: 18,
+				},
 			],
 		}
 	}
@@ -960,6 +1040,23 @@ pub fn titles_row_header<'a>(
 	})*/
 }
 
+/// Credited minus debited amount, negative for sends
+pub fn net_difference(tx: &TxLogEntry, truncate: bool) -> String {
+	if tx.amount_credited >= tx.amount_debited {
+		amount_to_hr_string(tx.amount_credited - tx.amount_debited, truncate)
+	} else {
+		format!(
+			"-{}",
+			amount_to_hr_string(tx.amount_debited - tx.amount_credited, truncate)
+		)
+	}
+}
+
+/// Contact name of the other party, or their address if they're not in the address book
+pub fn counterparty_display(tx: &TxLogEntry, address_book: &AddressBook) -> Option<String> {
+	counterparty_address(tx).map(|address| address_book.name_for(&address).unwrap_or(address))
+}
+
 //TODO: Move somewhere else
 pub fn create_tx_display_status(log_entry: &TxLogEntry) -> String {
 	if log_entry.confirmed {
@@ -979,6 +1076,7 @@ pub fn data_row_container<'a, 'b>(
 	is_odd: Option<bool>,
 	pending_confirmation: &Option<Confirm>,
 	node_synched: bool,
+	address_book: &AddressBook,
 ) -> Container<'a, Message> {
 	let default_height = Length::Fixed(26.0);
 	let mut default_row_height = 26;
@@ -1003,24 +1101,13 @@ pub fn data_row_container<'a, 'b>(
 
 	let creation_time = tx_log_entry_wrap.tx.creation_ts.to_string();
 	let confirmation_time = tx_log_entry_wrap.tx.creation_ts.to_string();
-	let net_diff = if tx_log_entry_wrap.tx.amount_credited >= tx_log_entry_wrap.tx.amount_debited {
-		amount_to_hr_string(
-			tx_log_entry_wrap.tx.amount_credited - tx_log_entry_wrap.tx.amount_debited,
-			true,
-		)
-	} else {
-		format!(
-			"-{}",
-			amount_to_hr_string(
-				tx_log_entry_wrap.tx.amount_debited - tx_log_entry_wrap.tx.amount_credited,
-				true
-			)
-		)
-	};
+	let net_diff = net_difference(&tx_log_entry_wrap.tx, true);
 	//TODO this will show the latest status
 	// Unconfirmed - Created time
 	// Confirmed
 	let status = create_tx_display_status(&tx_log_entry_wrap.tx);
+	let counterparty = counterparty_display(&tx_log_entry_wrap.tx, address_book)
+		.unwrap_or_else(|| "-".to_string());
 
 	/*let version = tx
 		.version()
@@ -1189,6 +1276,30 @@ pub fn data_row_container<'a, 'b>(
 		row_containers.push((idx, display_status_container));
 	}
 
+	if let Some((idx, width)) = column_config
+		.iter()
+		.enumerate()
+		.filter_map(|(idx, (key, width, hidden))| {
+			if *key == ColumnKey::Counterparty && !hidden {
+				Some((idx, width))
+			} else {
+				None
+			}
+		})
+		.next()
+	{
+		let display_counterparty = Text::new(counterparty).size(DEFAULT_FONT_SIZE);
+
+		let display_counterparty_container = Container::new(display_counterparty)
+			.padding(5)
+			.height(default_height)
+			.width(*width)
+			.center_y()
+			.style(grin_gui_core::theme::ContainerStyle::HoverableForeground);
+
+		row_containers.push((idx, display_counterparty_container));
+	}
+
 	/*if let Some((idx, width)) = column_config
 		.iter()
 		.enumerate()
@@ -3,15 +3,18 @@ use crate::log_error;
 use async_std::prelude::FutureExt;
 use chrono::DurationRound;
 use grin_gui_core::{
-	config::Config,
-	wallet::{TxLogEntry, TxLogEntryType},
+	config::{Config, Currency},
+	export::{export_table, ExportColumn, ExportFormat},
+	wallet::{contacts::AddressBook, TxLogEntry, TxLogEntryType},
 };
 use grin_gui_widgets::widget::header;
 use iced_aw::Card;
 use iced_core::Widget;
+use native_dialog::FileDialog;
+use serde_json::{json, Value};
 use std::{borrow::Borrow, path::PathBuf, str::FromStr};
 
-use super::tx_list::{HeaderState, TxList, TxLogEntryWrap};
+use super::tx_list::{ColumnKey, HeaderState, TxList, TxLogEntryWrap};
 
 use {
 	super::super::super::{
@@ -68,6 +71,105 @@ impl Default for StateContainer {
 	}
 }
 
+impl StateContainer {
+	/// Apply transactions that changed since the list was loaded, without reloading it.
+	/// Ones no longer matching the selected mode are dropped from the list
+	pub fn apply_changed_txs(&mut self, txs: Vec<TxLogEntry>) {
+		if self.mode == Mode::NotInit {
+			return;
+		}
+		for tx in txs.iter() {
+			let listed = self.wallet_txs.txs.iter().position(|t| t.tx.id == tx.id);
+			match (listed, self.is_listed(tx)) {
+				(Some(i), true) => self.wallet_txs.txs[i] = TxLogEntryWrap::new(tx.clone()),
+				(Some(i), false) => {
+					self.wallet_txs.txs.remove(i);
+				}
+				(None, true) => self.wallet_txs.txs.push(TxLogEntryWrap::new(tx.clone())),
+				(None, false) => {}
+			}
+		}
+		// Listed newest first, as the query sorts them
+		self.wallet_txs.txs.sort_by(|a, b| b.tx.id.cmp(&a.tx.id));
+		self.update_balance_data(&txs);
+	}
+
+	/// Whether the query of the selected mode would return `tx`
+	fn is_listed(&self, tx: &TxLogEntry) -> bool {
+		let cancelled = matches!(
+			tx.tx_type,
+			TxLogEntryType::TxSentCancelled | TxLogEntryType::TxReceivedCancelled
+		);
+		if cancelled && self.query_args.exclude_cancelled == Some(true) {
+			return false;
+		}
+		!(tx.confirmed && self.query_args.include_outstanding_only == Some(true))
+	}
+
+	/// Add newly confirmed transactions to the balance history
+	fn update_balance_data(&mut self, txs: &[TxLogEntry]) {
+		let confirmed_txns: Vec<&TxLogEntry> = txs.iter().filter(|tx| tx.confirmed).collect();
+
+		if !confirmed_txns.is_empty() {
+			// added new confirmed transactions to state confirmed set?
+			let mut added = false;
+
+			for tx in confirmed_txns.iter() {
+				// if tx is not in state confirmed transactions, add it
+				if self.confirmed_txns.iter().find(|t| t.id == tx.id).is_none() {
+					// push to state confirmed transactions
+					self.confirmed_txns.push(tx.clone().to_owned());
+					added = true;
+					debug!("Confirmed Tx: {:?}", tx);
+				}
+			}
+
+			if added {
+				// sort state transactions by date
+				self.confirmed_txns
+					.sort_by(|a, b| a.confirmation_ts.unwrap().cmp(&b.confirmation_ts.unwrap()));
+
+				let mut datetime_sums = vec![];
+				for tx in self.confirmed_txns.iter() {
+					// trunc transaction date to day
+					//let datetime = tx.confirmation_ts.unwrap().duration_trunc(chrono::Duration::days(1)).unwrap();
+					// this should be the date time above but for dev purposes lets backdate it
+					let datetime = chrono::DateTime::from_str("2019-01-20T00:00:00Z").unwrap();
+					let credits = tx.amount_credited;
+					let debits = tx.amount_debited;
+
+					datetime_sums.push((datetime, credits as i64 - debits as i64));
+				}
+
+				let mut sum = 0;
+				let mut dt = datetime_sums.first().unwrap().0;
+				let today = chrono::Utc::now()
+					.duration_trunc(chrono::Duration::days(1))
+					.unwrap();
+
+				// fill in sum data for days without transactions
+				let mut balance_history = vec![];
+				while dt <= today {
+					// get all transactions for this date
+					let txns = datetime_sums.iter().filter(|(date, _)| *date == dt);
+
+					// sum up balance amount
+					sum = sum + txns.map(|x| x.1).collect::<Vec<_>>().iter().sum::<i64>();
+
+					// convert to grin units
+					let grin_sum = (sum as f64 / grin_gui_core::GRIN_BASE as f64) as f64;
+					balance_history.push((dt.to_owned(), grin_sum));
+
+					dt = dt + chrono::Duration::days(1);
+				}
+
+				// finally we update state with the newly constructed balance history
+				self.balance_data = balance_history;
+			}
+		}
+	}
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum Action {}
 
@@ -82,6 +184,8 @@ pub enum Mode {
 pub enum LocalViewInteraction {
 	SelectMode(Mode),
 	RefreshList,
+	Export(ExportFormat),
+	ExportProofs,
 	TxListUpdateSuccess(bool, Vec<TxLogEntry>),
 	TxListUpdateFailure(Arc<RwLock<Option<anyhow::Error>>>),
 }
@@ -128,8 +232,10 @@ pub fn handle_message<'a>(
 		LocalViewInteraction::RefreshList => {
 			let w = grin_gui.wallet_interface.clone();
 
-			let fut = move || WalletInterface::get_txs(w, Some(state.query_args.clone()));
-			return Ok(Command::perform(fut(), |tx_list_res| {
+			let fut = grin_gui
+				.wallet_worker
+				.run(WalletInterface::get_txs(w, Some(state.query_args.clone())));
+			return Ok(Command::perform(fut, |tx_list_res| {
 				if tx_list_res.is_err() {
 					let e = tx_list_res
 						.context("Failed to retrieve transaction list")
@@ -149,6 +255,27 @@ pub fn handle_message<'a>(
 				))
 			}));
 		}
+		LocalViewInteraction::ExportProofs => {
+			grin_gui.wallet_state.operation_state.mode =
+				crate::gui::element::wallet::operation::Mode::ExportProofs;
+		}
+		LocalViewInteraction::Export(format) => {
+			log::debug!(
+				"Interaction::WalletOperationHomeTxListDisplayInteraction(Export({}))",
+				format
+			);
+			let path = match FileDialog::new()
+				.add_filter(&format.to_string(), &[format.file_ext()])
+				.show_save_single_file()
+			{
+				Ok(Some(p)) => p.with_extension(format.file_ext()),
+				_ => return Ok(Command::none()),
+			};
+			let home_state = &grin_gui.wallet_state.operation_state.home_state;
+			let exported = export_txs(home_state, grin_gui.config.currency, format)?;
+			std::fs::write(&path, exported)?;
+			log::info!("Exported transaction history to {}", path.display());
+		}
 		LocalViewInteraction::TxListUpdateSuccess(node_success, txs) => {
 			debug!("Update Tx List Summary: {}", node_success);
 			debug!("Update Wallet Txs Summary: {:?}", txs);
@@ -158,71 +285,7 @@ pub fn handle_message<'a>(
 				.collect();
 			state.wallet_txs = TxList { txs: tx_wrap_list };
 
-			let confirmed_txns: Vec<&TxLogEntry> = txs.iter().filter(|tx| tx.confirmed).collect();
-
-			if !confirmed_txns.is_empty() {
-				// added new confirmed transactions to state confirmed set?
-				let mut added = false;
-
-				for tx in confirmed_txns.iter() {
-					// if tx is not in state confirmed transactions, add it
-					if state
-						.confirmed_txns
-						.iter()
-						.find(|t| t.id == tx.id)
-						.is_none()
-					{
-						// push to state confirmed transactions
-						state.confirmed_txns.push(tx.clone().to_owned());
-						added = true;
-						debug!("Confirmed Tx: {:?}", tx);
-					}
-				}
-
-				if added {
-					// sort state transactions by date
-					state.confirmed_txns.sort_by(|a, b| {
-						a.confirmation_ts.unwrap().cmp(&b.confirmation_ts.unwrap())
-					});
-
-					let mut datetime_sums = vec![];
-					for tx in state.confirmed_txns.iter() {
-						// trunc transaction date to day
-						//let datetime = tx.confirmation_ts.unwrap().duration_trunc(chrono::Duration::days(1)).unwrap();
-						// this should be the date time above but for dev purposes lets backdate it
-						let datetime = chrono::DateTime::from_str("2019-01-20T00:00:00Z").unwrap();
-						let credits = tx.amount_credited;
-						let debits = tx.amount_debited;
-
-						datetime_sums.push((datetime, credits as i64 - debits as i64));
-					}
-
-					let mut sum = 0;
-					let mut dt = datetime_sums.first().unwrap().0;
-					let today = chrono::Utc::now()
-						.duration_trunc(chrono::Duration::days(1))
-						.unwrap();
-
-					// fill in sum data for days without transactions
-					let mut balance_history = vec![];
-					while dt <= today {
-						// get all transactions for this date
-						let txns = datetime_sums.iter().filter(|(date, _)| *date == dt);
-
-						// sum up balance amount
-						sum = sum + txns.map(|x| x.1).collect::<Vec<_>>().iter().sum::<i64>();
-
-						// convert to grin units
-						let grin_sum = (sum as f64 / grin_gui_core::GRIN_BASE as f64) as f64;
-						balance_history.push((dt.to_owned(), grin_sum));
-
-						dt = dt + chrono::Duration::days(1);
-					}
-
-					// finally we update state with the newly constructed balance history
-					state.balance_data = balance_history;
-				}
-			}
+			state.update_balance_data(&txs);
 		}
 		LocalViewInteraction::TxListUpdateFailure(err) => {
 			grin_gui.error = err.write().unwrap().take();
@@ -235,10 +298,69 @@ pub fn handle_message<'a>(
 	Ok(Command::none())
 }
 
+/// The listed transactions in the order shown, with the visible columns followed by any
+/// of `ColumnKey::EXPORT_ALWAYS` that are hidden, and a fiat value unless showing Grin
+fn export_txs(
+	home_state: &super::home::StateContainer,
+	currency: Currency,
+	format: ExportFormat,
+) -> Result<String> {
+	let state = &home_state.tx_list_display_state;
+	let mut keys: Vec<ColumnKey> = state
+		.tx_header_state
+		.column_config()
+		.into_iter()
+		.filter(|(_, _, hidden)| !hidden)
+		.map(|(key, _, _)| key)
+		.collect();
+	for key in ColumnKey::EXPORT_ALWAYS {
+		if !keys.contains(&key) {
+			keys.push(key);
+		}
+	}
+
+	let mut columns: Vec<ExportColumn> = keys.iter().map(|k| k.export_column()).collect();
+	let with_fiat = currency != Currency::GRIN;
+	if with_fiat {
+		columns.push(ExportColumn {
+			key: format!("fiat_value_{}", currency.shortname()),
+			title: format!(
+				"{} ({})",
+				localized_string("tx-export-fiat-value"),
+				currency.shortname().to_uppercase()
+			),
+		});
+	}
+
+	let rows: Vec<Vec<Value>> = state
+		.wallet_txs
+		.txs
+		.iter()
+		.map(|t| {
+			let tx = &t.tx;
+			let mut row: Vec<Value> = keys.iter().map(|k| k.export_value(tx)).collect();
+			if with_fiat {
+				// Valued at the price of the day it confirmed, or was created if it hasn't
+				let date = tx.confirmation_ts.unwrap_or(tx.creation_ts);
+				let net = tx.amount_credited as f64 - tx.amount_debited as f64;
+				let value = home_state.price_at(date).map(|price| {
+					let value = net / grin_gui_core::GRIN_BASE as f64 * price;
+					format!("{:.1$}", value, currency.precision())
+				});
+				row.push(json!(value));
+			}
+			row
+		})
+		.collect();
+
+	Ok(export_table(format, &columns, &rows)?)
+}
+
 pub fn data_container<'a>(
 	config: &'a Config,
 	home_state: &'a super::home::StateContainer,
 	state: &'a StateContainer,
+	address_book: &'a AddressBook,
 ) -> Container<'a, Message> {
 	let button_height = Length::Fixed(BUTTON_HEIGHT);
 	let button_width = Length::Fixed(BUTTON_WIDTH);
@@ -307,11 +429,59 @@ pub fn data_container<'a>(
 		.padding(1);
 
 	// add additional buttons here
-	let button_row = Row::new()
+	let mut button_row = Row::new()
 		.push(latest_container_wrap)
 		.push(Space::with_width(Length::Fixed(DEFAULT_PADDING)))
 		.push(outstanding_container_wrap);
 
+	for format in ExportFormat::ALL {
+		let export_label_container = Container::new(
+			Text::new(format!("{} {}", localized_string("tx-export"), format))
+				.size(SMALLER_FONT_SIZE),
+		)
+		.height(Length::Fixed(14.0))
+		.center_y()
+		.center_x();
+
+		let mut export_button = Button::new(export_label_container)
+			.style(grin_gui_core::theme::ButtonStyle::Bordered)
+			.padding(2);
+		if !state.wallet_txs.txs.is_empty() {
+			export_button =
+				export_button.on_press(Interaction::WalletOperationHomeTxListDisplayInteraction(
+					LocalViewInteraction::Export(format),
+				));
+		}
+		let export_button: Element<Interaction> = export_button.into();
+
+		button_row = button_row
+			.push(Space::with_width(Length::Fixed(DEFAULT_PADDING)))
+			.push(export_button.map(Message::Interaction));
+	}
+
+	let export_proofs_label_container =
+		Container::new(Text::new(localized_string("tx-export-proofs")).size(SMALLER_FONT_SIZE))
+			.height(Length::Fixed(14.0))
+			.center_y()
+			.center_x();
+
+	let mut export_proofs_button = Button::new(export_proofs_label_container)
+		.style(grin_gui_core::theme::ButtonStyle::Bordered)
+		.padding(2);
+	if !state.wallet_txs.txs.is_empty() {
+		export_proofs_button = export_proofs_button.on_press(
+			Interaction::WalletOperationHomeTxListDisplayInteraction(
+				LocalViewInteraction::ExportProofs,
+			),
+		);
+	}
+	let export_proofs_button: Element<Interaction> = export_proofs_button.into();
+
+	let button_row = button_row
+		.push(Space::with_width(Length::Fixed(DEFAULT_PADDING)))
+		.push(export_proofs_button.map(Message::Interaction))
+		.align_items(Alignment::Center);
+
 	/*let segmented_mode_container = Container::new(button_row).padding(1);
 	let segmented_mode_control_container = Container::new(segmented_mode_container)
 		.style(grin_gui_core::theme::ContainerStyle::Segmented)
@@ -396,6 +566,7 @@ pub fn data_container<'a>(
 			is_odd,
 			&None,
 			home_state.node_synched,
+			address_book,
 		);
 
 		// Adds the addon data cell to the scrollable.
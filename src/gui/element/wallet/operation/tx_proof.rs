@@ -4,11 +4,13 @@ use async_std::prelude::FutureExt;
 use grin_gui_core::{
 	config::Config,
 	error::GrinWalletInterfaceError,
+	wallet::proof::{proof_file_name, ProofFile},
 	wallet::{InvoiceProof, SlatepackAddress, TxLogEntry, TxLogEntryType},
 };
 use grin_gui_widgets::widget::header;
 use iced_aw::Card;
 use iced_core::Widget;
+use native_dialog::FileDialog;
 use std::fs::File;
 use std::io::{Read, Write};
 use std::path::PathBuf;
@@ -43,6 +45,8 @@ pub struct StateContainer {
 	// Transaction that we're viewing
 	pub current_tx: Option<TxLogEntry>,
 	pub current_proof: Option<InvoiceProof>,
+	// Where the proof was last exported to
+	pub exported_to: Option<PathBuf>,
 }
 
 impl Default for StateContainer {
@@ -50,35 +54,94 @@ impl Default for StateContainer {
 		Self {
 			current_tx: Default::default(),
 			current_proof: Default::default(),
+			exported_to: Default::default(),
 		}
 	}
 }
 
+/// Write a transaction's proof to the wallet's proof directory, or wherever the user picks
+/// when `choose_path` is set. Returns the file written, if any
+pub fn export_proof(
+	config: &Config,
+	tx: &TxLogEntry,
+	proof: &ProofFile,
+	choose_path: bool,
+) -> Result<Option<PathBuf>> {
+	let path = match config.get_wallet_proof_dir() {
+		Some(dir) if !choose_path => PathBuf::from(dir).join(proof_file_name(tx)),
+		_ => match FileDialog::new()
+			.add_filter("JSON", &["json"])
+			.show_save_single_file()
+		{
+			Ok(Some(p)) => p.with_extension("json"),
+			_ => return Ok(None),
+		},
+	};
+	std::fs::write(&path, proof.to_json()?)?;
+	log::info!("Exported proof of tx {} to {}", tx.id, path.display());
+	Ok(Some(path))
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum Action {}
 
 #[derive(Debug, Clone)]
 pub enum LocalViewInteraction {
 	Back,
+	/// Export to the wallet's proof directory, or a chosen path when set
+	Export(bool),
 }
 
 pub fn handle_message<'a>(
 	grin_gui: &mut GrinGui,
 	message: LocalViewInteraction,
 ) -> Result<Command<Message>> {
-	let state = &mut grin_gui.wallet_state.operation_state.create_tx_state;
+	let state = &mut grin_gui.wallet_state.operation_state.tx_proof_state;
 
 	match message {
 		LocalViewInteraction::Back => {
 			log::debug!("Interaction::WalletOperationTxProofViewInteraction(Back)");
+			state.exported_to = None;
 			grin_gui.wallet_state.operation_state.mode =
 				crate::gui::element::wallet::operation::Mode::Home;
 		}
+		LocalViewInteraction::Export(choose_path) => {
+			log::debug!(
+				"Interaction::WalletOperationTxProofViewInteraction(Export({}))",
+				choose_path
+			);
+			if let (Some(tx), Some(proof)) = (&state.current_tx, &state.current_proof) {
+				let proof = ProofFile::Invoice(proof.clone());
+				if let Some(path) = export_proof(&grin_gui.config, tx, &proof, choose_path)? {
+					state.exported_to = Some(path);
+				}
+			}
+		}
 	}
 
 	Ok(Command::none())
 }
 
+/// Text button exporting a proof, for the foot of a proof card
+pub fn proof_export_button<'a>(label: &str, interaction: Interaction) -> Button<'a, Message> {
+	Button::new(
+		Text::new(localized_string(label))
+			.size(SMALLER_FONT_SIZE)
+			.horizontal_alignment(alignment::Horizontal::Center),
+	)
+	.style(grin_gui_core::theme::ButtonStyle::NormalText)
+	.on_press(Message::Interaction(interaction))
+}
+
+pub fn exported_to_text<'a>(path: &PathBuf) -> Text<'a> {
+	Text::new(format!(
+		"{} {}",
+		localized_string("tx-proof-exported-to"),
+		path.display()
+	))
+	.size(SMALLER_FONT_SIZE)
+}
+
 pub fn data_container<'a>(config: &'a Config, state: &'a StateContainer) -> Container<'a, Message> {
 	// Title row
 	let title = Text::new(localized_string("tx-proof-title"))
@@ -199,15 +262,31 @@ pub fn data_container<'a>(config: &'a Config, state: &'a StateContainer) -> Cont
 				.width(Length::Fill)
 				.align_items(Alignment::Center)
 				.push(
-					Button::new(
-						Text::new(localized_string("copy-to-clipboard"))
-							.size(SMALLER_FONT_SIZE)
-							.horizontal_alignment(alignment::Horizontal::Center),
-					)
-					.style(grin_gui_core::theme::ButtonStyle::NormalText)
-					.on_press(Message::Interaction(Interaction::WriteToClipboard(
-						card_contents.clone(),
-					))),
+					Row::new()
+						.spacing(10)
+						.push(
+							Button::new(
+								Text::new(localized_string("copy-to-clipboard"))
+									.size(SMALLER_FONT_SIZE)
+									.horizontal_alignment(alignment::Horizontal::Center),
+							)
+							.style(grin_gui_core::theme::ButtonStyle::NormalText)
+							.on_press(Message::Interaction(Interaction::WriteToClipboard(
+								card_contents.clone(),
+							))),
+						)
+						.push(proof_export_button(
+							"tx-proof-export",
+							Interaction::WalletOperationTxProofViewInteraction(
+								LocalViewInteraction::Export(false),
+							),
+						))
+						.push(proof_export_button(
+							"tx-proof-export-as",
+							Interaction::WalletOperationTxProofViewInteraction(
+								LocalViewInteraction::Export(true),
+							),
+						)),
 				),
 		)
 		.max_width(400.0)
@@ -218,6 +297,12 @@ pub fn data_container<'a>(config: &'a Config, state: &'a StateContainer) -> Cont
 		column = column
 			.push(json_proof_row)
 			.push(Space::new(Length::Fixed(0.0), Length::Fixed(row_spacing)));
+
+		if let Some(ref path) = state.exported_to {
+			column = column
+				.push(exported_to_text(path))
+				.push(Space::new(Length::Fixed(0.0), Length::Fixed(row_spacing)));
+		}
 	}
 
 	let cancel_button_label_container =
@@ -0,0 +1,528 @@
+use crate::log_error;
+use grin_gui_core::{
+	config::Config,
+	error::GrinWalletInterfaceError,
+	wallet::proof::{KernelStatus, ProofFile, ProofVerdict},
+};
+use native_dialog::FileDialog;
+
+use {
+	super::super::super::{
+		BUTTON_HEIGHT, BUTTON_WIDTH, DEFAULT_FONT_SIZE, DEFAULT_HEADER_FONT_SIZE, DEFAULT_PADDING,
+		SMALLER_FONT_SIZE,
+	},
+	crate::gui::{GrinGui, Interaction, Message},
+	crate::localization::localized_string,
+	crate::Result,
+	grin_gui_core::node::amount_to_hr_string,
+	grin_gui_core::theme::{Button, Column, Container, Element, Row, Scrollable, Text, TextInput},
+	grin_gui_core::wallet::WalletInterface,
+	iced::widget::Space,
+	iced::{alignment, Command, Length},
+	std::sync::{Arc, RwLock},
+};
+
+pub struct StateContainer {
+	pub proof_value: String,
+	pub recipient_address_value: String,
+	pub kernel_excess_value: String,
+	// Proof parsed from `proof_value`
+	proof: Option<ProofFile>,
+	// Proof text that isn't a proof
+	proof_error: bool,
+	slatepack_address_error: bool,
+	kernel_excess_error: bool,
+	verdict: Option<ProofVerdict>,
+}
+
+impl Default for StateContainer {
+	fn default() -> Self {
+		Self {
+			proof_value: Default::default(),
+			recipient_address_value: Default::default(),
+			kernel_excess_value: Default::default(),
+			proof: None,
+			proof_error: false,
+			slatepack_address_error: false,
+			kernel_excess_error: false,
+			verdict: None,
+		}
+	}
+}
+
+impl StateContainer {
+	/// Parse the proof, filling in the recipient and kernel from it where possible.
+	/// Invoice proofs are made out to the invoicer, by default this wallet.
+	fn set_proof(&mut self, proof_value: String, own_address: Option<&String>) {
+		self.proof_value = proof_value;
+		self.verdict = None;
+		self.proof_error = false;
+		self.proof = None;
+		if self.proof_value.trim().is_empty() {
+			return;
+		}
+		match ProofFile::parse(&self.proof_value) {
+			Ok(proof) => {
+				if let Some(a) = proof.recipient_address().or(own_address.cloned()) {
+					self.recipient_address_value = a;
+				}
+				self.kernel_excess_value = proof.kernel_excess_hex().unwrap_or_default();
+				self.proof = Some(proof);
+			}
+			Err(_) => self.proof_error = true,
+		}
+	}
+}
+
+#[derive(Debug, Clone)]
+pub enum LocalViewInteraction {
+	Back,
+	Proof(String),
+	LoadProofFile,
+	RecipientAddress(String),
+	KernelExcess(String),
+	Verify,
+	VerifyOk(ProofVerdict),
+	VerifyError(Arc<RwLock<Option<anyhow::Error>>>),
+	SlatepackAddressError,
+	KernelExcessError,
+}
+
+pub fn handle_message<'a>(
+	grin_gui: &mut GrinGui,
+	message: LocalViewInteraction,
+) -> Result<Command<Message>> {
+	let own_address = grin_gui
+		.wallet_state
+		.operation_state
+		.home_state
+		.address_value
+		.clone();
+	let state = &mut grin_gui.wallet_state.operation_state.verify_proof_state;
+
+	match message {
+		LocalViewInteraction::Back => {
+			log::debug!("Interaction::WalletOperationVerifyProofViewInteraction(Back)");
+			grin_gui.wallet_state.operation_state.mode =
+				crate::gui::element::wallet::operation::Mode::Home;
+		}
+		LocalViewInteraction::Proof(s) => {
+			state.set_proof(s, own_address.as_ref());
+		}
+		LocalViewInteraction::LoadProofFile => {
+			log::debug!("Interaction::WalletOperationVerifyProofViewInteraction(LoadProofFile)");
+			let path = match FileDialog::new()
+				.add_filter("JSON", &["json"])
+				.show_open_single_file()
+			{
+				Ok(Some(p)) => p,
+				_ => return Ok(Command::none()),
+			};
+			let contents = std::fs::read_to_string(&path)?;
+			state.set_proof(contents, own_address.as_ref());
+		}
+		LocalViewInteraction::RecipientAddress(s) => {
+			state.recipient_address_value = s;
+			state.slatepack_address_error = false;
+			state.verdict = None;
+		}
+		LocalViewInteraction::KernelExcess(s) => {
+			state.kernel_excess_value = s;
+			state.kernel_excess_error = false;
+			state.verdict = None;
+		}
+		LocalViewInteraction::Verify => {
+			log::debug!("Interaction::WalletOperationVerifyProofViewInteraction(Verify)");
+			let proof = match state.proof {
+				Some(ref p) => p.clone(),
+				None => return Ok(Command::none()),
+			};
+			grin_gui.error.take();
+			state.slatepack_address_error = false;
+			state.kernel_excess_error = false;
+			state.verdict = None;
+
+			let w = grin_gui.wallet_interface.clone();
+			let recipient = state.recipient_address_value.clone();
+			let kernel_excess = Some(state.kernel_excess_value.clone());
+			let fut = grin_gui.wallet_worker.run(WalletInterface::verify_proof(
+				w,
+				proof,
+				recipient,
+				kernel_excess,
+			));
+
+			return Ok(Command::perform(fut, |r| match r {
+				Ok(verdict) => {
+					Message::Interaction(Interaction::WalletOperationVerifyProofViewInteraction(
+						LocalViewInteraction::VerifyOk(verdict),
+					))
+				}
+				Err(GrinWalletInterfaceError::InvalidSlatepackAddress) => {
+					Message::Interaction(Interaction::WalletOperationVerifyProofViewInteraction(
+						LocalViewInteraction::SlatepackAddressError,
+					))
+				}
+				Err(GrinWalletInterfaceError::InvalidKernelExcess) => {
+					Message::Interaction(Interaction::WalletOperationVerifyProofViewInteraction(
+						LocalViewInteraction::KernelExcessError,
+					))
+				}
+				Err(e) => {
+					let e = anyhow::Error::from(e).context("Failed to Verify Proof");
+					Message::Interaction(Interaction::WalletOperationVerifyProofViewInteraction(
+						LocalViewInteraction::VerifyError(Arc::new(RwLock::new(Some(e)))),
+					))
+				}
+			}));
+		}
+		LocalViewInteraction::VerifyOk(verdict) => {
+			state.verdict = Some(verdict);
+		}
+		LocalViewInteraction::VerifyError(err) => {
+			grin_gui.error = err.write().unwrap().take();
+			if let Some(e) = grin_gui.error.as_ref() {
+				log_error(e);
+			}
+		}
+		LocalViewInteraction::SlatepackAddressError => state.slatepack_address_error = true,
+		LocalViewInteraction::KernelExcessError => state.kernel_excess_error = true,
+	}
+
+	Ok(Command::none())
+}
+
+/// One line of the verdict, styled as a warning when the check failed
+fn verdict_line<'a>(text: String, ok: bool) -> Container<'a, Message> {
+	let mut text = Text::new(text)
+		.size(DEFAULT_FONT_SIZE)
+		.horizontal_alignment(alignment::Horizontal::Left);
+	if !ok {
+		text = text.style(grin_gui_core::theme::text::TextStyle::Warning);
+	}
+	Container::new(text).style(grin_gui_core::theme::ContainerStyle::NormalBackground)
+}
+
+pub fn data_container<'a>(config: &'a Config, state: &'a StateContainer) -> Container<'a, Message> {
+	// Title row
+	let title = Text::new(localized_string("verify-proof-title"))
+		.size(DEFAULT_HEADER_FONT_SIZE)
+		.horizontal_alignment(alignment::Horizontal::Center);
+
+	let title_container = Container::new(title)
+		.style(grin_gui_core::theme::ContainerStyle::BrightBackground)
+		.padding(iced::Padding::from([
+			2, // top
+			0, // right
+			2, // bottom
+			5, // left
+		]));
+
+	let header_row = Row::new().push(title_container);
+
+	let header_container = Container::new(header_row).padding(iced::Padding::from([
+		0,                      // top
+		0,                      // right
+		DEFAULT_PADDING as u16, // bottom
+		0,                      // left
+	]));
+
+	let unit_spacing = 15.0;
+	let row_spacing = 5.0;
+
+	let button_height = Length::Fixed(BUTTON_HEIGHT);
+	let button_width = Length::Fixed(BUTTON_WIDTH);
+
+	// Proof
+	let proof_label = Text::new(localized_string("verify-proof-proof"))
+		.size(DEFAULT_FONT_SIZE)
+		.horizontal_alignment(alignment::Horizontal::Left);
+
+	let proof_label_container =
+		Container::new(proof_label).style(grin_gui_core::theme::ContainerStyle::NormalBackground);
+
+	let proof_instruction = Text::new(localized_string("verify-proof-proof-instruction"))
+		.size(SMALLER_FONT_SIZE)
+		.horizontal_alignment(alignment::Horizontal::Left);
+
+	let proof_instruction_container = Container::new(proof_instruction)
+		.style(grin_gui_core::theme::ContainerStyle::NormalBackground);
+
+	let proof_input = TextInput::new("", &state.proof_value)
+		.on_input(|s| {
+			Interaction::WalletOperationVerifyProofViewInteraction(LocalViewInteraction::Proof(s))
+		})
+		.size(DEFAULT_FONT_SIZE)
+		.padding(6)
+		.width(Length::Fixed(400.0))
+		.style(grin_gui_core::theme::TextInputStyle::AddonsQuery);
+
+	let proof_input: Element<Interaction> = proof_input.into();
+
+	let load_file_label_container = Container::new(
+		Text::new(localized_string("verify-proof-load-file")).size(SMALLER_FONT_SIZE),
+	)
+	.height(Length::Fixed(14.0))
+	.center_y()
+	.center_x();
+
+	let load_file_button: Element<Interaction> = Button::new(load_file_label_container)
+		.style(grin_gui_core::theme::ButtonStyle::Bordered)
+		.on_press(Interaction::WalletOperationVerifyProofViewInteraction(
+			LocalViewInteraction::LoadProofFile,
+		))
+		.padding(2)
+		.into();
+
+	let proof_row = Row::new()
+		.push(proof_input.map(Message::Interaction))
+		.push(Space::with_width(Length::Fixed(DEFAULT_PADDING)))
+		.push(load_file_button.map(Message::Interaction))
+		.align_items(iced::Alignment::Center);
+
+	let mut column = Column::new()
+		.push(proof_label_container)
+		.push(Space::new(Length::Fixed(0.0), Length::Fixed(row_spacing)))
+		.push(proof_instruction_container)
+		.push(Space::new(Length::Fixed(0.0), Length::Fixed(row_spacing)))
+		.push(proof_row)
+		.push(Space::new(Length::Fixed(0.0), Length::Fixed(unit_spacing)));
+
+	if state.proof_error {
+		column = column
+			.push(verdict_line(
+				localized_string("verify-proof-invalid-file"),
+				false,
+			))
+			.push(Space::new(Length::Fixed(0.0), Length::Fixed(unit_spacing)));
+	}
+
+	if let Some(ref proof) = state.proof {
+		let kind = match proof {
+			ProofFile::Payment(_) => localized_string("verify-proof-kind-payment"),
+			ProofFile::Invoice(_) => localized_string("verify-proof-kind-invoice"),
+		};
+		let summary = Text::new(format!(
+			"{}, {}: {}",
+			kind,
+			localized_string("pr-amount"),
+			amount_to_hr_string(proof.amount(), true)
+		))
+		.size(DEFAULT_FONT_SIZE);
+
+		column = column
+			.push(
+				Container::new(summary)
+					.style(grin_gui_core::theme::ContainerStyle::NormalBackground),
+			)
+			.push(Space::new(Length::Fixed(0.0), Length::Fixed(unit_spacing)));
+	}
+
+	// Recipient address
+	let recipient_label = Text::new(localized_string("verify-proof-recipient-address"))
+		.size(DEFAULT_FONT_SIZE)
+		.horizontal_alignment(alignment::Horizontal::Left);
+
+	let recipient_label_container = Container::new(recipient_label)
+		.style(grin_gui_core::theme::ContainerStyle::NormalBackground);
+
+	let recipient_input = TextInput::new("", &state.recipient_address_value)
+		.on_input(|s| {
+			Interaction::WalletOperationVerifyProofViewInteraction(
+				LocalViewInteraction::RecipientAddress(s),
+			)
+		})
+		.size(DEFAULT_FONT_SIZE)
+		.padding(6)
+		.width(Length::Fixed(400.0))
+		.style(grin_gui_core::theme::TextInputStyle::AddonsQuery);
+
+	let recipient_input: Element<Interaction> = recipient_input.into();
+
+	column = column
+		.push(recipient_label_container)
+		.push(Space::new(Length::Fixed(0.0), Length::Fixed(row_spacing)))
+		.push(recipient_input.map(Message::Interaction))
+		.push(Space::new(Length::Fixed(0.0), Length::Fixed(unit_spacing)));
+
+	if state.slatepack_address_error {
+		column = column
+			.push(verdict_line(
+				localized_string("create-tx-address-error"),
+				false,
+			))
+			.push(Space::new(Length::Fixed(0.0), Length::Fixed(unit_spacing)));
+	}
+
+	// Kernel excess
+	let kernel_label = Text::new(localized_string("verify-proof-kernel-excess"))
+		.size(DEFAULT_FONT_SIZE)
+		.horizontal_alignment(alignment::Horizontal::Left);
+
+	let kernel_label_container =
+		Container::new(kernel_label).style(grin_gui_core::theme::ContainerStyle::NormalBackground);
+
+	let kernel_instruction = Text::new(localized_string("verify-proof-kernel-excess-instruction"))
+		.size(SMALLER_FONT_SIZE)
+		.horizontal_alignment(alignment::Horizontal::Left);
+
+	let kernel_instruction_container = Container::new(kernel_instruction)
+		.style(grin_gui_core::theme::ContainerStyle::NormalBackground);
+
+	let kernel_input = TextInput::new("", &state.kernel_excess_value)
+		.on_input(|s| {
+			Interaction::WalletOperationVerifyProofViewInteraction(
+				LocalViewInteraction::KernelExcess(s),
+			)
+		})
+		.size(DEFAULT_FONT_SIZE)
+		.padding(6)
+		.width(Length::Fixed(400.0))
+		.style(grin_gui_core::theme::TextInputStyle::AddonsQuery);
+
+	let kernel_input: Element<Interaction> = kernel_input.into();
+
+	column = column
+		.push(kernel_label_container)
+		.push(Space::new(Length::Fixed(0.0), Length::Fixed(row_spacing)))
+		.push(kernel_instruction_container)
+		.push(Space::new(Length::Fixed(0.0), Length::Fixed(row_spacing)))
+		.push(kernel_input.map(Message::Interaction))
+		.push(Space::new(Length::Fixed(0.0), Length::Fixed(unit_spacing)));
+
+	if state.kernel_excess_error {
+		column = column
+			.push(verdict_line(
+				localized_string("verify-proof-kernel-excess-error"),
+				false,
+			))
+			.push(Space::new(Length::Fixed(0.0), Length::Fixed(unit_spacing)));
+	}
+
+	// Verdict
+	if let Some(ref verdict) = state.verdict {
+		let overall = if verdict.is_valid() {
+			localized_string("verify-proof-valid")
+		} else {
+			localized_string("verify-proof-not-valid")
+		};
+		let signatures = if verdict.signatures_valid {
+			localized_string("verify-proof-signatures-valid")
+		} else {
+			localized_string("verify-proof-signatures-invalid")
+		};
+		let (kernel, kernel_ok) = match verdict.kernel {
+			KernelStatus::OnChain(height) => (
+				format!(
+					"{} {}",
+					localized_string("verify-proof-kernel-on-chain"),
+					height
+				),
+				true,
+			),
+			KernelStatus::NotFound => (localized_string("verify-proof-kernel-not-found"), false),
+			KernelStatus::NotChecked => {
+				(localized_string("verify-proof-kernel-not-checked"), false)
+			}
+			KernelStatus::LookupFailed(ref e) => (
+				format!(
+					"{}: {}",
+					localized_string("verify-proof-kernel-lookup-failed"),
+					e
+				),
+				false,
+			),
+		};
+
+		column = column
+			.push(verdict_line(overall, verdict.is_valid()))
+			.push(Space::new(Length::Fixed(0.0), Length::Fixed(row_spacing)))
+			.push(verdict_line(signatures, verdict.signatures_valid))
+			.push(Space::new(Length::Fixed(0.0), Length::Fixed(row_spacing)))
+			.push(verdict_line(kernel, kernel_ok))
+			.push(Space::new(Length::Fixed(0.0), Length::Fixed(unit_spacing)));
+	}
+
+	let verify_button_label_container =
+		Container::new(Text::new(localized_string("verify-proof-submit")).size(DEFAULT_FONT_SIZE))
+			.width(button_width)
+			.height(button_height)
+			.center_x()
+			.center_y()
+			.align_x(alignment::Horizontal::Center);
+
+	let mut verify_button = Button::new(verify_button_label_container)
+		.style(grin_gui_core::theme::ButtonStyle::Primary);
+	if state.proof.is_some() {
+		verify_button = verify_button.on_press(
+			Interaction::WalletOperationVerifyProofViewInteraction(LocalViewInteraction::Verify),
+		);
+	}
+	let verify_button: Element<Interaction> = verify_button.into();
+
+	let cancel_button_label_container =
+		Container::new(Text::new(localized_string("back")).size(DEFAULT_FONT_SIZE))
+			.width(button_width)
+			.height(button_height)
+			.center_x()
+			.center_y()
+			.align_x(alignment::Horizontal::Center);
+
+	let cancel_button: Element<Interaction> = Button::new(cancel_button_label_container)
+		.style(grin_gui_core::theme::ButtonStyle::Primary)
+		.on_press(Interaction::WalletOperationVerifyProofViewInteraction(
+			LocalViewInteraction::Back,
+		))
+		.into();
+
+	let verify_container = Container::new(verify_button.map(Message::Interaction)).padding(1);
+	let verify_container = Container::new(verify_container)
+		.style(grin_gui_core::theme::ContainerStyle::Segmented)
+		.padding(1);
+
+	let cancel_container = Container::new(cancel_button.map(Message::Interaction)).padding(1);
+	let cancel_container = Container::new(cancel_container)
+		.style(grin_gui_core::theme::ContainerStyle::Segmented)
+		.padding(1);
+
+	let button_row = Row::new()
+		.push(verify_container)
+		.push(Space::new(Length::Fixed(unit_spacing), Length::Fixed(0.0)))
+		.push(cancel_container);
+
+	column = column
+		.push(button_row)
+		.push(Space::new(Length::Fixed(0.0), Length::Fixed(unit_spacing)));
+
+	let form_container = Container::new(column)
+		.width(Length::Fill)
+		.padding(iced::Padding::from([
+			0, // top
+			0, // right
+			0, // bottom
+			5, // left
+		]));
+
+	// form container should be scrollable in tiny windows
+	let scrollable = Scrollable::new(form_container)
+		.height(Length::Fill)
+		.style(grin_gui_core::theme::ScrollableStyle::Primary);
+
+	let content = Container::new(scrollable)
+		.width(Length::Fill)
+		.height(Length::Shrink)
+		.style(grin_gui_core::theme::ContainerStyle::NormalBackground);
+
+	let wrapper_column = Column::new()
+		.height(Length::Fill)
+		.push(header_container)
+		.push(content);
+
+	// Returns the final container.
+	Container::new(wrapper_column).padding(iced::Padding::from([
+		DEFAULT_PADDING, // top
+		DEFAULT_PADDING, // right
+		DEFAULT_PADDING, // bottom
+		DEFAULT_PADDING, // left
+	]))
+}
@@ -20,7 +20,7 @@ use {
 		fs::PersistentData,
 		node::ChainTypes::{self, Mainnet, Testnet},
 		wallet::create_grin_wallet_path,
-		wallet::WalletInterface,
+		wallet::{SecretString, WalletInterface, Zeroizing},
 	},
 	iced::widget::{button, pick_list, scrollable, text_input, Checkbox, Space},
 	iced::{alignment, Alignment, Command, Length},
@@ -30,7 +30,7 @@ use {
 pub struct StateContainer {
 	pub password_state: PasswordState,
 	pub restore_from_seed: bool,
-	pub seed_input_value: String,
+	pub seed_input_value: SecretString,
 	pub show_advanced_options: bool,
 	pub is_testnet: bool,
 	pub advanced_options_state: AdvancedOptionsState,
@@ -65,8 +65,8 @@ impl Default for AdvancedOptionsState {
 
 #[derive(Debug, Clone)]
 pub struct PasswordState {
-	pub input_value: String,
-	pub repeat_input_value: String,
+	pub input_value: SecretString,
+	pub repeat_input_value: SecretString,
 }
 
 impl Default for PasswordState {
@@ -81,7 +81,7 @@ impl Default for PasswordState {
 #[derive(Debug, Clone)]
 pub enum LocalViewInteraction {
 	Back,
-	//TODO: ZeroingString these
+	// Moved into SecretStrings as soon as they arrive
 	PasswordInput(String),
 	PasswordInputEnterPressed,
 	PasswordRepeatInput(String),
@@ -91,7 +91,7 @@ pub enum LocalViewInteraction {
 	ToggleIsTestnet(bool),
 	DisplayName(String),
 	CreateWallet(String, PathBuf),
-	WalletCreatedOk((String, String, String, ChainTypes)),
+	WalletCreatedOk((String, SecretString, String, ChainTypes)),
 	WalletCreateError(Arc<RwLock<Option<anyhow::Error>>>),
 	SeedInput(String),
 	ShowFolderPicker,
@@ -121,14 +121,14 @@ pub fn handle_message<'a>(
 			};
 		}
 		LocalViewInteraction::PasswordInput(password) => {
-			state.password_state.input_value = password;
+			state.password_state.input_value = Zeroizing::new(password);
 		}
 		LocalViewInteraction::PasswordInputEnterPressed => {
 			// state.password_state.input_state.unfocus();
 			// state.password_state.repeat_input_state.focus();
 		}
 		LocalViewInteraction::PasswordRepeatInput(repeat_password) => {
-			state.password_state.repeat_input_value = repeat_password;
+			state.password_state.repeat_input_value = Zeroizing::new(repeat_password);
 		}
 		LocalViewInteraction::PasswordRepeatInputEnterPressed => {
 			//state.password_state.repeat_input_state.unfocus();
@@ -196,18 +196,16 @@ pub fn handle_message<'a>(
 				None
 			};
 
-			let fut = move || {
-				WalletInterface::init(
-					w,
-					password.clone(),
-					top_level_directory,
-					display_name,
-					chain_type,
-					recovery_phrase,
-				)
-			};
+			let fut = grin_gui.wallet_worker.run(WalletInterface::init(
+				w,
+				password,
+				top_level_directory,
+				display_name,
+				chain_type,
+				recovery_phrase,
+			));
 
-			return Ok(Command::perform(fut(), |r| {
+			return Ok(Command::perform(fut, |r| {
 				match r.context("Failed to Create Wallet") {
 					Ok(ret) => Message::Interaction(Interaction::WalletSetupWalletViewInteraction(
 						LocalViewInteraction::WalletCreatedOk(ret),
@@ -231,6 +229,20 @@ pub fn handle_message<'a>(
 				.setup_wallet_success_state
 				.recovery_phrase = mnemonic;
 
+			// A restored wallet knows nothing about its outputs until it's been scanned
+			if grin_gui
+				.wallet_state
+				.setup_state
+				.setup_wallet_state
+				.restore_from_seed
+			{
+				grin_gui
+					.wallet_state
+					.operation_state
+					.scan_state
+					.scan_on_open = true;
+			}
+
 			// reset user input values
 			grin_gui.wallet_state.setup_state.setup_wallet_state = Default::default();
 
@@ -251,7 +263,7 @@ pub fn handle_message<'a>(
 			}
 		}
 		LocalViewInteraction::SeedInput(seed) => {
-			state.seed_input_value = seed;
+			state.seed_input_value = Zeroizing::new(seed);
 		}
 	}
 
@@ -7,14 +7,14 @@ use {
 	grin_gui_core::theme::{
 		Column, Container, Element, PickList, Row, Scrollable, Text, TextInput,
 	},
+	grin_gui_core::wallet::SecretString,
 	iced::widget::{button, pick_list, scrollable, text_input, Button, Checkbox, Space},
 	iced::{alignment, Alignment, Command, Length},
 	iced_aw::Card,
 };
 
 pub struct StateContainer {
-	// TODO: ZeroingString this
-	pub recovery_phrase: String,
+	pub recovery_phrase: SecretString,
 }
 
 impl Default for StateContainer {
@@ -37,6 +37,8 @@ pub fn handle_message(
 	let state = &mut grin_gui.wallet_state.setup_state.setup_wallet_state;
 	match message {
 		LocalViewInteraction::Submit => {
+			// Shown once, don't keep it around
+			state.recovery_phrase = Default::default();
 			grin_gui.wallet_state.mode = super::super::Mode::Operation;
 			grin_gui.wallet_state.setup_state.mode = crate::gui::element::wallet::setup::Mode::Init;
 		}
@@ -67,7 +69,7 @@ pub fn data_container<'a>(state: &'a StateContainer) -> Container<'a, Message> {
 	let recovery_phrase_card = Card::new(
 		Text::new(localized_string("setup-grin-wallet-recovery-phrase-title"))
 			.size(DEFAULT_HEADER_FONT_SIZE),
-		Text::new(&state.recovery_phrase).size(DEFAULT_FONT_SIZE),
+		Text::new(state.recovery_phrase.as_str()).size(DEFAULT_FONT_SIZE),
 	)
 	.foot(
 		Column::new()
@@ -83,7 +85,7 @@ pub fn data_container<'a>(state: &'a StateContainer) -> Container<'a, Message> {
 				)
 				.style(grin_gui_core::theme::ButtonStyle::NormalText)
 				.on_press(Message::Interaction(Interaction::WriteToClipboard(
-					state.recovery_phrase.clone(),
+					state.recovery_phrase.to_string(),
 				))),
 			),
 	)
@@ -16,7 +16,12 @@ use grin_gui_core::{
 		ChainTypes, NodeInterface,
 	},
 	theme::{Button, ColorPalette, Column, Container, PickList, Row, Scrollable, Text, Theme},
-	wallet::{get_grin_wallet_default_path, global, HTTPNodeClient, WalletInterfaceHttpNodeClient},
+	wallet::{
+		get_grin_wallet_default_path, global, inbox,
+		subscriber::{self as wallet_subscriber, WalletMessage, WalletWatcher},
+		worker::WalletWorker,
+		HTTPNodeClient, InterruptibleNodeClient, WalletInterfaceHttpNodeClient,
+	},
 };
 
 use iced::widget::{button, pick_list, scrollable, text_input, Checkbox, Space, TextInput};
@@ -33,6 +38,7 @@ use image::ImageFormat;
 use std::borrow::BorrowMut;
 //use std::collections::HashMap;
 use std::sync::{Arc, RwLock};
+use std::time::Instant;
 
 use element::DEFAULT_HEADER_FONT_SIZE;
 
@@ -42,6 +48,12 @@ pub struct GrinGui {
 	/// Wallet Interface
 	wallet_interface: Arc<RwLock<WalletInterfaceHttpNodeClient>>,
 
+	/// Runs wallet operations off the UI executor, one at a time
+	wallet_worker: Arc<WalletWorker>,
+
+	/// Watches the open wallet for the wallet subscription, once it's been set up
+	wallet_watcher: Option<WalletWatcher>,
+
 	/// Node Interface
 	node_interface: Arc<RwLock<NodeInterface>>,
 
@@ -67,6 +79,9 @@ pub struct GrinGui {
 	/// About screen state
 	about_state: element::about::StateContainer,
 
+	/// Time of the last keyboard, mouse or touch input, for locking an idle wallet
+	last_activity: Instant,
+
 	show_modal: bool,
 	modal_type: ModalType,
 	exit: bool,
@@ -84,9 +99,21 @@ impl GrinGui {
 	}
 
 	pub fn safe_exit(&mut self) {
+		self.wallet_worker.shutdown();
+		if let Some(mut watcher) = self.wallet_watcher.take() {
+			watcher.stop();
+		}
+		WalletInterfaceHttpNodeClient::stop_foreign_listener(self.wallet_interface.clone());
 		let mut node = self.node_interface.write().unwrap();
 		node.shutdown_server(true);
 	}
+
+	/// Point the wallet watcher at the inbox folder of the currently selected wallet
+	pub fn watch_wallet_inbox(&self) {
+		if let Some(watcher) = self.wallet_watcher.as_ref() {
+			watcher.set_inbox_dir(self.config.get_wallet_slatepack_dir().map(inbox::inbox_dir));
+		}
+	}
 }
 
 impl GrinGui {
@@ -94,7 +121,8 @@ impl GrinGui {
 		// Instantiate wallet node client
 		// TODO: Fill out
 		let node_url = "http://localhost:8080";
-		let node_client = HTTPNodeClient::new(node_url, None).unwrap();
+		let node_client =
+			InterruptibleNodeClient::new(HTTPNodeClient::new(node_url, None).unwrap());
 
 		// restore theme from config
 		let name = config.theme.clone().unwrap_or("Alliance".to_string());
@@ -104,6 +132,8 @@ impl GrinGui {
 			wallet_interface: Arc::new(RwLock::new(WalletInterfaceHttpNodeClient::new(
 				node_client,
 			))),
+			wallet_worker: Arc::new(WalletWorker::new()),
+			wallet_watcher: None,
 			node_interface: Arc::new(RwLock::new(NodeInterface::new())),
 			error: None,
 			mode: Mode::Catalog,
@@ -116,6 +146,7 @@ impl GrinGui {
 			node_settings_state: Default::default(),
 			general_settings_state: Default::default(),
 			about_state: Default::default(),
+			last_activity: Instant::now(),
 			show_modal: false,
 			modal_type: ModalType::Error,
 			exit: false,
@@ -129,6 +160,7 @@ impl GrinGui {
 pub enum Message {
 	Error(Arc<RwLock<Option<anyhow::Error>>>),
 	SendNodeMessage((usize, UIMessage, Option<mpsc::Sender<UIMessage>>)),
+	SendWalletMessage((usize, WalletMessage, Option<mpsc::Sender<WalletMessage>>)),
 	Interaction(Interaction),
 	Tick(chrono::DateTime<chrono::Local>),
 	RuntimeEvent(iced_core::Event),
@@ -216,11 +248,14 @@ impl Application for GrinGui {
 		let tick_subscription =
 			time::every(std::time::Duration::from_millis(1000)).map(Message::Tick);
 		let node_subscription = subscriber::subscriber(0).map(|e| Message::SendNodeMessage(e));
+		let wallet_subscription =
+			wallet_subscriber::subscriber(0).map(|e| Message::SendWalletMessage(e));
 
 		iced::Subscription::batch(vec![
 			runtime_subscription,
 			tick_subscription,
 			node_subscription,
+			wallet_subscription,
 		])
 	}
 
@@ -427,6 +462,16 @@ pub enum Interaction {
 	WalletOperationOpenViewInteraction(element::wallet::operation::open::LocalViewInteraction),
 	WalletOperationHomeViewInteraction(element::wallet::operation::home::LocalViewInteraction),
 	WalletOperationTxListInteraction(element::wallet::operation::tx_list::LocalViewInteraction),
+	WalletOperationAccountsViewInteraction(
+		element::wallet::operation::accounts::LocalViewInteraction,
+	),
+	WalletOperationScanViewInteraction(element::wallet::operation::scan::LocalViewInteraction),
+	WalletOperationOutputsViewInteraction(
+		element::wallet::operation::outputs::LocalViewInteraction,
+	),
+	WalletOperationContactsViewInteraction(
+		element::wallet::operation::contacts::LocalViewInteraction,
+	),
 	WalletOperationHomeTxListDisplayInteraction(
 		element::wallet::operation::tx_list_display::LocalViewInteraction,
 	),
@@ -436,6 +481,9 @@ pub enum Interaction {
 	WalletOperationCreateTxViewInteraction(
 		element::wallet::operation::create_tx::LocalViewInteraction,
 	),
+	WalletOperationCreateInvoiceViewInteraction(
+		element::wallet::operation::create_invoice::LocalViewInteraction,
+	),
 	WalletOperationApplyTxViewInteraction(
 		element::wallet::operation::apply_tx::LocalViewInteraction,
 	),
@@ -452,6 +500,15 @@ pub enum Interaction {
 		element::wallet::operation::tx_proof::LocalViewInteraction,
 	),
 	WalletOperationTxDoneViewInteraction(element::wallet::operation::tx_done::LocalViewInteraction),
+	WalletOperationVerifyProofViewInteraction(
+		element::wallet::operation::verify_proof::LocalViewInteraction,
+	),
+	WalletOperationExportProofsViewInteraction(
+		element::wallet::operation::export_proofs::LocalViewInteraction,
+	),
+	WalletOperationRecoveryPhraseViewInteraction(
+		element::wallet::operation::recovery_phrase::LocalViewInteraction,
+	),
 	WalletOperationCreateTxContractsViewInteraction(
 		element::wallet::operation::create_tx_contracts::LocalViewInteraction,
 	),
@@ -2,8 +2,11 @@ use {
 	super::{GrinGui, Interaction, Message, Mode},
 	crate::{gui::element, log_error, Result},
 	grin_gui_core::{
-		fs::PersistentData, node::subscriber::UIMessage, node::ChainTypes::Mainnet,
+		fs::PersistentData,
+		node::subscriber::UIMessage,
+		node::ChainTypes::Mainnet,
 		node::ChainTypes::Testnet,
+		wallet::{config_file_exists, subscriber::WalletWatcher},
 	},
 	iced::{clipboard, Command},
 	//grin_gui_widgets::header::ResizeEvent,
@@ -19,20 +22,23 @@ use std::sync::atomic::Ordering;
 
 pub fn handle_message(grin_gui: &mut GrinGui, message: Message) -> Result<Command<Message>> {
 	if let Some(index) = grin_gui.config.current_wallet_index {
-		// Take opportunity to check if we don't have a wallet config file for some reason
-		if !grin_gui.wallet_state.config_missing() {
-			match &grin_gui.config.wallets[index].tld {
-				Some(t) => {
-					let wallet_interface = grin_gui.wallet_interface.clone();
-					let r = wallet_interface.read();
-					if let Ok(w) = r {
-						if !w.config_exists(t.to_str().unwrap()) {
+		// Check we have a wallet config file once, when the wallet is selected
+		// (remote owner API wallets have no local config to check)
+		if grin_gui.wallet_state.config_checked_wallet != Some(index) {
+			grin_gui.wallet_state.config_checked_wallet = Some(index);
+			if !grin_gui.wallet_state.config_missing()
+				&& !grin_gui.config.wallets[index].backend.is_remote()
+			{
+				match &grin_gui.config.wallets[index].tld {
+					Some(t) => {
+						// A path that isn't valid UTF8 can't hold a loadable config either
+						if !t.to_str().map_or(false, config_file_exists) {
 							grin_gui.wallet_state.set_config_missing();
 						}
 					}
-				}
-				None => {
-					grin_gui.wallet_state.set_config_missing();
+					None => {
+						grin_gui.wallet_state.set_config_missing();
+					}
 				}
 			}
 		}
@@ -42,7 +48,7 @@ pub fn handle_message(grin_gui: &mut GrinGui, message: Message) -> Result<Comman
 			&& !grin_gui.wallet_state.operation_state.wallet_not_open()
 		{
 			let wallet_interface = grin_gui.wallet_interface.clone();
-			let r = wallet_interface.read();
+			let r = wallet_interface.try_read();
 			if let Ok(w) = r {
 				if !w.wallet_is_open() {
 					grin_gui.wallet_state.operation_state.set_wallet_not_open()
@@ -51,18 +57,23 @@ pub fn handle_message(grin_gui: &mut GrinGui, message: Message) -> Result<Comman
 		}
 		// Check if embedded node needs starting
 		if grin_gui.config.wallets[index].use_embedded_node {
-			let (node_started, has_ui_sender) = {
+			let (node_started, start_failed, has_ui_sender) = {
 				let n = grin_gui.node_interface.read().unwrap();
-				(n.node_started, n.ui_sender.is_some())
+				(n.node_started, n.start_failed, n.ui_sender.is_some())
 			};
 
-			if !node_started && has_ui_sender {
+			// A failed start is only retried when the user opens a wallet again
+			if !node_started && !start_failed && has_ui_sender {
 				let mut node = grin_gui.node_interface.write().unwrap();
 				//let is_testnet = grin_gui.config.wallets[index].is_testnet;
 				let wallet_chain_type = grin_gui.config.wallets[index].chain_type;
 
 				if !node_started {
-					node.start_server(wallet_chain_type);
+					if let Err(e) = node.start_server(wallet_chain_type) {
+						let e = anyhow::Error::from(e).context("Failed to start embedded node");
+						log_error(&e);
+						grin_gui.error = Some(e);
+					}
 				} else {
 					let running_chain_type = {
 						let node = grin_gui.node_interface.read().unwrap();
@@ -71,7 +82,12 @@ pub fn handle_message(grin_gui: &mut GrinGui, message: Message) -> Result<Comman
 					.unwrap();
 
 					if running_chain_type != wallet_chain_type {
-						node.restart_server(wallet_chain_type);
+						if let Err(e) = node.restart_server(wallet_chain_type) {
+							let e =
+								anyhow::Error::from(e).context("Failed to restart embedded node");
+							log_error(&e);
+							grin_gui.error = Some(e);
+						}
 					}
 				}
 			}
@@ -82,6 +98,14 @@ pub fn handle_message(grin_gui: &mut GrinGui, message: Message) -> Result<Comman
 		}
 	}
 
+	// Any input counts as activity for the idle lock
+	if let Message::RuntimeEvent(
+		iced_core::Event::Keyboard(_) | iced_core::Event::Mouse(_) | iced_core::Event::Touch(_),
+	) = message
+	{
+		grin_gui.last_activity = std::time::Instant::now();
+	}
+
 	// Clear errors when necessary
 	match message {
 		Message::Interaction(Interaction::OpenErrorModal) => {}
@@ -110,6 +134,9 @@ pub fn handle_message(grin_gui: &mut GrinGui, message: Message) -> Result<Comman
 		}
 		// Ticks, for stuff that happens frequently, like checking wallet status
 		Message::Tick(time) => {
+			if let Some(lock) = element::wallet::operation::home::idle_lock_command(grin_gui) {
+				return Ok(lock);
+			}
 			// Call all views 'registered' for ticks
 			return element::wallet::operation::home::handle_tick(grin_gui, time);
 		}
@@ -135,6 +162,23 @@ pub fn handle_message(grin_gui: &mut GrinGui, message: Message) -> Result<Comman
 				return Ok(Command::none());
 			}
 		},
+		// Changes of the open wallet
+		Message::SendWalletMessage((_id, msg, sender)) => match sender {
+			Some(sender) => {
+				grin_gui.wallet_watcher = Some(WalletWatcher::start(
+					grin_gui.wallet_interface.clone(),
+					grin_gui.wallet_worker.clone(),
+					sender,
+				));
+				if grin_gui.wallet_interface.read().unwrap().wallet_is_open() {
+					grin_gui.watch_wallet_inbox();
+				}
+				return Ok(Command::none());
+			}
+			None => {
+				return element::wallet::operation::home::handle_wallet_message(grin_gui, msg);
+			}
+		},
 		// Error modal state
 		Message::Interaction(Interaction::OpenErrorModal) => grin_gui.show_modal = true,
 		Message::Interaction(Interaction::CloseErrorModal) => grin_gui.show_modal = false,
@@ -159,7 +203,7 @@ pub fn handle_message(grin_gui: &mut GrinGui, message: Message) -> Result<Comman
 		}
 		// Settings -> Wallet Settings
 		Message::Interaction(Interaction::WalletSettingsViewInteraction(l)) => {
-			element::settings::wallet::handle_message(grin_gui, l);
+			return element::settings::wallet::handle_message(grin_gui, l);
 		}
 		// Settings -> Node Settings
 		Message::Interaction(Interaction::NodeSettingsViewInteraction(l)) => {
@@ -210,6 +254,10 @@ pub fn handle_message(grin_gui: &mut GrinGui, message: Message) -> Result<Comman
 		Message::Interaction(Interaction::WalletOperationCreateTxViewInteraction(l)) => {
 			return element::wallet::operation::create_tx::handle_message(grin_gui, l);
 		}
+		// Wallet -> Operation -> CreateInvoice
+		Message::Interaction(Interaction::WalletOperationCreateInvoiceViewInteraction(l)) => {
+			return element::wallet::operation::create_invoice::handle_message(grin_gui, l);
+		}
 		// Wallet -> Operation -> CreateTxSuccess
 		Message::Interaction(Interaction::WalletOperationShowSlatepackViewInteraction(l)) => {
 			return element::wallet::operation::show_slatepack::handle_message(grin_gui, l);
@@ -222,6 +270,22 @@ pub fn handle_message(grin_gui: &mut GrinGui, message: Message) -> Result<Comman
 		Message::Interaction(Interaction::WalletOperationApplyTxConfirmViewInteraction(l)) => {
 			return element::wallet::operation::apply_tx_confirm::handle_message(grin_gui, l);
 		}
+		// Wallet -> Operation -> Accounts
+		Message::Interaction(Interaction::WalletOperationAccountsViewInteraction(l)) => {
+			return element::wallet::operation::accounts::handle_message(grin_gui, l);
+		}
+		// Wallet -> Operation -> Scan
+		Message::Interaction(Interaction::WalletOperationScanViewInteraction(l)) => {
+			return element::wallet::operation::scan::handle_message(grin_gui, l);
+		}
+		// Wallet -> Operation -> Outputs
+		Message::Interaction(Interaction::WalletOperationOutputsViewInteraction(l)) => {
+			return element::wallet::operation::outputs::handle_message(grin_gui, l);
+		}
+		// Wallet -> Operation -> Contacts
+		Message::Interaction(Interaction::WalletOperationContactsViewInteraction(l)) => {
+			return element::wallet::operation::contacts::handle_message(grin_gui, l);
+		}
 		// Wallet -> Operation -> Home -> Action
 		Message::Interaction(Interaction::WalletOperationTxDetailViewInteraction(l)) => {
 			return element::wallet::operation::tx_detail::handle_message(grin_gui, l);
@@ -230,6 +294,18 @@ pub fn handle_message(grin_gui: &mut GrinGui, message: Message) -> Result<Comman
 		Message::Interaction(Interaction::WalletOperationTxProofViewInteraction(l)) => {
 			return element::wallet::operation::tx_proof::handle_message(grin_gui, l);
 		}
+		// Wallet -> Operation -> VerifyProof
+		Message::Interaction(Interaction::WalletOperationVerifyProofViewInteraction(l)) => {
+			return element::wallet::operation::verify_proof::handle_message(grin_gui, l);
+		}
+		// Wallet -> Operation -> ExportProofs
+		Message::Interaction(Interaction::WalletOperationExportProofsViewInteraction(l)) => {
+			return element::wallet::operation::export_proofs::handle_message(grin_gui, l);
+		}
+		// Wallet -> Operation -> RecoveryPhrase
+		Message::Interaction(Interaction::WalletOperationRecoveryPhraseViewInteraction(l)) => {
+			return element::wallet::operation::recovery_phrase::handle_message(grin_gui, l);
+		}
 		// Wallet -> Operation -> Home -> Action
 		Message::Interaction(Interaction::WalletOperationHomeActionMenuViewInteraction(l)) => {
 			return element::wallet::operation::action_menu::handle_message(grin_gui, l);
@@ -301,6 +377,34 @@ pub fn handle_message(grin_gui: &mut GrinGui, message: Message) -> Result<Comman
 				}
 			}
 		}
+		// A slatepack file dropped onto an open wallet is applied as if it had been pasted
+		Message::RuntimeEvent(iced_core::Event::Window(iced_core::window::Event::FileDropped(
+			path,
+		))) => {
+			log::debug!("Message::RuntimeEvent(FileDropped({:?}))", path);
+			let operation_state = &mut grin_gui.wallet_state.operation_state;
+			let can_apply = match operation_state.mode {
+				element::wallet::operation::Mode::Home => true,
+				element::wallet::operation::Mode::ApplyTx => {
+					!operation_state.apply_tx_state.confirm_state.is_self_send
+						&& !operation_state.apply_tx_state.confirm_state.is_signing
+				}
+				_ => false,
+			};
+			if grin_gui.menu_state.mode != element::menu::Mode::Wallet
+				|| grin_gui.wallet_state.mode != element::wallet::Mode::Operation
+				|| !can_apply
+			{
+				return Ok(Command::none());
+			}
+			operation_state.mode = element::wallet::operation::Mode::ApplyTx;
+			return element::wallet::operation::apply_tx::handle_message(
+				grin_gui,
+				element::wallet::operation::apply_tx::LocalViewInteraction::LoadSlatepackFromPath(
+					path,
+				),
+			);
+		}
 		Message::RuntimeEvent(iced_core::Event::Keyboard(
 			iced_core::keyboard::Event::KeyReleased {
 				key_code,